@@ -0,0 +1,48 @@
+//!
+//! The build script for `solx-codegen-evm`.
+//!
+
+///
+/// Compiles the LLVM optimization remark reader, time-trace profiler, and command line options
+/// bindings against the LLVM build used by `llvm-sys`.
+///
+/// The LLVM C API does not expose the kind, pass, function, and location of a remark, the
+/// time-trace profiler, nor resetting the command line options, so they are written in C++ and
+/// require a C++ compiler supported by the LLVM build.
+///
+fn main() {
+    const SOURCE_PATHS: [&str; 3] = [
+        "cpp/remark.cpp",
+        "cpp/time_trace.cpp",
+        "cpp/command_line.cpp",
+    ];
+
+    for path in SOURCE_PATHS.into_iter() {
+        println!("cargo:rerun-if-changed={path}");
+    }
+    println!("cargo:rerun-if-env-changed=LLVM_SYS_211_PREFIX");
+
+    let llvm_config = match std::env::var("LLVM_SYS_211_PREFIX") {
+        Ok(prefix) => std::path::PathBuf::from(prefix)
+            .join("bin")
+            .join("llvm-config"),
+        Err(_) => std::path::PathBuf::from("llvm-config"),
+    };
+    let output = std::process::Command::new(llvm_config.as_path())
+        .arg("--cxxflags")
+        .output()
+        .unwrap_or_else(|error| panic!("{llvm_config:?} process: {error}"));
+    assert!(
+        output.status.success(),
+        "{llvm_config:?} failure: {}",
+        String::from_utf8_lossy(output.stderr.as_slice())
+    );
+    let cxx_flags = String::from_utf8_lossy(output.stdout.as_slice()).to_string();
+
+    let mut build = cc::Build::new();
+    build.cpp(true).files(SOURCE_PATHS);
+    for flag in cxx_flags.split_whitespace() {
+        build.flag(flag);
+    }
+    build.compile("solx_llvm");
+}
@@ -0,0 +1,15 @@
+//
+// The LLVM command line options bindings.
+//
+// The LLVM C API only parses command line options, and parsing an option for the second
+// time is an error, so the options are reset via the C++ API here.
+//
+
+#include "llvm/Support/CommandLine.h"
+
+using namespace llvm;
+
+///
+/// Resets all options to their default values, as if they have never been parsed.
+///
+extern "C" void solx_llvm_reset_command_line_options() { cl::ResetAllOptionOccurrences(); }
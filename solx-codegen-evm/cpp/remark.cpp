@@ -0,0 +1,88 @@
+//
+// The LLVM optimization remark reader.
+//
+// The LLVM C API only exposes the severity and the printed description of a diagnostic,
+// so the remark kind, pass, function, and location are read via the C++ API here.
+// The `llvm-c/Remarks.h` API only parses serialized remark files, and the streamer
+// writing them cannot be attached to a context via the C API.
+//
+
+#include "llvm-c/Core.h"
+#include "llvm/IR/DiagnosticInfo.h"
+#include "llvm/IR/Function.h"
+
+using namespace llvm;
+
+///
+/// The remark kinds. Must match `solx_codegen_evm::codegen::remarks::RawRemark`.
+///
+enum SolxLLVMRemarkKind : int {
+  SOLX_LLVM_REMARK_KIND_NONE = 0,
+  SOLX_LLVM_REMARK_KIND_PASSED = 1,
+  SOLX_LLVM_REMARK_KIND_MISSED = 2,
+  SOLX_LLVM_REMARK_KIND_ANALYSIS = 3,
+};
+
+///
+/// The remark fields. The strings are allocated with `LLVMCreateMessage`.
+///
+struct SolxLLVMRemark {
+  int kind;
+  char *pass;
+  char *name;
+  char *function;
+  char *message;
+  char *file;
+  unsigned line;
+  unsigned column;
+};
+
+static char *createMessage(StringRef string) {
+  return LLVMCreateMessage(string.str().c_str());
+}
+
+///
+/// Reads the remark fields from the diagnostic.
+///
+/// Returns `SOLX_LLVM_REMARK_KIND_NONE` and leaves the fields untouched if the diagnostic is not an optimization remark.
+///
+extern "C" int solx_llvm_remark_read(LLVMDiagnosticInfoRef diagnosticInfo,
+                                     SolxLLVMRemark *remark) {
+  const DiagnosticInfo *info = unwrap(diagnosticInfo);
+
+  int kind = SOLX_LLVM_REMARK_KIND_NONE;
+  switch (info->getKind()) {
+  case DK_OptimizationRemark:
+  case DK_MachineOptimizationRemark:
+    kind = SOLX_LLVM_REMARK_KIND_PASSED;
+    break;
+  case DK_OptimizationRemarkMissed:
+  case DK_MachineOptimizationRemarkMissed:
+    kind = SOLX_LLVM_REMARK_KIND_MISSED;
+    break;
+  case DK_OptimizationRemarkAnalysis:
+  case DK_OptimizationRemarkAnalysisFPCommute:
+  case DK_OptimizationRemarkAnalysisAliasing:
+  case DK_MachineOptimizationRemarkAnalysis:
+    kind = SOLX_LLVM_REMARK_KIND_ANALYSIS;
+    break;
+  default:
+    return SOLX_LLVM_REMARK_KIND_NONE;
+  }
+
+  const auto *optimization = static_cast<const DiagnosticInfoOptimizationBase *>(info);
+  remark->kind = kind;
+  remark->pass = createMessage(optimization->getPassName());
+  remark->name = createMessage(optimization->getRemarkName());
+  remark->function = createMessage(optimization->getFunction().getName());
+  remark->message = createMessage(optimization->getMsg());
+  remark->file = nullptr;
+  remark->line = 0;
+  remark->column = 0;
+  if (optimization->isLocationAvailable()) {
+    StringRef file;
+    optimization->getLocation(file, remark->line, remark->column);
+    remark->file = createMessage(file);
+  }
+  return kind;
+}
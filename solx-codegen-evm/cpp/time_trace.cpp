@@ -0,0 +1,39 @@
+//
+// The LLVM time-trace profiler bindings.
+//
+// The LLVM C API does not expose the time-trace profiler, which records the spans
+// of the LLVM passes, so it is controlled via the C++ API here.
+//
+
+#include "llvm-c/Core.h"
+#include "llvm/ADT/SmallString.h"
+#include "llvm/Support/TimeProfiler.h"
+#include "llvm/Support/raw_ostream.h"
+
+using namespace llvm;
+
+///
+/// Starts the time-trace profiler of the current thread.
+///
+/// The spans shorter than `granularity` microseconds are not recorded.
+///
+extern "C" void solx_llvm_time_trace_initialize(unsigned granularity) {
+  timeTraceProfilerInitialize(granularity, "solx");
+}
+
+///
+/// Stops the time-trace profiler of the current thread, returning the recorded spans
+/// as a Chrome trace-event JSON allocated with `LLVMCreateMessage`.
+///
+extern "C" char *solx_llvm_time_trace_finish() {
+  SmallString<0> json;
+  raw_svector_ostream stream(json);
+  timeTraceProfilerWrite(stream);
+  timeTraceProfilerCleanup();
+  return LLVMCreateMessage(json.c_str());
+}
+
+///
+/// Stops the time-trace profiler of the current thread, discarding the recorded spans.
+///
+extern "C" void solx_llvm_time_trace_cleanup() { timeTraceProfilerCleanup(); }
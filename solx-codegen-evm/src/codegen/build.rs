@@ -16,6 +16,14 @@ pub struct Build {
     pub bytecode: Option<Vec<u8>>,
     /// Text assembly.
     pub assembly: Option<String>,
+    /// Unoptimized LLVM IR.
+    pub llvm_ir: Option<String>,
+    /// Optimized LLVM IR.
+    pub llvm_ir_optimized: Option<String>,
+    /// LLVM optimization remarks.
+    pub llvm_remarks: Option<Vec<solx_utils::LLVMRemark>>,
+    /// EthIR, produced only by the EVM legacy assembly pipeline.
+    pub ethir: Option<String>,
     /// Mapping with immutables.
     pub immutables: Option<BTreeMap<String, BTreeSet<u64>>>,
     /// Whether the size fallback has been activated.
@@ -31,6 +39,10 @@ impl Build {
     pub fn new(
         bytecode: Option<Vec<u8>>,
         assembly: Option<String>,
+        llvm_ir: Option<String>,
+        llvm_ir_optimized: Option<String>,
+        llvm_remarks: Option<Vec<solx_utils::LLVMRemark>>,
+        ethir: Option<String>,
         immutables: Option<BTreeMap<String, BTreeSet<u64>>>,
         is_size_fallback: bool,
         warnings: Vec<Warning>,
@@ -38,6 +50,10 @@ impl Build {
         Self {
             bytecode,
             assembly,
+            llvm_ir,
+            llvm_ir_optimized,
+            llvm_remarks,
+            ethir,
             immutables,
             is_size_fallback,
             warnings,
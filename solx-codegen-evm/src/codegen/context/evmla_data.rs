@@ -2,6 +2,7 @@
 //! The LLVM IR generator EVM legacy assembly data.
 //!
 
+use crate::codegen::profiler::run::Run;
 use crate::context::traits::evmla_data::IEVMLAData;
 use crate::context::value::Value;
 
@@ -17,6 +18,12 @@ pub struct EVMLAData<'ctx> {
     pub version: semver::Version,
     /// The static stack allocated for the current function.
     pub stack: Vec<Value<'ctx>>,
+    /// Whether the EthIR text must be captured for the output.
+    pub output_ethir: bool,
+    /// The captured EthIR text.
+    pub ethir: Option<String>,
+    /// The EthIR construction run, which is measured by the EVM legacy assembly translator.
+    pub ethir_run: Option<Run>,
 }
 
 impl EVMLAData<'_> {
@@ -26,10 +33,13 @@ impl EVMLAData<'_> {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(version: semver::Version) -> Self {
+    pub fn new(version: semver::Version, output_ethir: bool) -> Self {
         Self {
             version,
             stack: Vec::with_capacity(Self::DEFAULT_STACK_SIZE),
+            output_ethir,
+            ethir: None,
+            ethir_run: None,
         }
     }
 }
@@ -16,6 +16,7 @@ use inkwell::types::BasicType;
 
 use crate::codegen::build::Build as EVMBuild;
 use crate::codegen::profiler::Profiler;
+use crate::codegen::remarks::Remarks;
 use crate::codegen::warning::Warning;
 use crate::context::attribute::Attribute;
 use crate::context::function::declaration::Declaration as FunctionDeclaration;
@@ -121,23 +122,41 @@ impl<'ctx> Context<'ctx> {
     ///
     /// Builds the LLVM IR module, returning the build artifacts.
     ///
+    /// The EthIR is taken from the EVM legacy assembly data, if it has been captured there.
+    ///
+    /// The LLVM remarks are collected during the optimization only, whereas the LLVM passes are
+    /// traced during the optimization and bytecode emission if enabled in the `profiler`.
+    ///
     pub fn build(
         &mut self,
         output_assembly: bool,
+        output_llvm_ir: bool,
+        output_llvm_ir_optimized: bool,
+        output_llvm_remarks: bool,
         output_bytecode: bool,
         is_size_fallback: bool,
         profiler: &mut Profiler,
     ) -> anyhow::Result<EVMBuild> {
         let contract_path = self.module.get_name().to_str().expect("Always valid");
 
+        if let (Some(solidity_data), Some(spill_area_size)) = (
+            self.solidity_data.as_ref(),
+            self.optimizer.settings().spill_area_size(),
+        ) {
+            solidity_data.check_unsafe_asm(spill_area_size)?;
+        }
+
         let run_init_verify = profiler.start_evm_translation_unit(
             contract_path,
             self.code_segment,
             "InitVerify",
             self.optimizer.settings(),
         );
-        let target_machine =
-            TargetMachine::new(self.optimizer.settings(), self.llvm_options.as_slice())?;
+        let target_machine = TargetMachine::new(
+            self.optimizer.settings(),
+            self.llvm_options.as_slice(),
+            output_llvm_remarks,
+        )?;
         target_machine.set_target_data(self.module());
         target_machine.set_asm_verbosity(true);
 
@@ -147,6 +166,7 @@ impl<'ctx> Context<'ctx> {
             .spill_area_size()
             .map(|spill_area_size| (crate::r#const::SOLC_USER_MEMORY_OFFSET, spill_area_size));
 
+        self.optimizer.annotate(self.module());
         if let Some(ref debug_config) = self.debug_config {
             debug_config.dump_llvm_ir_unoptimized(
                 contract_path,
@@ -161,6 +181,11 @@ impl<'ctx> Context<'ctx> {
                 self.code_segment,
             )
         })?;
+        let llvm_ir = if output_llvm_ir {
+            Some(self.module().print_to_string().to_string())
+        } else {
+            None
+        };
         run_init_verify.borrow_mut().finish();
 
         let module_size_fallback = self.module.clone();
@@ -170,9 +195,27 @@ impl<'ctx> Context<'ctx> {
             "OptimizeVerify",
             self.optimizer.settings(),
         );
+        let remarks = if output_llvm_remarks {
+            Some(Remarks::install(self.llvm))
+        } else {
+            None
+        };
+        let time_trace = profiler.start_llvm_time_trace();
         self.optimizer
             .run(&target_machine, self.module())
             .map_err(|error| anyhow::anyhow!("{} code optimizing: {error}", self.code_segment))?;
+        profiler.finish_llvm_time_trace(time_trace)?;
+        let mut warnings = Vec::with_capacity(1);
+        let llvm_remarks = match remarks {
+            Some(remarks) => {
+                let (llvm_remarks, llvm_warnings) = remarks.finish().map_err(|error| {
+                    anyhow::anyhow!("{} code optimizing: {error}", self.code_segment)
+                })?;
+                warnings.extend(llvm_warnings.into_iter().map(Warning::LLVMDiagnostic));
+                Some(llvm_remarks)
+            }
+            None => None,
+        };
         if let Some(ref debug_config) = self.debug_config {
             debug_config.dump_llvm_ir_optimized(
                 contract_path,
@@ -187,6 +230,11 @@ impl<'ctx> Context<'ctx> {
                 self.code_segment,
             )
         })?;
+        let llvm_ir_optimized = if output_llvm_ir_optimized {
+            Some(self.module().print_to_string().to_string())
+        } else {
+            None
+        };
         run_optimize_verify.borrow_mut().finish();
 
         let assembly_buffer = if output_assembly || self.debug_config.is_some() {
@@ -221,6 +269,10 @@ impl<'ctx> Context<'ctx> {
         };
         let assembly = assembly_buffer
             .map(|assembly_buffer| String::from_utf8_lossy(assembly_buffer.as_slice()).to_string());
+        let ethir = self
+            .evmla_data
+            .as_ref()
+            .and_then(|evmla_data| evmla_data.ethir.clone());
 
         if output_bytecode {
             let run_emit_bytecode = profiler.start_evm_translation_unit(
@@ -229,11 +281,13 @@ impl<'ctx> Context<'ctx> {
                 "EmitBytecode",
                 self.optimizer.settings(),
             );
+            let time_trace = profiler.start_llvm_time_trace();
             let bytecode_buffer = target_machine
                 .write_to_memory_buffer(self.module(), inkwell::targets::FileType::Object)
                 .map_err(|error| {
                     anyhow::anyhow!("{} bytecode emitting: {error}", self.code_segment)
                 })?;
+            profiler.finish_llvm_time_trace(time_trace)?;
             run_emit_bytecode.borrow_mut().finish();
 
             let immutables = match self.code_segment {
@@ -246,26 +300,31 @@ impl<'ctx> Context<'ctx> {
                 solx_utils::CodeSegment::Runtime => crate::r#const::RUNTIME_CODE_SIZE_LIMIT,
             };
 
-            let mut warnings = Vec::with_capacity(1);
             let bytecode_size = bytecode_buffer.as_slice().len();
             if bytecode_size > bytecode_size_limit {
                 if self.optimizer.settings() == &OptimizerSettings::cycles()
                     && self.optimizer.settings().is_fallback_to_size_enabled()
                 {
-                    crate::codegen::IS_SIZE_FALLBACK
-                        .compare_exchange(
-                            false,
-                            true,
-                            std::sync::atomic::Ordering::Relaxed,
-                            std::sync::atomic::Ordering::Relaxed,
-                        )
-                        .expect("Failed to set the global size fallback flag");
+                    assert!(
+                        !crate::codegen::IS_SIZE_FALLBACK.replace(true),
+                        "Failed to set the size fallback flag"
+                    );
+                    let profile = self.optimizer.profile().cloned();
                     self.optimizer = Optimizer::new(OptimizerSettings::size());
+                    self.optimizer.set_profile(profile);
                     self.module = module_size_fallback;
                     for function in self.module.get_functions() {
                         Function::set_size_attributes(self.llvm, function);
                     }
-                    return self.build(output_assembly, output_bytecode, true, profiler);
+                    return self.build(
+                        output_assembly,
+                        output_llvm_ir,
+                        output_llvm_ir_optimized,
+                        output_llvm_remarks,
+                        output_bytecode,
+                        true,
+                        profiler,
+                    );
                 } else {
                     warnings.push(match self.code_segment {
                         solx_utils::CodeSegment::Deploy => Warning::DeployCodeSize {
@@ -280,6 +339,10 @@ impl<'ctx> Context<'ctx> {
             Ok(EVMBuild::new(
                 Some(bytecode_buffer.as_slice().to_vec()),
                 assembly,
+                llvm_ir,
+                llvm_ir_optimized,
+                llvm_remarks,
+                ethir,
                 immutables,
                 is_size_fallback,
                 warnings,
@@ -288,9 +351,13 @@ impl<'ctx> Context<'ctx> {
             Ok(EVMBuild::new(
                 None,
                 assembly,
+                llvm_ir,
+                llvm_ir_optimized,
+                llvm_remarks,
+                ethir,
                 None,
                 is_size_fallback,
-                vec![],
+                warnings,
             ))
         }
     }
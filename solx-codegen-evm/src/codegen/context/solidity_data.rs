@@ -25,6 +25,12 @@ pub struct SolidityData {
     /// but we still want to try compiling the deploy code to check for other errors including stack-too-deep.
     /// In this case, `immutables` is `None`, and `immutables_dummy` is used to allocated the offsets of the immutables.
     immutables_dummy: BTreeMap<String, u64>,
+    /// The `solc` source file identifier-to-path mapping.
+    source_paths: BTreeMap<usize, String>,
+    /// The source code locations of memory-unsafe assembly blocks, in the order of appearance.
+    unsafe_asm_locations: Vec<String>,
+    /// Whether the contract contains any memory-unsafe assembly.
+    has_unsafe_asm: bool,
 }
 
 impl ISolidityData for SolidityData {
@@ -44,13 +50,67 @@ impl SolidityData {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(immutables: Option<BTreeMap<String, BTreeSet<u64>>>) -> Self {
+    pub fn new(
+        immutables: Option<BTreeMap<String, BTreeSet<u64>>>,
+        source_paths: BTreeMap<usize, String>,
+    ) -> Self {
         Self {
             immutables,
             immutables_dummy: BTreeMap::new(),
+            source_paths,
+            unsafe_asm_locations: Vec::new(),
+            has_unsafe_asm: false,
         }
     }
 
+    ///
+    /// Registers a memory-unsafe assembly block.
+    ///
+    /// The location is resolved to `<path>:<start>:<end>`, where `start` and `end` are
+    /// source code offsets, as in `solc` source mappings.
+    /// Blocks without a known location are only counted.
+    ///
+    pub fn add_unsafe_asm(&mut self, source_id: Option<usize>, start: isize, end: isize) {
+        self.has_unsafe_asm = true;
+
+        let path = match source_id.and_then(|source_id| self.source_paths.get(&source_id)) {
+            Some(path) => path,
+            None => return,
+        };
+        let location = format!("{path}:{start}:{end}");
+        if !self.unsafe_asm_locations.contains(&location) {
+            self.unsafe_asm_locations.push(location);
+        }
+    }
+
+    ///
+    /// Checks whether the contract can be compiled with the spill area.
+    ///
+    /// Returns an error listing the memory-unsafe assembly blocks if there are any,
+    /// unless the check is disabled via the environment variable.
+    ///
+    pub fn check_unsafe_asm(&self, spill_area_size: u64) -> anyhow::Result<()> {
+        if !self.has_unsafe_asm
+            || std::env::var(solx_utils::ENV_DISABLE_UNSAFE_MEMORY_ASM_STACK_TOO_DEEP_CHECK).is_ok()
+        {
+            return Ok(());
+        }
+
+        let mut message = solx_utils::ERROR_UNSAFE_MEMORY_ASM_STACK_TOO_DEEP.to_owned();
+        message.push_str(
+            format!("\nThe required spill area size is {spill_area_size} bytes.\n").as_str(),
+        );
+        if self.unsafe_asm_locations.is_empty() {
+            message.push_str("The locations of the memory-unsafe assembly blocks are unknown.\n");
+        } else {
+            message.push_str("Memory-unsafe assembly blocks (<path>:<start>:<end>):\n\n");
+            for location in self.unsafe_asm_locations.iter() {
+                message.push_str(format!("    {location}\n").as_str());
+            }
+        }
+        anyhow::bail!(message)
+    }
+
     ///
     /// Returns the current number of immutables values in the contract.
     ///
@@ -0,0 +1,145 @@
+//!
+//! LLVM time-trace profiler.
+//!
+
+use std::collections::BTreeMap;
+use std::ffi::c_char;
+use std::ffi::c_uint;
+use std::ffi::CStr;
+
+use crate::codegen::profiler::trace_event::TraceEvent;
+
+///
+/// LLVM time-trace profiler.
+///
+/// Records the spans of the LLVM passes run on the current thread, from the moment it is
+/// started until it is finished or dropped. Only one profiler can run on a thread at a time.
+///
+#[derive(Debug)]
+pub struct LLVMTimeTrace {
+    /// Whether the profiler has been stopped.
+    is_finished: bool,
+}
+
+///
+/// The LLVM time-trace profiler output.
+///
+#[derive(Debug, serde::Deserialize)]
+struct Output {
+    /// The recorded events.
+    #[serde(rename = "traceEvents")]
+    trace_events: Vec<Event>,
+    /// The profiler start timestamp in microseconds since the UNIX epoch.
+    #[serde(rename = "beginningOfTime")]
+    beginning_of_time: u64,
+}
+
+///
+/// The LLVM time-trace profiler event.
+///
+#[derive(Debug, serde::Deserialize)]
+struct Event {
+    /// Event name, which is the pass name for the pass spans.
+    #[serde(default)]
+    name: String,
+    /// Event phase.
+    #[serde(rename = "ph")]
+    phase: String,
+    /// Start timestamp in microseconds since the profiler start.
+    #[serde(rename = "ts", default)]
+    timestamp: u64,
+    /// Duration in microseconds.
+    #[serde(rename = "dur", default)]
+    duration: u64,
+    /// Event arguments, where `detail` is the IR unit name for the pass spans.
+    #[serde(default)]
+    args: BTreeMap<String, serde_json::Value>,
+}
+
+extern "C" {
+    ///
+    /// Starts the time-trace profiler of the current thread.
+    ///
+    fn solx_llvm_time_trace_initialize(granularity: c_uint);
+
+    ///
+    /// Stops the time-trace profiler of the current thread, returning the recorded spans.
+    ///
+    fn solx_llvm_time_trace_finish() -> *mut c_char;
+
+    ///
+    /// Stops the time-trace profiler of the current thread, discarding the recorded spans.
+    ///
+    fn solx_llvm_time_trace_cleanup();
+}
+
+impl LLVMTimeTrace {
+    /// The minimal duration of a recorded span in microseconds.
+    pub const GRANULARITY: u32 = 10;
+
+    /// The prefix of the summary events, which aggregate the spans with the same name.
+    const SUMMARY_EVENT_PREFIX: &'static str = "Total ";
+
+    ///
+    /// Starts the profiler on the current thread.
+    ///
+    pub fn start() -> Self {
+        unsafe { solx_llvm_time_trace_initialize(Self::GRANULARITY) };
+        Self { is_finished: false }
+    }
+
+    ///
+    /// Stops the profiler, returning the recorded spans as trace events of the current process.
+    ///
+    /// The thread ID of the events is left zero, and is assigned by the profiler.
+    ///
+    pub fn finish(mut self) -> anyhow::Result<Vec<TraceEvent>> {
+        self.is_finished = true;
+        let output = unsafe {
+            let output_pointer = solx_llvm_time_trace_finish();
+            let output = CStr::from_ptr(output_pointer)
+                .to_string_lossy()
+                .into_owned();
+            llvm_sys::core::LLVMDisposeMessage(output_pointer);
+            output
+        };
+        let output: Output = serde_json::from_str(output.as_str())
+            .map_err(|error| anyhow::anyhow!("LLVM time trace parsing: {error}"))?;
+
+        let process_id = std::process::id();
+        let events = output
+            .trace_events
+            .into_iter()
+            .filter(|event| {
+                event.phase == TraceEvent::PHASE_COMPLETE
+                    && !event.name.starts_with(Self::SUMMARY_EVENT_PREFIX)
+            })
+            .map(|event| {
+                let detail = event
+                    .args
+                    .get("detail")
+                    .and_then(|detail| detail.as_str())
+                    .map(|detail| BTreeMap::from([("detail".to_owned(), detail.to_owned())]));
+                let mut trace_event = TraceEvent::new(
+                    event.name,
+                    TraceEvent::CATEGORY_LLVM_PASS,
+                    output.beginning_of_time + event.timestamp,
+                    event.duration,
+                    process_id,
+                    0,
+                );
+                trace_event.args = detail;
+                trace_event
+            })
+            .collect();
+        Ok(events)
+    }
+}
+
+impl Drop for LLVMTimeTrace {
+    fn drop(&mut self) {
+        if !self.is_finished {
+            unsafe { solx_llvm_time_trace_cleanup() };
+        }
+    }
+}
@@ -2,7 +2,9 @@
 //! Compiler pipeline profiler.
 //!
 
+pub mod llvm_time_trace;
 pub mod run;
+pub mod trace_event;
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -11,7 +13,9 @@ use indexmap::IndexMap;
 
 use crate::optimizer::settings::Settings as OptimizerSettings;
 
+use self::llvm_time_trace::LLVMTimeTrace;
 use self::run::Run;
+use self::trace_event::TraceEvent;
 
 ///
 /// Compiler pipeline profiler.
@@ -20,9 +24,43 @@ use self::run::Run;
 pub struct Profiler {
     /// Indexed map of timing entries.
     pub timings: IndexMap<String, Rc<RefCell<Run>>>,
+    /// Whether the LLVM passes are traced.
+    pub is_llvm_time_trace_enabled: bool,
+    /// The LLVM pass spans, with the thread ID left zero.
+    pub llvm_trace_events: Vec<TraceEvent>,
 }
 
 impl Profiler {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(is_llvm_time_trace_enabled: bool) -> Self {
+        Self {
+            is_llvm_time_trace_enabled,
+            ..Self::default()
+        }
+    }
+
+    ///
+    /// Starts tracing the LLVM passes on the current thread, if enabled.
+    ///
+    pub fn start_llvm_time_trace(&self) -> Option<LLVMTimeTrace> {
+        self.is_llvm_time_trace_enabled.then(LLVMTimeTrace::start)
+    }
+
+    ///
+    /// Stops tracing the LLVM passes, and records the spans.
+    ///
+    pub fn finish_llvm_time_trace(
+        &mut self,
+        time_trace: Option<LLVMTimeTrace>,
+    ) -> anyhow::Result<()> {
+        if let Some(time_trace) = time_trace {
+            self.llvm_trace_events.extend(time_trace.finish()?);
+        }
+        Ok(())
+    }
+
     ///
     /// Starts a new run for a generic part of the pipeline.
     ///
@@ -46,12 +84,11 @@ impl Profiler {
         description: &str,
         optimizer_settings: &OptimizerSettings,
     ) -> Rc<RefCell<Run>> {
-        let spill_area_description = format!(
-            "SpillArea({})",
-            optimizer_settings.spill_area_size().unwrap_or_default()
-        );
-        let run_name = format!(
-            "{full_path}:{code_segment}/{description}/{optimizer_settings}/{spill_area_description}",
+        let run_name = Self::evm_translation_unit_run_name(
+            full_path,
+            code_segment,
+            description,
+            optimizer_settings,
         );
         assert!(
             !self.timings.contains_key(run_name.as_str()),
@@ -61,6 +98,31 @@ impl Profiler {
         self.start_run(run_name)
     }
 
+    ///
+    /// Inserts a run for an EVM translation unit that has been measured outside of the profiler.
+    ///
+    pub fn insert_evm_translation_unit(
+        &mut self,
+        full_path: &str,
+        code_segment: solx_utils::CodeSegment,
+        description: &str,
+        optimizer_settings: &OptimizerSettings,
+        run: Run,
+    ) {
+        let run_name = Self::evm_translation_unit_run_name(
+            full_path,
+            code_segment,
+            description,
+            optimizer_settings,
+        );
+        assert!(
+            !self.timings.contains_key(run_name.as_str()),
+            "Translation unit run `{run_name}` already exists"
+        );
+
+        self.timings.insert(run_name, Rc::new(RefCell::new(run)));
+    }
+
     ///
     /// Returns a serializeable vector of the profiler runs.
     ///
@@ -77,6 +139,54 @@ impl Profiler {
             .collect()
     }
 
+    ///
+    /// Returns the profiler runs and the LLVM pass spans as trace events attributed to the
+    /// current process.
+    ///
+    /// Runs that have not been finished are skipped.
+    ///
+    pub fn to_trace_events(&self, category: &str, thread_id: u64) -> Vec<TraceEvent> {
+        let process_id = std::process::id();
+        let llvm_trace_events = self.llvm_trace_events.iter().map(|event| TraceEvent {
+            tid: thread_id,
+            ..event.clone()
+        });
+        self.timings
+            .iter()
+            .filter_map(|(name, run)| {
+                let run = run.borrow();
+                let duration = run.duration?;
+                Some(TraceEvent::new(
+                    name.clone(),
+                    category,
+                    run.start_timestamp,
+                    duration.as_micros() as u64,
+                    process_id,
+                    thread_id,
+                ))
+            })
+            .chain(llvm_trace_events)
+            .collect()
+    }
+
+    ///
+    /// Returns the run name for an EVM translation unit.
+    ///
+    fn evm_translation_unit_run_name(
+        full_path: &str,
+        code_segment: solx_utils::CodeSegment,
+        description: &str,
+        optimizer_settings: &OptimizerSettings,
+    ) -> String {
+        let spill_area_description = format!(
+            "SpillArea({})",
+            optimizer_settings.spill_area_size().unwrap_or_default()
+        );
+        format!(
+            "{full_path}:{code_segment}/{description}/{optimizer_settings}/{spill_area_description}",
+        )
+    }
+
     ///
     /// Starts a new run with the given name.
     ///
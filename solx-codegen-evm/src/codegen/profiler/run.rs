@@ -4,14 +4,18 @@
 
 use std::time::Duration;
 use std::time::Instant;
+use std::time::SystemTime;
 
 ///
 /// Compiler pipeline profiler run.
 ///
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Run {
     /// Start time.
     pub start_time: Instant,
+    /// Start timestamp in microseconds since the UNIX epoch.
+    /// Unlike the start time, it can be compared across processes.
+    pub start_timestamp: u64,
     /// Recorded duration.
     pub duration: Option<Duration>,
 }
@@ -20,6 +24,10 @@ impl Default for Run {
     fn default() -> Self {
         Run {
             start_time: Instant::now(),
+            start_timestamp: SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .expect("Always valid")
+                .as_micros() as u64,
             duration: None,
         }
     }
@@ -0,0 +1,73 @@
+//!
+//! Compiler pipeline profiler trace event.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// Compiler pipeline profiler trace event.
+///
+/// Follows the complete event format of the Chrome trace-event specification,
+/// so the resulting files can be opened in Perfetto or `chrome://tracing`.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TraceEvent {
+    /// Event name.
+    pub name: String,
+    /// Event category.
+    #[serde(rename = "cat")]
+    pub category: String,
+    /// Event phase.
+    #[serde(rename = "ph")]
+    pub phase: String,
+    /// Start timestamp in microseconds since the UNIX epoch.
+    #[serde(rename = "ts")]
+    pub timestamp: u64,
+    /// Duration in microseconds.
+    #[serde(rename = "dur")]
+    pub duration: u64,
+    /// Process ID.
+    pub pid: u32,
+    /// Thread ID.
+    pub tid: u64,
+    /// Event arguments shown in the event details.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub args: Option<BTreeMap<String, String>>,
+}
+
+impl TraceEvent {
+    /// The complete event phase, which describes a duration with a single event.
+    pub const PHASE_COMPLETE: &'static str = "X";
+
+    /// The category of the compiler pipeline elements.
+    pub const CATEGORY_PIPELINE: &'static str = "pipeline";
+    /// The category of the compiler worker processes.
+    pub const CATEGORY_PROCESS: &'static str = "process";
+    /// The category of the translation unit stages.
+    pub const CATEGORY_TRANSLATION_UNIT: &'static str = "translation_unit";
+    /// The category of the LLVM passes run within the translation unit stages.
+    pub const CATEGORY_LLVM_PASS: &'static str = "llvm_pass";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        name: String,
+        category: &str,
+        timestamp: u64,
+        duration: u64,
+        pid: u32,
+        tid: u64,
+    ) -> Self {
+        Self {
+            name,
+            category: category.to_owned(),
+            phase: Self::PHASE_COMPLETE.to_owned(),
+            timestamp,
+            duration,
+            pid,
+            tid,
+            args: None,
+        }
+    }
+}
@@ -0,0 +1,252 @@
+//!
+//! The LLVM optimization remarks collector.
+//!
+
+use std::ffi::c_char;
+use std::ffi::c_int;
+use std::ffi::c_void;
+use std::ffi::CStr;
+
+use inkwell::context::AsContextRef;
+
+///
+/// The LLVM optimization remarks collector.
+///
+/// Is installed as the LLVM context diagnostic handler for its lifetime, and restores the
+/// previous handler on drop. The remarks are only produced by LLVM if at least one of the
+/// `-pass-remarks` options is set.
+///
+/// Other diagnostics are forwarded to the previous handler if there is one. Otherwise, they
+/// are collected, since the LLVM default handler terminates the process on errors.
+///
+pub struct Remarks<'ctx> {
+    /// The LLVM context the collector is installed on.
+    llvm: &'ctx inkwell::context::Context,
+    /// The diagnostic handler installed before the collector.
+    previous_handler: llvm_sys::core::LLVMDiagnosticHandler,
+    /// The diagnostic context installed before the collector.
+    previous_context: *mut c_void,
+
+    /// The collected remarks.
+    entries: Vec<solx_utils::LLVMRemark>,
+    /// The collected error descriptions.
+    errors: Vec<String>,
+    /// The collected warning and note descriptions.
+    warnings: Vec<String>,
+}
+
+///
+/// The remark fields read by the C++ remark reader.
+///
+/// Must match `SolxLLVMRemark` in `cpp/remark.cpp`.
+///
+#[repr(C)]
+struct RawRemark {
+    /// The remark kind, where `0` means that the diagnostic is not a remark.
+    kind: c_int,
+    /// The pass name.
+    pass: *mut c_char,
+    /// The remark name.
+    name: *mut c_char,
+    /// The function name.
+    function: *mut c_char,
+    /// The remark message.
+    message: *mut c_char,
+    /// The source file path, or null if the location is not available.
+    file: *mut c_char,
+    /// The source line.
+    line: u32,
+    /// The source column.
+    column: u32,
+}
+
+extern "C" {
+    ///
+    /// Reads the remark fields from the diagnostic, returning the remark kind.
+    ///
+    fn solx_llvm_remark_read(
+        diagnostic_info: llvm_sys::prelude::LLVMDiagnosticInfoRef,
+        remark: *mut RawRemark,
+    ) -> c_int;
+}
+
+impl<'ctx> Remarks<'ctx> {
+    /// The LLVM options prefix shared by `-pass-remarks`, `-pass-remarks-missed`, and `-pass-remarks-analysis`.
+    pub const LLVM_OPTIONS_PREFIX: &'static str = "pass-remarks";
+
+    /// The LLVM options enabling passed, missed, and analysis remarks of all passes.
+    pub const LLVM_OPTIONS_DEFAULT: [&'static str; 3] = [
+        "-pass-remarks=.*",
+        "-pass-remarks-missed=.*",
+        "-pass-remarks-analysis=.*",
+    ];
+
+    ///
+    /// Creates a collector and installs it as the diagnostic handler of the LLVM context.
+    ///
+    /// The collector is boxed, since LLVM keeps a pointer to it until it is dropped.
+    ///
+    pub fn install(llvm: &'ctx inkwell::context::Context) -> Box<Self> {
+        let context = llvm.as_ctx_ref();
+        let (previous_handler, previous_context) = unsafe {
+            (
+                llvm_sys::core::LLVMContextGetDiagnosticHandler(context),
+                llvm_sys::core::LLVMContextGetDiagnosticContext(context),
+            )
+        };
+        let mut remarks = Box::new(Self {
+            llvm,
+            previous_handler,
+            previous_context,
+
+            entries: vec![],
+            errors: vec![],
+            warnings: vec![],
+        });
+        let remarks_pointer = remarks.as_mut() as *mut Self as *mut c_void;
+        unsafe {
+            llvm_sys::core::LLVMContextSetDiagnosticHandler(
+                context,
+                Some(Self::diagnostic_handler),
+                remarks_pointer,
+            );
+        }
+        remarks
+    }
+
+    ///
+    /// Uninstalls the collector, returning the remarks and the warnings.
+    ///
+    /// Returns an error if LLVM has reported any errors.
+    ///
+    pub fn finish(
+        mut self: Box<Self>,
+    ) -> anyhow::Result<(Vec<solx_utils::LLVMRemark>, Vec<String>)> {
+        if !self.errors.is_empty() {
+            anyhow::bail!("{}", self.errors.join("\n"));
+        }
+        Ok((
+            std::mem::take(&mut self.entries),
+            std::mem::take(&mut self.warnings),
+        ))
+    }
+
+    ///
+    /// Returns the LLVM options with the default remarks filters appended,
+    /// unless the user has already specified at least one of them.
+    ///
+    pub fn llvm_options(llvm_options: &[String]) -> Vec<String> {
+        let mut llvm_options = llvm_options.to_vec();
+        if !llvm_options.iter().any(|option| {
+            option
+                .trim_start_matches('-')
+                .starts_with(Self::LLVM_OPTIONS_PREFIX)
+        }) {
+            llvm_options.extend(
+                Self::LLVM_OPTIONS_DEFAULT
+                    .into_iter()
+                    .map(|option| option.to_owned()),
+            );
+        }
+        llvm_options
+    }
+
+    ///
+    /// The LLVM context diagnostic handler.
+    ///
+    extern "C" fn diagnostic_handler(
+        diagnostic_info: llvm_sys::prelude::LLVMDiagnosticInfoRef,
+        remarks: *mut c_void,
+    ) {
+        let remarks = unsafe { &mut *(remarks as *mut Self) };
+
+        if let Some(remark) = unsafe { Self::read_remark(diagnostic_info) } {
+            remarks.entries.push(remark);
+            return;
+        }
+
+        if let Some(previous_handler) = remarks.previous_handler {
+            unsafe { previous_handler(diagnostic_info, remarks.previous_context) };
+            return;
+        }
+
+        let (severity, description) = unsafe {
+            let severity = llvm_sys::core::LLVMGetDiagInfoSeverity(diagnostic_info);
+            let description_pointer = llvm_sys::core::LLVMGetDiagInfoDescription(diagnostic_info);
+            let description = CStr::from_ptr(description_pointer)
+                .to_string_lossy()
+                .into_owned();
+            llvm_sys::core::LLVMDisposeMessage(description_pointer);
+            (severity, description)
+        };
+        match severity {
+            llvm_sys::LLVMDiagnosticSeverity::LLVMDSError => remarks.errors.push(description),
+            llvm_sys::LLVMDiagnosticSeverity::LLVMDSWarning
+            | llvm_sys::LLVMDiagnosticSeverity::LLVMDSNote => remarks.warnings.push(description),
+            llvm_sys::LLVMDiagnosticSeverity::LLVMDSRemark => {}
+        }
+    }
+
+    ///
+    /// Reads the remark from the diagnostic, or returns `None` if it is not an optimization remark.
+    ///
+    unsafe fn read_remark(
+        diagnostic_info: llvm_sys::prelude::LLVMDiagnosticInfoRef,
+    ) -> Option<solx_utils::LLVMRemark> {
+        let mut raw = RawRemark {
+            kind: 0,
+            pass: std::ptr::null_mut(),
+            name: std::ptr::null_mut(),
+            function: std::ptr::null_mut(),
+            message: std::ptr::null_mut(),
+            file: std::ptr::null_mut(),
+            line: 0,
+            column: 0,
+        };
+        let kind = match solx_llvm_remark_read(diagnostic_info, &mut raw) {
+            1 => solx_utils::LLVMRemarkKind::Passed,
+            2 => solx_utils::LLVMRemarkKind::Missed,
+            3 => solx_utils::LLVMRemarkKind::Analysis,
+            _ => return None,
+        };
+
+        let location = if raw.file.is_null() {
+            None
+        } else {
+            Some(solx_utils::LLVMRemarkLocation::new(
+                Self::take_message(raw.file),
+                raw.line,
+                raw.column,
+            ))
+        };
+        Some(solx_utils::LLVMRemark::new(
+            kind,
+            Self::take_message(raw.pass),
+            Self::take_message(raw.name),
+            Self::take_message(raw.function),
+            Self::take_message(raw.message),
+            location,
+        ))
+    }
+
+    ///
+    /// Converts the LLVM-allocated message into a string, and disposes it.
+    ///
+    unsafe fn take_message(message: *mut c_char) -> String {
+        let string = CStr::from_ptr(message).to_string_lossy().into_owned();
+        llvm_sys::core::LLVMDisposeMessage(message);
+        string
+    }
+}
+
+impl Drop for Remarks<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            llvm_sys::core::LLVMContextSetDiagnosticHandler(
+                self.llvm.as_ctx_ref(),
+                self.previous_handler,
+                self.previous_context,
+            );
+        }
+    }
+}
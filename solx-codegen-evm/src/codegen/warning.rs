@@ -28,6 +28,10 @@ pub enum Warning {
         /// Bytecode size.
         found: usize,
     },
+
+    /// LLVM diagnostic warning.
+    #[error("LLVM: {0}")]
+    LLVMDiagnostic(String),
 }
 
 impl Warning {
@@ -40,6 +44,7 @@ impl Warning {
         match self {
             Self::DeployCodeSize { .. } => Some(3860),
             Self::RuntimeCodeSize { .. } => Some(5574),
+            Self::LLVMDiagnostic(_) => None,
         }
     }
 }
@@ -15,14 +15,19 @@ use self::ir_type::IRType;
 pub struct DebugConfig {
     /// The directory to dump the IRs to.
     pub output_directory: PathBuf,
+    /// Whether to dump the control flow graphs in the Graphviz DOT format.
+    pub is_dot_enabled: bool,
 }
 
 impl DebugConfig {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(output_directory: PathBuf) -> Self {
-        Self { output_directory }
+    pub fn new(output_directory: PathBuf, is_dot_enabled: bool) -> Self {
+        Self {
+            output_directory,
+            is_dot_enabled,
+        }
     }
 
     ///
@@ -34,6 +39,7 @@ impl DebugConfig {
         std::fs::create_dir_all(subdirectory_path.as_path())?;
         Ok(Self {
             output_directory: subdirectory_path,
+            is_dot_enabled: self.is_dot_enabled,
         })
     }
 
@@ -73,6 +79,22 @@ impl DebugConfig {
         Ok(())
     }
 
+    ///
+    /// Dumps the Ethereal IR control flow graph in the Graphviz DOT format.
+    ///
+    pub fn dump_ethir_dot(&self, contract_path: &str, code: &str) -> anyhow::Result<()> {
+        let mut file_path = self.output_directory.to_owned();
+        let full_file_name = Self::full_file_name(
+            contract_path,
+            Some(IRType::EthIR.file_extension()),
+            IRType::Dot,
+        );
+        file_path.push(full_file_name);
+        std::fs::write(file_path, code)?;
+
+        Ok(())
+    }
+
     ///
     /// Dumps the unoptimized LLVM IR.
     ///
@@ -43,7 +43,9 @@ pub use self::codegen::instructions::return_data;
 pub use self::codegen::instructions::storage;
 pub use self::codegen::link;
 pub use self::codegen::minimal_deploy_code;
+pub use self::codegen::profiler::llvm_time_trace::LLVMTimeTrace;
 pub use self::codegen::profiler::run::Run;
+pub use self::codegen::profiler::trace_event::TraceEvent;
 pub use self::codegen::profiler::Profiler;
 pub use self::codegen::warning::Warning;
 pub use self::codegen::DummyLLVMWritable;
@@ -4,6 +4,8 @@
 
 pub mod settings;
 
+use inkwell::values::AsValueRef;
+
 use crate::target_machine::TargetMachine;
 
 use self::settings::Settings;
@@ -15,14 +17,25 @@ use self::settings::Settings;
 pub struct Optimizer {
     /// The optimizer settings.
     settings: Settings,
+    /// The execution profile of the translation unit.
+    profile: Option<solx_utils::ExecutionProfileUnit>,
 }
 
 impl Optimizer {
+    /// The LLVM profile metadata kind.
+    pub const PROFILE_METADATA_KIND: &'static str = "prof";
+
+    /// The LLVM profile summary module flag.
+    pub const PROFILE_SUMMARY_FLAG: &'static str = "ProfileSummary";
+
     ///
     /// A shortcut constructor.
     ///
     pub fn new(settings: Settings) -> Self {
-        Self { settings }
+        Self {
+            settings,
+            profile: None,
+        }
     }
 
     ///
@@ -33,10 +46,36 @@ impl Optimizer {
         target_machine: &TargetMachine,
         module: &inkwell::module::Module,
     ) -> Result<(), inkwell::support::LLVMString> {
-        target_machine.run_optimization_passes(
-            module,
-            format!("default<O{}>", self.settings.middle_end_as_char()).as_str(),
-        )
+        target_machine.run_optimization_passes(module, self.settings.middle_end_passes().as_str())
+    }
+
+    ///
+    /// Annotates the module with the execution profile, if it is set.
+    ///
+    /// Must be called before the module is dumped or optimized, so the annotations are visible in
+    /// the unoptimized LLVM IR.
+    ///
+    pub fn annotate(&self, module: &inkwell::module::Module) {
+        if let Some(ref profile) = self.profile {
+            Self::annotate_profile(module, profile);
+        }
+    }
+
+    ///
+    /// Checks whether the custom pass pipeline can be parsed by the new pass manager.
+    ///
+    /// The pipeline is only parsed by the pass builder, which reports malformed pipelines
+    /// and unknown passes before any translation unit is compiled.
+    ///
+    pub fn validate_passes(settings: &Settings) -> anyhow::Result<()> {
+        let passes = match settings.passes {
+            Some(ref passes) => passes,
+            None => return Ok(()),
+        };
+
+        TargetMachine::create(settings)?
+            .parse_passes(passes.as_str())
+            .map_err(|error| anyhow::anyhow!("Invalid LLVM pass pipeline `{passes}`: {error}"))
     }
 
     ///
@@ -45,4 +84,175 @@ impl Optimizer {
     pub fn settings(&self) -> &Settings {
         &self.settings
     }
+
+    ///
+    /// Sets the execution profile of the translation unit.
+    ///
+    pub fn set_profile(&mut self, profile: Option<solx_utils::ExecutionProfileUnit>) {
+        self.profile = profile;
+    }
+
+    ///
+    /// Returns the execution profile reference.
+    ///
+    pub fn profile(&self) -> Option<&solx_utils::ExecutionProfileUnit> {
+        self.profile.as_ref()
+    }
+
+    ///
+    /// Annotates the module with the basic block execution counts.
+    ///
+    /// Functions get their entry counts, conditional branches and switches get their successor
+    /// weights, and the module gets the profile summary, which makes the counts visible to the
+    /// inliner and other profile-guided passes. Blocks are matched by their LLVM IR names, and
+    /// branches are left unannotated if their block or any of its successors is absent in the
+    /// profile, since the blocks of the profiled build are always present with zero counts if
+    /// they were never executed. As with sampling profiles, each weight is incremented by one
+    /// to avoid marking blocks as unreachable.
+    ///
+    fn annotate_profile(
+        module: &inkwell::module::Module,
+        profile: &solx_utils::ExecutionProfileUnit,
+    ) {
+        let llvm = module.get_context();
+        let profile_kind_id = unsafe {
+            llvm_sys::core::LLVMGetMDKindID(
+                Self::PROFILE_METADATA_KIND.as_ptr() as *const std::ffi::c_char,
+                Self::PROFILE_METADATA_KIND.len() as u32,
+            )
+        };
+
+        let mut function_count = 0;
+        let mut max_function_count = 0;
+        let mut max_internal_count = 0;
+        for function in module.get_functions() {
+            let function_name = function.get_name().to_string_lossy();
+            let Some(blocks) = profile.functions.get(function_name.as_ref()) else {
+                continue;
+            };
+            function_count += 1;
+
+            let entry_block = function.get_first_basic_block();
+            for block in function.get_basic_blocks() {
+                let block_name = block.get_name().to_string_lossy();
+                let Some(count) = blocks.get(block_name.as_ref()).copied() else {
+                    continue;
+                };
+                if Some(block) == entry_block {
+                    max_function_count = max_function_count.max(count);
+                    let entry_count = llvm.metadata_node(&[
+                        llvm.metadata_string("function_entry_count").into(),
+                        llvm.i64_type().const_int(count, false).into(),
+                    ]);
+                    unsafe {
+                        llvm_sys::core::LLVMGlobalSetMetadata(
+                            function.as_value_ref(),
+                            profile_kind_id,
+                            llvm_sys::core::LLVMValueAsMetadata(entry_count.as_value_ref()),
+                        );
+                    }
+                } else {
+                    max_internal_count = max_internal_count.max(count);
+                }
+
+                let Some(terminator) = block.get_terminator() else {
+                    continue;
+                };
+                let successor_counts = unsafe {
+                    let successors_number =
+                        llvm_sys::core::LLVMGetNumSuccessors(terminator.as_value_ref());
+                    (0..successors_number)
+                        .map(|index| {
+                            let successor =
+                                llvm_sys::core::LLVMGetSuccessor(terminator.as_value_ref(), index);
+                            let successor_name = std::ffi::CStr::from_ptr(
+                                llvm_sys::core::LLVMGetBasicBlockName(successor),
+                            );
+                            blocks
+                                .get(successor_name.to_string_lossy().as_ref())
+                                .copied()
+                        })
+                        .collect::<Option<Vec<u64>>>()
+                };
+                let Some(successor_counts) = successor_counts else {
+                    continue;
+                };
+                if successor_counts.len() < 2 {
+                    continue;
+                }
+
+                let scale = successor_counts.iter().max().copied().unwrap_or_default()
+                    / (u32::MAX as u64)
+                    + 1;
+                let mut branch_weights: Vec<inkwell::values::BasicMetadataValueEnum> =
+                    Vec::with_capacity(successor_counts.len() + 1);
+                branch_weights.push(llvm.metadata_string("branch_weights").into());
+                for count in successor_counts.into_iter() {
+                    let weight = (count / scale).saturating_add(1).min(u32::MAX as u64);
+                    branch_weights.push(llvm.i32_type().const_int(weight, false).into());
+                }
+                let branch_weights = llvm.metadata_node(branch_weights.as_slice());
+                unsafe {
+                    llvm_sys::core::LLVMSetMetadata(
+                        terminator.as_value_ref(),
+                        profile_kind_id,
+                        branch_weights.as_value_ref(),
+                    );
+                }
+            }
+        }
+        if function_count == 0 {
+            return;
+        }
+
+        let summary_entry = |key: &str, value: u64| {
+            llvm.metadata_node(&[
+                llvm.metadata_string(key).into(),
+                llvm.i64_type().const_int(value, false).into(),
+            ])
+        };
+        let detailed_summary = profile
+            .detailed_summary()
+            .into_iter()
+            .map(|(cutoff, min_count, counts_number)| {
+                llvm.metadata_node(&[
+                    llvm.i32_type().const_int(cutoff as u64, false).into(),
+                    llvm.i64_type().const_int(min_count, false).into(),
+                    llvm.i32_type()
+                        .const_int(counts_number as u64, false)
+                        .into(),
+                ])
+                .into()
+            })
+            .collect::<Vec<inkwell::values::BasicMetadataValueEnum>>();
+        let summary = llvm.metadata_node(&[
+            llvm.metadata_node(&[
+                llvm.metadata_string("ProfileFormat").into(),
+                llvm.metadata_string("SampleProfile").into(),
+            ])
+            .into(),
+            summary_entry(
+                "TotalCount",
+                profile
+                    .counts()
+                    .fold(0u64, |total, count| total.saturating_add(count)),
+            )
+            .into(),
+            summary_entry("MaxCount", profile.counts().max().unwrap_or_default()).into(),
+            summary_entry("MaxInternalCount", max_internal_count).into(),
+            summary_entry("MaxFunctionCount", max_function_count).into(),
+            summary_entry("NumCounts", profile.counts().count() as u64).into(),
+            summary_entry("NumFunctions", function_count).into(),
+            llvm.metadata_node(&[
+                llvm.metadata_string("DetailedSummary").into(),
+                llvm.metadata_node(detailed_summary.as_slice()).into(),
+            ])
+            .into(),
+        ]);
+        module.add_metadata_flag(
+            Self::PROFILE_SUMMARY_FLAG,
+            inkwell::module::FlagBehavior::Error,
+            summary,
+        );
+    }
 }
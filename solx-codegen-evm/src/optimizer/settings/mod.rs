@@ -21,6 +21,12 @@ pub struct Settings {
     pub level_back_end: inkwell::OptimizationLevel,
     /// Fallback to optimizing for size if the bytecode is too large.
     pub is_fallback_to_size_enabled: bool,
+    /// The custom middle-end pass pipeline, which overrides the default one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub passes: Option<String>,
+    /// The auto-tuning objective, which selects the best of the candidate settings per contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub objective: Option<solx_utils::OptimizationObjective>,
 
     /// Size of the spill area used for stack-too-deep mitigation.
     pub spill_area_size: Option<u64>,
@@ -40,6 +46,9 @@ impl Settings {
     /// The list of supported middle-end optimization levels.
     pub const MIDDLE_END_LEVELS: [&str; 5] = ["1", "2", "3", "s", "z"];
 
+    /// The middle-end optimization levels compared by the auto-tuning.
+    pub const AUTO_CANDIDATE_LEVELS: [char; 3] = ['3', 's', 'z'];
+
     ///
     /// A shortcut constructor.
     ///
@@ -73,6 +82,8 @@ impl Settings {
             level_middle_end_size,
             level_back_end,
             is_fallback_to_size_enabled: false,
+            passes: None,
+            objective: None,
 
             spill_area_size: None,
             metadata_size: None,
@@ -161,6 +172,32 @@ impl Settings {
         )
     }
 
+    ///
+    /// Returns the settings for the auto-tuning with the given `objective`.
+    ///
+    pub fn auto(objective: solx_utils::OptimizationObjective) -> Self {
+        let mut settings = Self::cycles();
+        settings.objective = Some(objective);
+        settings
+    }
+
+    ///
+    /// Returns the candidate settings of the auto-tuning, in the order of preference.
+    ///
+    /// The candidates only differ in the optimization levels, and do not inherit the objective itself.
+    ///
+    pub fn auto_candidates(&self) -> Vec<Self> {
+        Self::AUTO_CANDIDATE_LEVELS
+            .into_iter()
+            .map(|level| {
+                let mut settings = self.to_owned();
+                settings.set_levels(&Self::try_from_cli(level).expect("Always valid"));
+                settings.objective = None;
+                settings
+            })
+            .collect()
+    }
+
     ///
     /// Returns the middle-end optimization parameter as string.
     ///
@@ -179,10 +216,23 @@ impl Settings {
     /// Checks whether there are middle-end optimizations enabled.
     ///
     pub fn is_middle_end_enabled(&self) -> bool {
-        self.level_middle_end != inkwell::OptimizationLevel::None
+        self.passes.is_some()
+            || self.level_middle_end != inkwell::OptimizationLevel::None
             || self.level_middle_end_size != SizeLevel::Zero
     }
 
+    ///
+    /// Returns the middle-end pass pipeline.
+    ///
+    /// The custom pipeline takes precedence over the default one of the optimization level.
+    ///
+    pub fn middle_end_passes(&self) -> String {
+        match self.passes {
+            Some(ref passes) => passes.to_owned(),
+            None => format!("default<O{}>", self.middle_end_as_char()),
+        }
+    }
+
     ///
     /// Returns all possible combinations of the optimizer settings.
     ///
@@ -247,9 +297,36 @@ impl Settings {
         self.level_middle_end = inkwell::OptimizationLevel::Default;
         self.level_middle_end_size = SizeLevel::Z;
         self.level_back_end = inkwell::OptimizationLevel::Aggressive;
+        self.passes = None;
         self.enable_fallback_to_size();
     }
 
+    ///
+    /// Enables the auto-tuning with the given `objective`.
+    ///
+    /// Only the optimization levels are changed, so the debugging tools and other settings are kept.
+    ///
+    pub fn set_objective(&mut self, objective: solx_utils::OptimizationObjective) {
+        self.set_levels(&Self::cycles());
+        self.objective = Some(objective);
+    }
+
+    ///
+    /// Copies the optimization levels of `other`.
+    ///
+    fn set_levels(&mut self, other: &Self) {
+        self.level_middle_end = other.level_middle_end;
+        self.level_middle_end_size = other.level_middle_end_size;
+        self.level_back_end = other.level_back_end;
+    }
+
+    ///
+    /// Sets the custom middle-end pass pipeline.
+    ///
+    pub fn set_passes(&mut self, passes: String) {
+        self.passes = Some(passes);
+    }
+
     ///
     /// Sets the deploy code spill area size.
     ///
@@ -284,6 +361,8 @@ impl PartialEq for Settings {
         self.level_middle_end == other.level_middle_end
             && self.level_middle_end_size == other.level_middle_end_size
             && self.level_back_end == other.level_back_end
+            && self.passes == other.passes
+            && self.objective == other.objective
     }
 }
 
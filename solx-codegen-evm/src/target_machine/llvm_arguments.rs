@@ -0,0 +1,97 @@
+//!
+//! The LLVM command line arguments lock.
+//!
+
+use std::cell::RefCell;
+use std::sync::RwLock;
+use std::sync::RwLockReadGuard;
+
+/// The LLVM command line arguments in effect in the current process.
+///
+/// LLVM options are process-global, so they can only be changed when no translation unit is
+/// being compiled with the ones in effect.
+static LLVM_ARGUMENTS: RwLock<Option<Vec<String>>> = RwLock::new(None);
+
+thread_local! {
+    /// The LLVM command line arguments locked by the current thread.
+    static LOCKED_LLVM_ARGUMENTS: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+extern "C" {
+    ///
+    /// Resets all LLVM options to their default values, as if they have never been parsed.
+    ///
+    fn solx_llvm_reset_command_line_options();
+}
+
+///
+/// The LLVM command line arguments lock.
+///
+/// Keeps the LLVM command line arguments in effect for its lifetime. Translation units with the
+/// same arguments are compiled concurrently, whereas the ones with other arguments, such as
+/// a different metadata size or spill area, wait until the lock is released, and then reset
+/// the LLVM options and parse their own ones.
+///
+#[derive(Debug)]
+pub struct LLVMArguments {
+    /// The shared lock of the arguments in effect.
+    _guard: RwLockReadGuard<'static, Option<Vec<String>>>,
+}
+
+impl LLVMArguments {
+    ///
+    /// Locks the LLVM command line `arguments`, parsing them if they are not in effect yet.
+    ///
+    /// Returns `None` if the current thread has already locked the same arguments, for instance,
+    /// for the size fallback build, since the lock is not reentrant.
+    ///
+    /// Returns an error if the current thread has locked other arguments, as the lock would
+    /// never be released.
+    ///
+    pub fn lock(arguments: Vec<String>) -> anyhow::Result<Option<Self>> {
+        if let Some(locked_arguments) =
+            LOCKED_LLVM_ARGUMENTS.with_borrow(|locked_arguments| locked_arguments.clone())
+        {
+            if locked_arguments != arguments {
+                anyhow::bail!(
+                    "LLVM arguments `{}` cannot be used while `{}` are in effect in the same thread",
+                    arguments.join(" "),
+                    locked_arguments.join(" "),
+                );
+            }
+            return Ok(None);
+        }
+
+        loop {
+            let guard = LLVM_ARGUMENTS.read().expect("Sync");
+            if guard.as_ref() == Some(&arguments) {
+                LOCKED_LLVM_ARGUMENTS
+                    .with_borrow_mut(|locked_arguments| *locked_arguments = Some(arguments));
+                return Ok(Some(Self { _guard: guard }));
+            }
+            drop(guard);
+
+            let mut guard = LLVM_ARGUMENTS.write().expect("Sync");
+            if guard.as_ref() != Some(&arguments) {
+                if guard.is_some() {
+                    unsafe { solx_llvm_reset_command_line_options() };
+                }
+                if arguments.len() > 1 {
+                    let arguments: Vec<&str> =
+                        arguments.iter().map(|argument| argument.as_str()).collect();
+                    inkwell::support::parse_command_line_options(
+                        arguments.as_slice(),
+                        "LLVM options",
+                    );
+                }
+                *guard = Some(arguments.clone());
+            }
+        }
+    }
+}
+
+impl Drop for LLVMArguments {
+    fn drop(&mut self) {
+        LOCKED_LLVM_ARGUMENTS.with_borrow_mut(|locked_arguments| *locked_arguments = None);
+    }
+}
@@ -2,8 +2,13 @@
 //! EVM target machine.
 //!
 
+pub mod llvm_arguments;
+
+use crate::codegen::remarks::Remarks;
 use crate::optimizer::settings::Settings as OptimizerSettings;
 
+use self::llvm_arguments::LLVMArguments;
+
 ///
 /// EVM target machine.
 ///
@@ -13,6 +18,8 @@ pub struct TargetMachine {
     target_machine: inkwell::targets::TargetMachine,
     /// The optimizer settings.
     optimizer_settings: OptimizerSettings,
+    /// The lock of the LLVM command line arguments the target machine is used with.
+    _llvm_arguments: Option<LLVMArguments>,
 }
 
 impl TargetMachine {
@@ -22,18 +29,46 @@ impl TargetMachine {
     ///
     /// A shortcut constructor.
     ///
+    /// LLVM options are process-global, so the target machine keeps its LLVM command line
+    /// arguments in effect until it is dropped. See [`LLVMArguments`].
+    ///
+    pub fn new(
+        optimizer_settings: &OptimizerSettings,
+        llvm_options: &[String],
+        output_llvm_remarks: bool,
+    ) -> anyhow::Result<Self> {
+        let llvm_arguments = LLVMArguments::lock(Self::llvm_arguments(
+            optimizer_settings,
+            llvm_options,
+            output_llvm_remarks,
+        ))?;
+        let mut target_machine = Self::create(optimizer_settings)?;
+        target_machine._llvm_arguments = llvm_arguments;
+        Ok(target_machine)
+    }
+
+    ///
+    /// Returns the LLVM command line arguments for the optimizer settings and LLVM options.
+    ///
+    /// The default remarks filters are appended if `output_llvm_remarks` is set.
+    ///
     /// Supported LLVM options:
     /// `-evm-stack-region-size <value>`
     /// `-evm-stack-region-offset <value>`
     /// `-evm-metadata-size <value>`
     ///
-    pub fn new(
+    pub fn llvm_arguments(
         optimizer_settings: &OptimizerSettings,
         llvm_options: &[String],
-    ) -> anyhow::Result<Self> {
+        output_llvm_remarks: bool,
+    ) -> Vec<String> {
         let mut arguments = Vec::with_capacity(1 + llvm_options.len());
         arguments.push(Self::TARGET.to_string());
-        arguments.extend_from_slice(llvm_options);
+        if output_llvm_remarks {
+            arguments.extend(Remarks::llvm_options(llvm_options));
+        } else {
+            arguments.extend_from_slice(llvm_options);
+        }
         if let Some(size) = optimizer_settings.spill_area_size {
             arguments.push(format!(
                 "-evm-stack-region-offset={}",
@@ -44,11 +79,13 @@ impl TargetMachine {
         if let Some(size) = optimizer_settings.metadata_size {
             arguments.push(format!("-evm-metadata-size={size}"));
         }
-        if arguments.len() > 1 {
-            let arguments: Vec<&str> = arguments.iter().map(|argument| argument.as_str()).collect();
-            inkwell::support::parse_command_line_options(arguments.as_slice(), "LLVM options");
-        }
+        arguments
+    }
 
+    ///
+    /// Creates the target machine without parsing any LLVM options.
+    ///
+    pub fn create(optimizer_settings: &OptimizerSettings) -> anyhow::Result<Self> {
         let target_machine = inkwell::targets::Target::from_name(Self::TARGET.to_string().as_str())
             .ok_or_else(|| anyhow::anyhow!("LLVM target machine `{}` not found", Self::TARGET))?
             .create_target_machine(
@@ -69,6 +106,7 @@ impl TargetMachine {
         Ok(Self {
             target_machine,
             optimizer_settings: optimizer_settings.to_owned(),
+            _llvm_arguments: None,
         })
     }
 
@@ -114,6 +152,27 @@ impl TargetMachine {
         module.run_passes(passes, &self.target_machine, pass_builder_options)
     }
 
+    ///
+    /// Validates the pass pipeline `passes`, returning the LLVM error if it is invalid.
+    ///
+    /// The LLVM C API parses pass pipelines only as a part of running them, so the pipeline
+    /// is run on an empty module, which does not contain any code to transform.
+    ///
+    pub fn parse_passes(&self, passes: &str) -> anyhow::Result<()> {
+        let llvm = inkwell::context::Context::create();
+        let module = llvm.create_module("passes");
+        module.set_triple(&self.get_triple());
+        module.set_data_layout(&self.get_target_data().get_data_layout());
+
+        module
+            .run_passes(
+                passes,
+                &self.target_machine,
+                inkwell::passes::PassBuilderOptions::create(),
+            )
+            .map_err(|error| anyhow::anyhow!(error.to_string()))
+    }
+
     ///
     /// Returns the target triple.
     ///
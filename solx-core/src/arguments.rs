@@ -9,14 +9,19 @@ use std::sync::Arc;
 use std::sync::Mutex;
 
 use clap::Parser;
+use clap::Subcommand;
 use path_slash::PathExt;
 
 ///
 /// Solidity compiler arguments.
 ///
 #[derive(Debug, Parser)]
-#[command(about, long_about = None)]
+#[command(about, long_about = None, args_conflicts_with_subcommands = true)]
 pub struct Arguments {
+    /// The subcommand to run instead of compiling the input files.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Print the version and exit.
     #[arg(long)]
     pub version: bool,
@@ -54,6 +59,16 @@ pub struct Arguments {
     #[arg(short = 'O', long)]
     pub optimization: Option<char>,
 
+    /// Pick the best of the optimization parameters `3`, `s`, and `z` for each contract according to `--optimization-objective`.
+    #[arg(long)]
+    pub optimization_auto: bool,
+
+    /// Objective of the `--optimization-auto` mode.
+    /// Available objectives: `runtime-size`, `deploy-size`, `gas`.
+    /// The default is `gas`.
+    #[arg(long)]
+    pub optimization_objective: Option<solx_utils::OptimizationObjective>,
+
     /// Try to recompile with -Oz if the bytecode is too large.
     #[arg(long = "optimization-size-fallback")]
     pub size_fallback: bool,
@@ -64,6 +79,18 @@ pub struct Arguments {
     #[arg(long)]
     pub llvm_options: Option<String>,
 
+    /// Run a custom LLVM middle-end pass pipeline instead of the default one of the optimization level.
+    /// The pipeline uses the LLVM new pass manager syntax.
+    /// Example: `--llvm-passes='function(instcombine,simplifycfg),globaldce'`.
+    #[arg(long)]
+    pub llvm_passes: Option<String>,
+
+    /// Optimize with the basic block execution counts from the specified profile.
+    /// The profile is collected from execution traces of a previous build with the help of its
+    /// `evm.bytecode.blockMap` and `evm.deployedBytecode.blockMap` standard JSON outputs.
+    #[arg(long)]
+    pub profile_use: Option<PathBuf>,
+
     /// EVM version `solc` will produce Yul or EVM assembly for.
     /// The default is chosen by `solc`.
     #[arg(long)]
@@ -101,7 +128,7 @@ pub struct Arguments {
     pub via_ir: bool,
 
     /// Set the metadata hash type.
-    /// Available types: `none`, `ipfs`.
+    /// Available types: `none`, `ipfs`, `bzzr1`.
     /// The default is `ipfs`.
     #[arg(long)]
     pub metadata_hash: Option<solx_utils::MetadataHashType>,
@@ -171,15 +198,52 @@ pub struct Arguments {
     #[arg(long = "ir", alias = "ir-optimized")]
     pub output_ir: bool,
 
+    /// Emit the Yul AST JSON of the compiled project, with locations and the extracted LLVM function attributes.
+    /// Only available in Yul mode and with the `solc` IR codegen.
+    /// The emitted JSON can be passed back as input in Yul mode.
+    #[arg(long = "yul-ast-json")]
+    pub output_yul_ast_json: bool,
+
     /// Emit solx's compilation pipeline benchmarks.
     #[arg(long = "benchmarks")]
     pub output_benchmarks: bool,
 
+    /// Emit the stack-too-deep resolution diagnostics of each contract and code segment:
+    /// the spill area size, if spilling was required, and whether the size fallback was activated.
+    #[arg(long = "stack-diagnostics")]
+    pub output_stack_diagnostics: bool,
+
+    /// Write a Chrome trace-event JSON profile of the compilation pipeline to the specified file.
+    /// The profile can be opened in Perfetto or `chrome://tracing`.
+    #[arg(long)]
+    pub time_trace: Option<PathBuf>,
+
+    /// Write the LLVM optimization remarks of each contract and code segment to the specified directory.
+    /// The remarks kinds and passes are filtered with `--llvm-remarks-kind` and `--llvm-remarks-filter`,
+    /// or with the LLVM `-pass-remarks`, `-pass-remarks-missed`, and `-pass-remarks-analysis` options.
+    /// If none of them are passed, all remarks are written.
+    #[arg(long)]
+    pub llvm_remarks: Option<PathBuf>,
+
+    /// Only write the LLVM optimization remarks of passes whose names match the specified regex.
+    #[arg(long)]
+    pub llvm_remarks_filter: Option<String>,
+
+    /// Only write the LLVM optimization remarks of the specified kind: `passed`, `missed`, or `analysis`.
+    /// Can be specified multiple times.
+    #[arg(long)]
+    pub llvm_remarks_kind: Vec<solx_utils::LLVMRemarkKind>,
+
     /// Dump all IRs to files in the specified directory.
     /// Only for testing and debugging.
     #[arg(long)]
     pub debug_output_dir: Option<PathBuf>,
 
+    /// Additionally dump the Ethereal IR control flow graphs in the Graphviz DOT format to the debug output directory.
+    /// Only for testing and debugging.
+    #[arg(long)]
+    pub debug_output_dot: bool,
+
     /// Set the verify-each option in LLVM.
     /// Only for testing and debugging.
     #[arg(long)]
@@ -196,6 +260,36 @@ pub struct Arguments {
     pub recursive_process: bool,
 }
 
+///
+/// Solidity compiler subcommands.
+///
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Recompile the sources embedded into the contract metadata and compare the result with the deployed bytecode.
+    /// The metadata must have been emitted by `solx` with the literal content flag enabled.
+    /// Reports a full match if the bytecode is identical, or a partial match if only the CBOR metadata differs.
+    Verify {
+        /// Path to the contract metadata JSON.
+        #[arg(long)]
+        metadata: PathBuf,
+
+        /// Deployed bytecode of the contract as a hexadecimal string, optionally prefixed with `0x`.
+        #[arg(long)]
+        bytecode: String,
+    },
+}
+
+impl Command {
+    ///
+    /// Returns the subcommand name.
+    ///
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Verify { .. } => "verify",
+        }
+    }
+}
+
 impl Arguments {
     ///
     /// Validates the arguments.
@@ -221,6 +315,16 @@ impl Arguments {
             return Arc::new(Mutex::new(messages));
         }
 
+        if let Some(ref command) = self.command {
+            if std::path::Path::new(command.name()).exists() {
+                messages.push(solx_standard_json::OutputError::new_error(format!(
+                    "`{name}` is ambiguous, as there is a file with the same name in the current directory. Specify the file as `./{name}` to compile it, or run the subcommand from another directory.",
+                    name = command.name(),
+                )));
+            }
+            return Arc::new(Mutex::new(messages));
+        }
+
         if self.standard_json.is_none() && self.inputs.is_empty() {
             messages.push(solx_standard_json::OutputError::new_error(
                 format!("No input files given. For standard input, specify `{}` explicitly, or visit `--help` to see all options.", solx_standard_json::InputSource::STDIN_INPUT_IDENTIFIER).as_str(),
@@ -237,6 +341,28 @@ impl Arguments {
             ));
         }
 
+        if self.optimization_auto {
+            if self.optimization.is_some() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Optimization parameter cannot be used with the `auto` optimization mode.",
+                ));
+            }
+            if self.size_fallback {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "Size optimization fallback cannot be used with the `auto` optimization mode.",
+                ));
+            }
+            if self.llvm_passes.is_some() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "LLVM passes cannot be used with the `auto` optimization mode.",
+                ));
+            }
+        } else if self.optimization_objective.is_some() {
+            messages.push(solx_standard_json::OutputError::new_error(
+                "Optimization objective can only be used with the `auto` optimization mode.",
+            ));
+        }
+
         if self.yul || self.llvm_ir {
             if self.base_path.is_some() {
                 messages.push(solx_standard_json::OutputError::new_error(
@@ -283,6 +409,20 @@ impl Arguments {
             }
         }
 
+        if self.llvm_remarks.is_none()
+            && (self.llvm_remarks_filter.is_some() || !self.llvm_remarks_kind.is_empty())
+        {
+            messages.push(solx_standard_json::OutputError::new_error(
+                "LLVM remarks filters can only be used with `--llvm-remarks`.",
+            ));
+        }
+
+        if self.output_yul_ast_json && (self.llvm_ir || !(self.yul || self.via_ir)) {
+            messages.push(solx_standard_json::OutputError::new_error(
+                "Yul AST can be only emitted in Yul mode or with the IR codegen.",
+            ));
+        }
+
         if self.standard_json.is_some() {
             if self.output_bytecode
                 || self.output_bytecode_runtime
@@ -297,7 +437,12 @@ impl Arguments {
                 || self.output_ast_json
                 || self.output_asm_solc_json
                 || self.output_ir
+                || self.output_yul_ast_json
                 || self.output_benchmarks
+                || self.output_stack_diagnostics
+                || self.llvm_remarks.is_some()
+                || self.llvm_remarks_filter.is_some()
+                || !self.llvm_remarks_kind.is_empty()
             {
                 messages.push(solx_standard_json::OutputError::new_error(
                     "Cannot output data outside of JSON in standard JSON mode.",
@@ -351,6 +496,11 @@ impl Arguments {
                     "LLVM options must be specified in standard JSON input settings.",
                 ));
             }
+            if self.llvm_passes.is_some() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    "LLVM passes must be specified in standard JSON input settings.",
+                ));
+            }
             if self.metadata_hash.is_some() {
                 messages.push(solx_standard_json::OutputError::new_error(
                     "Metadata hash mode must be specified in standard JSON input settings.",
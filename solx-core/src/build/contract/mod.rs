@@ -42,6 +42,10 @@ pub struct Contract {
     pub legacy_assembly: Option<solx_evm_assembly::Assembly>,
     /// solc Yul IR.
     pub yul: Option<String>,
+    /// The Yul AST JSON.
+    pub yul_ast: Option<serde_json::Value>,
+    /// The optimization mode selected by the auto-tuning.
+    pub optimization_mode: Option<char>,
 }
 
 impl Contract {
@@ -61,6 +65,8 @@ impl Contract {
         transient_storage_layout: Option<serde_json::Value>,
         legacy_assembly: Option<solx_evm_assembly::Assembly>,
         yul: Option<String>,
+        yul_ast: Option<serde_json::Value>,
+        optimization_mode: Option<char>,
     ) -> Self {
         Self {
             name,
@@ -75,6 +81,8 @@ impl Contract {
             transient_storage_layout,
             legacy_assembly,
             yul,
+            yul_ast,
+            optimization_mode,
         }
     }
 
@@ -91,6 +99,9 @@ impl Contract {
             self.name.full_path
         )?;
 
+        if let Some(optimization_mode) = self.optimization_mode {
+            writeln!(std::io::stdout(), "Optimization mode: {optimization_mode}")?;
+        }
         if output_selection.check_selection(
             self.name.path.as_str(),
             self.name.name.as_deref(),
@@ -179,6 +190,15 @@ impl Contract {
             let yul = self.yul.take().expect("Always exists");
             writeln!(std::io::stdout(), "IR:\n{yul}")?;
         }
+        if let Some(yul_ast) = self.yul_ast.take().filter(|_| {
+            output_selection.check_selection(
+                self.name.path.as_str(),
+                self.name.name.as_deref(),
+                solx_standard_json::InputSelector::YulAST,
+            )
+        }) {
+            writeln!(std::io::stdout(), "Yul AST JSON:\n{yul_ast}")?;
+        }
 
         if output_selection.check_selection(
             self.name.path.as_str(),
@@ -262,6 +282,31 @@ impl Contract {
                 self.userdoc.expect("Always exists")
             )?;
         }
+        if let (Some(deploy_object_result), Some(runtime_object_result)) = (
+            self.deploy_object_result.as_ref(),
+            self.runtime_object_result.as_ref(),
+        ) {
+            if output_selection.check_selection(
+                self.name.path.as_str(),
+                self.name.name.as_deref(),
+                solx_standard_json::InputSelector::BytecodeStackDiagnostics,
+            ) {
+                writeln!(std::io::stdout(), "Stack diagnostics:")?;
+                for (object, code_segment) in [deploy_object_result, runtime_object_result]
+                    .into_iter()
+                    .zip([
+                        solx_utils::CodeSegment::Deploy,
+                        solx_utils::CodeSegment::Runtime,
+                    ])
+                {
+                    writeln!(
+                        std::io::stdout(),
+                        "    {code_segment}: {}",
+                        object.as_ref().expect("Always exists").stack_diagnostics()
+                    )?;
+                }
+            }
+        }
         if let (Some(deploy_object_result), Some(runtime_object_result)) =
             (self.deploy_object_result, self.runtime_object_result)
         {
@@ -564,6 +609,59 @@ impl Contract {
             let yul = self.yul.expect("Always exists").to_string();
             Self::write_to_file(output_path.as_path(), yul, overwrite)?;
         }
+        if let Some(yul_ast) = self.yul_ast.take().filter(|_| {
+            output_selection.check_selection(
+                self.name.path.as_str(),
+                self.name.name.as_deref(),
+                solx_standard_json::InputSelector::YulAST,
+            )
+        }) {
+            let output_name = format!(
+                "{contract_path}_{}_yul_ast.{}",
+                self.name.name.as_deref().unwrap_or(contract_name),
+                solx_utils::EXTENSION_JSON,
+            );
+            let mut output_path = output_directory.to_owned();
+            output_path.push(output_name.as_str());
+
+            Self::write_to_file(output_path.as_path(), yul_ast.to_string(), overwrite)?;
+        }
+        if let (Some(deploy_object_result), Some(runtime_object_result)) = (
+            self.deploy_object_result.as_ref(),
+            self.runtime_object_result.as_ref(),
+        ) {
+            if output_selection.check_selection(
+                self.name.path.as_str(),
+                self.name.name.as_deref(),
+                solx_standard_json::InputSelector::BytecodeStackDiagnostics,
+            ) {
+                let output_name = format!(
+                    "{contract_path}_{}_stack_diagnostics.txt",
+                    self.name.name.as_deref().unwrap_or(contract_name),
+                );
+                let mut output_path = output_directory.to_owned();
+                output_path.push(output_name.as_str());
+
+                let mut output = String::with_capacity(256);
+                output.push_str("Stack diagnostics:\n");
+                for (object, code_segment) in [deploy_object_result, runtime_object_result]
+                    .into_iter()
+                    .zip([
+                        solx_utils::CodeSegment::Deploy,
+                        solx_utils::CodeSegment::Runtime,
+                    ])
+                {
+                    output.push_str(
+                        format!(
+                            "{code_segment}: {}\n",
+                            object.as_ref().expect("Always exists").stack_diagnostics()
+                        )
+                        .as_str(),
+                    );
+                }
+                Self::write_to_file(output_path.as_path(), output, overwrite)?;
+            }
+        }
         if let (Some(deploy_object_result), Some(runtime_object_result)) =
             (self.deploy_object_result, self.runtime_object_result)
         {
@@ -673,6 +771,15 @@ impl Contract {
         }) {
             standard_json_contract.ir = Some(value);
         }
+        if let Some(value) = self.yul_ast.take().filter(|_| {
+            output_selection.check_selection(
+                self.name.path.as_str(),
+                self.name.name.as_deref(),
+                solx_standard_json::InputSelector::YulAST,
+            )
+        }) {
+            standard_json_contract.yul_ast = Some(value);
+        }
 
         let evm = standard_json_contract
             .evm
@@ -702,7 +809,19 @@ impl Contract {
         ) {
             evm.gas_estimates = Some(serde_json::json!({}));
         }
+        evm.optimization_mode = self.optimization_mode;
 
+        let deploy_block_map = self.deploy_object_result.as_ref().and_then(|result| {
+            let object = result.as_ref().expect("Always exists");
+            output_selection
+                .check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeBlockMap,
+                )
+                .then(|| object.block_map())
+                .flatten()
+        });
         evm.bytecode = Some(solx_standard_json::OutputContractEVMBytecode::new(
             if is_bytecode_linked {
                 self.deploy_object_result
@@ -742,6 +861,85 @@ impl Contract {
                         })
                 })
                 .unwrap_or_default(),
+            self.deploy_object_result
+                .as_mut()
+                .map(|result| {
+                    result
+                        .as_mut()
+                        .expect("Always exists")
+                        .llvm_ir
+                        .take()
+                        .filter(|_| {
+                            output_selection.check_selection(
+                                self.name.path.as_str(),
+                                self.name.name.as_deref(),
+                                solx_standard_json::InputSelector::BytecodeLLVMIR,
+                            )
+                        })
+                })
+                .unwrap_or_default(),
+            self.deploy_object_result
+                .as_mut()
+                .map(|result| {
+                    result
+                        .as_mut()
+                        .expect("Always exists")
+                        .llvm_ir_optimized
+                        .take()
+                        .filter(|_| {
+                            output_selection.check_selection(
+                                self.name.path.as_str(),
+                                self.name.name.as_deref(),
+                                solx_standard_json::InputSelector::BytecodeLLVMIROptimized,
+                            )
+                        })
+                })
+                .unwrap_or_default(),
+            self.deploy_object_result
+                .as_mut()
+                .map(|result| {
+                    result
+                        .as_mut()
+                        .expect("Always exists")
+                        .llvm_remarks
+                        .take()
+                        .filter(|_| {
+                            output_selection.check_selection(
+                                self.name.path.as_str(),
+                                self.name.name.as_deref(),
+                                solx_standard_json::InputSelector::BytecodeLLVMRemarks,
+                            )
+                        })
+                })
+                .unwrap_or_default(),
+            self.deploy_object_result
+                .as_mut()
+                .map(|result| {
+                    result
+                        .as_mut()
+                        .expect("Always exists")
+                        .ethir
+                        .take()
+                        .filter(|_| {
+                            output_selection.check_selection(
+                                self.name.path.as_str(),
+                                self.name.name.as_deref(),
+                                solx_standard_json::InputSelector::BytecodeEthIR,
+                            )
+                        })
+                })
+                .unwrap_or_default(),
+            self.deploy_object_result.as_ref().and_then(|result| {
+                let object = result.as_ref().expect("Always exists");
+                output_selection
+                    .check_selection(
+                        self.name.path.as_str(),
+                        self.name.name.as_deref(),
+                        solx_standard_json::InputSelector::BytecodeStackDiagnostics,
+                    )
+                    .then(|| object.stack_diagnostics())
+            }),
+            deploy_block_map,
             if is_bytecode_linked
                 && output_selection.check_selection(
                     self.name.path.as_str(),
@@ -822,6 +1020,17 @@ impl Contract {
             None,
         ));
 
+        let runtime_block_map = self.runtime_object_result.as_ref().and_then(|result| {
+            let object = result.as_ref().expect("Always exists");
+            output_selection
+                .check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeBlockMap,
+                )
+                .then(|| object.block_map())
+                .flatten()
+        });
         evm.deployed_bytecode = Some(solx_standard_json::OutputContractEVMBytecode::new(
             if is_bytecode_linked {
                 self.runtime_object_result
@@ -861,6 +1070,85 @@ impl Contract {
                         })
                 })
                 .unwrap_or_default(),
+            self.runtime_object_result
+                .as_mut()
+                .map(|result| {
+                    result
+                        .as_mut()
+                        .expect("Always exists")
+                        .llvm_ir
+                        .take()
+                        .filter(|_| {
+                            output_selection.check_selection(
+                                self.name.path.as_str(),
+                                self.name.name.as_deref(),
+                                solx_standard_json::InputSelector::RuntimeBytecodeLLVMIR,
+                            )
+                        })
+                })
+                .unwrap_or_default(),
+            self.runtime_object_result
+                .as_mut()
+                .map(|result| {
+                    result
+                        .as_mut()
+                        .expect("Always exists")
+                        .llvm_ir_optimized
+                        .take()
+                        .filter(|_| {
+                            output_selection.check_selection(
+                                self.name.path.as_str(),
+                                self.name.name.as_deref(),
+                                solx_standard_json::InputSelector::RuntimeBytecodeLLVMIROptimized,
+                            )
+                        })
+                })
+                .unwrap_or_default(),
+            self.runtime_object_result
+                .as_mut()
+                .map(|result| {
+                    result
+                        .as_mut()
+                        .expect("Always exists")
+                        .llvm_remarks
+                        .take()
+                        .filter(|_| {
+                            output_selection.check_selection(
+                                self.name.path.as_str(),
+                                self.name.name.as_deref(),
+                                solx_standard_json::InputSelector::RuntimeBytecodeLLVMRemarks,
+                            )
+                        })
+                })
+                .unwrap_or_default(),
+            self.runtime_object_result
+                .as_mut()
+                .map(|result| {
+                    result
+                        .as_mut()
+                        .expect("Always exists")
+                        .ethir
+                        .take()
+                        .filter(|_| {
+                            output_selection.check_selection(
+                                self.name.path.as_str(),
+                                self.name.name.as_deref(),
+                                solx_standard_json::InputSelector::RuntimeBytecodeEthIR,
+                            )
+                        })
+                })
+                .unwrap_or_default(),
+            self.runtime_object_result.as_ref().and_then(|result| {
+                let object = result.as_ref().expect("Always exists");
+                output_selection
+                    .check_selection(
+                        self.name.path.as_str(),
+                        self.name.name.as_deref(),
+                        solx_standard_json::InputSelector::RuntimeBytecodeStackDiagnostics,
+                    )
+                    .then(|| object.stack_diagnostics())
+            }),
+            runtime_block_map,
             if is_bytecode_linked
                 && output_selection.check_selection(
                     self.name.path.as_str(),
@@ -950,6 +1238,41 @@ impl Contract {
         ));
     }
 
+    ///
+    /// Writes the LLVM optimization remarks of both code segments to the specified directory.
+    ///
+    pub fn write_llvm_remarks(&self, output_directory: &Path) -> anyhow::Result<()> {
+        for (object_result, code_segment) in [
+            (
+                self.deploy_object_result.as_ref(),
+                solx_utils::CodeSegment::Deploy,
+            ),
+            (
+                self.runtime_object_result.as_ref(),
+                solx_utils::CodeSegment::Runtime,
+            ),
+        ] {
+            let Some(Ok(object)) = object_result else {
+                continue;
+            };
+            let Some(ref llvm_remarks) = object.llvm_remarks else {
+                continue;
+            };
+
+            let output_name = format!(
+                "{}.{code_segment}.remarks.{}",
+                self.name.full_path.replace(['\\', '/', ':'], "_"),
+                solx_utils::EXTENSION_JSON,
+            );
+            let mut output_path = output_directory.to_owned();
+            output_path.push(output_name.as_str());
+
+            let output = serde_json::to_string_pretty(llvm_remarks).expect("Always valid");
+            Self::write_to_file(output_path.as_path(), output, true)?;
+        }
+        Ok(())
+    }
+
     ///
     /// Writes data to the file, checking the `overwrite` flag.
     ///
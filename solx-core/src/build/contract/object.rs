@@ -18,6 +18,14 @@ pub struct Object {
     pub contract_name: solx_utils::ContractName,
     /// Text assembly.
     pub assembly: Option<String>,
+    /// Unoptimized LLVM IR.
+    pub llvm_ir: Option<String>,
+    /// Optimized LLVM IR.
+    pub llvm_ir_optimized: Option<String>,
+    /// LLVM optimization remarks.
+    pub llvm_remarks: Option<Vec<solx_utils::LLVMRemark>>,
+    /// EthIR, produced only by the EVM legacy assembly pipeline.
+    pub ethir: Option<String>,
     /// Bytecode.
     pub bytecode: Option<Vec<u8>>,
     /// Hexadecimal bytecode.
@@ -39,10 +47,14 @@ pub struct Object {
     pub is_assembled: bool,
     /// Whether the size fallback was activated during the compilation.
     pub is_size_fallback: bool,
+    /// The spill area size the object has been compiled with, if stack-too-deep errors required spilling.
+    pub spill_area_size: Option<u64>,
     /// Compilation warnings.
     pub warnings: Vec<solx_codegen_evm::Warning>,
     /// Compilation pipeline benchmarks.
     pub benchmarks: Vec<(String, u64)>,
+    /// Compilation pipeline trace events.
+    pub trace_events: Vec<solx_codegen_evm::TraceEvent>,
 }
 
 impl Object {
@@ -56,6 +68,10 @@ impl Object {
         identifier: String,
         contract_name: solx_utils::ContractName,
         assembly: Option<String>,
+        llvm_ir: Option<String>,
+        llvm_ir_optimized: Option<String>,
+        llvm_remarks: Option<Vec<solx_utils::LLVMRemark>>,
+        ethir: Option<String>,
         bytecode: Option<Vec<u8>>,
         via_ir: bool,
         code_segment: solx_utils::CodeSegment,
@@ -65,12 +81,17 @@ impl Object {
         is_size_fallback: bool,
         warnings: Vec<solx_codegen_evm::Warning>,
         benchmarks: Vec<(String, u64)>,
+        trace_events: Vec<solx_codegen_evm::TraceEvent>,
     ) -> Self {
         let bytecode_hex = bytecode.as_ref().map(hex::encode);
         Self {
             identifier,
             contract_name,
             assembly,
+            llvm_ir,
+            llvm_ir_optimized,
+            llvm_remarks,
+            ethir,
             bytecode,
             bytecode_hex,
             via_ir,
@@ -81,8 +102,10 @@ impl Object {
             unlinked_symbols: BTreeMap::new(),
             is_assembled: false,
             is_size_fallback,
+            spill_area_size: None,
             warnings,
             benchmarks,
+            trace_events,
         }
     }
 
@@ -211,6 +234,27 @@ impl Object {
         Ok(())
     }
 
+    ///
+    /// Returns the stack-too-deep resolution diagnostics.
+    ///
+    pub fn stack_diagnostics(
+        &self,
+    ) -> solx_standard_json::OutputContractEVMBytecodeStackDiagnostics {
+        solx_standard_json::OutputContractEVMBytecodeStackDiagnostics::new(
+            self.spill_area_size,
+            self.is_size_fallback,
+        )
+    }
+
+    ///
+    /// Returns the mapping of bytecode offsets to LLVM basic blocks, if the assembly is available.
+    ///
+    pub fn block_map(&self) -> Option<solx_utils::BlockMap> {
+        self.assembly
+            .as_deref()
+            .map(solx_utils::BlockMap::from_llvm_assembly)
+    }
+
     ///
     /// Extracts warnings in standard JSON format.
     ///
@@ -33,6 +33,8 @@ pub struct Build {
     pub messages: Arc<Mutex<Vec<solx_standard_json::OutputError>>>,
     /// Compilation pipeline benchmarks.
     pub benchmarks: Vec<(String, u64)>,
+    /// Compilation pipeline trace events.
+    pub trace_events: Vec<solx_codegen_evm::TraceEvent>,
 }
 
 impl Build {
@@ -49,6 +51,7 @@ impl Build {
             ast_jsons,
             messages,
             benchmarks: Vec::new(),
+            trace_events: Vec::new(),
         }
     }
 
@@ -150,6 +153,45 @@ impl Build {
         Self::new(self.contracts, ast_jsons, self.messages)
     }
 
+    ///
+    /// Writes the LLVM optimization remarks of all contracts to the specified directory.
+    ///
+    pub fn write_llvm_remarks(&self, output_directory: &Path) -> anyhow::Result<()> {
+        std::fs::create_dir_all(output_directory)?;
+        for contract in self.contracts.values() {
+            contract.write_llvm_remarks(output_directory)?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Writes the trace events of the pipeline and all successfully compiled objects
+    /// to the specified file in the Chrome trace-event JSON format.
+    ///
+    pub fn write_time_trace(&self, path: &Path) -> anyhow::Result<()> {
+        let mut trace_events = self.trace_events.clone();
+        for contract in self.contracts.values() {
+            for object in [
+                contract.deploy_object_result.as_ref(),
+                contract.runtime_object_result.as_ref(),
+            ]
+            .into_iter()
+            .flatten()
+            .filter_map(|object_result| object_result.as_ref().ok())
+            {
+                trace_events.extend(object.trace_events.iter().cloned());
+            }
+        }
+        trace_events.sort_by_key(|event| event.timestamp);
+
+        let trace = serde_json::json!({
+            "traceEvents": trace_events,
+            "displayTimeUnit": "ms",
+        });
+        std::fs::write(path, trace.to_string())
+            .map_err(|error| anyhow::anyhow!("Time trace file {path:?} writing: {error}"))
+    }
+
     ///
     /// Writes all contracts to the terminal.
     ///
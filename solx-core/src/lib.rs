@@ -16,30 +16,40 @@ pub mod error;
 pub mod process;
 pub mod project;
 pub mod solc;
+pub mod verification;
 pub mod yul;
 
 pub use self::arguments::Arguments;
+pub use self::arguments::Command;
 pub use self::build::contract::Contract as EVMContractBuild;
 pub use self::build::Build as EVMBuild;
 pub use self::error::stack_too_deep::StackTooDeep as StackTooDeepError;
 pub use self::error::Error;
+pub use self::process::execution_mode::ExecutionMode;
 pub use self::process::input::Input as EVMProcessInput;
 pub use self::process::output::Output as EVMProcessOutput;
 pub use self::process::run as run_recursive;
 pub use self::process::EXECUTABLE;
+pub use self::process::EXECUTION_MODE;
 pub use self::project::contract::Contract as ProjectContract;
 pub use self::project::Project;
 pub use self::r#const::*;
 pub use self::solc::Solc;
+pub use self::verification::metadata::Metadata as VerificationMetadata;
+pub use self::verification::status::Status as VerificationStatus;
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::Mutex;
 
 use solx_standard_json::CollectableError;
 
+use self::project::contract::ir::yul::Yul as ProjectContractYul;
+
 /// The default error compatible with `solc` standard JSON output.
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -80,9 +90,18 @@ pub fn main(
         return self::run_recursive();
     }
 
+    if let Some(Command::Verify { metadata, bytecode }) = arguments.command {
+        return self::verify(solc, metadata.as_path(), bytecode.as_str(), messages);
+    }
+
     let (input_files, remappings) = arguments.split_input_files_and_remappings()?;
 
     let mut optimizer_settings = match arguments.optimization {
+        _ if arguments.optimization_auto => solx_codegen_evm::OptimizerSettings::auto(
+            arguments
+                .optimization_objective
+                .unwrap_or(solx_utils::OptimizationObjective::Gas),
+        ),
         Some(mode) => solx_codegen_evm::OptimizerSettings::try_from_cli(mode)?,
         None if arguments.standard_json.is_none() => {
             if let Ok(optimization) = std::env::var(SOLX_OPTIMIZATION_ENV) {
@@ -107,6 +126,15 @@ pub fn main(
     }
     optimizer_settings.is_verify_each_enabled = arguments.llvm_verify_each;
     optimizer_settings.is_debug_logging_enabled = arguments.llvm_debug_logging;
+    if let Some(passes) = arguments.llvm_passes {
+        optimizer_settings.set_passes(passes);
+    }
+    solx_codegen_evm::Optimizer::validate_passes(&optimizer_settings)?;
+    let profile = arguments
+        .profile_use
+        .as_deref()
+        .map(solx_utils::ExecutionProfile::try_from_path)
+        .transpose()?;
 
     let mut selectors = BTreeSet::new();
     if arguments.output_bytecode {
@@ -149,12 +177,23 @@ pub fn main(
     if arguments.output_ir {
         selectors.insert(solx_standard_json::InputSelector::Yul);
     }
+    if arguments.output_yul_ast_json {
+        selectors.insert(solx_standard_json::InputSelector::YulAST);
+    }
     if arguments.output_benchmarks {
         selectors.insert(solx_standard_json::InputSelector::Benchmarks);
     }
+    if arguments.output_stack_diagnostics {
+        selectors.insert(solx_standard_json::InputSelector::BytecodeStackDiagnostics);
+        selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeStackDiagnostics);
+    }
+    if arguments.llvm_remarks.is_some() {
+        selectors.insert(solx_standard_json::InputSelector::BytecodeLLVMRemarks);
+        selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeLLVMRemarks);
+    }
     let output_selection = solx_standard_json::InputSelection::new(selectors);
 
-    let llvm_options: Vec<String> = arguments
+    let mut llvm_options: Vec<String> = arguments
         .llvm_options
         .as_ref()
         .map(|options| {
@@ -164,6 +203,19 @@ pub fn main(
                 .collect()
         })
         .unwrap_or_default();
+    if arguments.llvm_remarks_filter.is_some() || !arguments.llvm_remarks_kind.is_empty() {
+        let regex = arguments.llvm_remarks_filter.as_deref().unwrap_or(".*");
+        let kinds = if arguments.llvm_remarks_kind.is_empty() {
+            vec![
+                solx_utils::LLVMRemarkKind::Passed,
+                solx_utils::LLVMRemarkKind::Missed,
+                solx_utils::LLVMRemarkKind::Analysis,
+            ]
+        } else {
+            arguments.llvm_remarks_kind.clone()
+        };
+        llvm_options.extend(kinds.iter().map(|kind| kind.llvm_option(regex)));
+    }
 
     let debug_config = match arguments
         .debug_output_dir
@@ -175,6 +227,7 @@ pub fn main(
             std::fs::create_dir_all(debug_output_directory.as_path())?;
             Some(solx_codegen_evm::DebugConfig::new(
                 debug_output_directory.to_owned(),
+                arguments.debug_output_dot,
             ))
         }
         None => None,
@@ -199,6 +252,8 @@ pub fn main(
             optimizer_settings,
             llvm_options,
             debug_config,
+            profile,
+            arguments.time_trace.is_some(),
         )
     } else if arguments.llvm_ir {
         self::llvm_ir_to_evm(
@@ -212,6 +267,8 @@ pub fn main(
             optimizer_settings,
             llvm_options,
             debug_config,
+            profile,
+            arguments.time_trace.is_some(),
         )
     } else if let Some(standard_json) = arguments.standard_json {
         return self::standard_json_evm(
@@ -222,7 +279,9 @@ pub fn main(
             arguments.include_path,
             arguments.allow_paths,
             use_import_callback,
+            arguments.time_trace,
             debug_config,
+            profile,
         );
     } else {
         self::standard_output_evm(
@@ -244,9 +303,18 @@ pub fn main(
             optimizer_settings,
             llvm_options,
             debug_config,
+            profile,
+            arguments.time_trace.is_some(),
         )
     }?;
 
+    if let Some(ref time_trace) = arguments.time_trace {
+        build.write_time_trace(time_trace.as_path())?;
+    }
+    if let Some(ref llvm_remarks) = arguments.llvm_remarks {
+        build.write_llvm_remarks(llvm_remarks.as_path())?;
+    }
+
     if output_selection.is_empty() {
         writeln!(
             std::io::stdout(),
@@ -279,20 +347,35 @@ pub fn yul_to_evm(
     optimizer_settings: solx_codegen_evm::OptimizerSettings,
     llvm_options: Vec<String>,
     debug_config: Option<solx_codegen_evm::DebugConfig>,
+    profile: Option<solx_utils::ExecutionProfile>,
+    is_time_trace_enabled: bool,
 ) -> anyhow::Result<EVMBuild> {
     let libraries = solx_utils::Libraries::try_from(libraries)?;
     let linker_symbols = libraries.as_linker_symbols()?;
 
-    solc.validate_yul_paths(paths, libraries.clone())?;
+    let yul_paths = paths
+        .iter()
+        .filter(|path| {
+            path.extension().and_then(|extension| extension.to_str())
+                != Some(solx_utils::EXTENSION_JSON)
+        })
+        .cloned()
+        .collect::<Vec<PathBuf>>();
+    if !yul_paths.is_empty() {
+        solc.validate_yul_paths(yul_paths.as_slice(), libraries.clone())?;
+    }
 
+    let mut solc_output = solx_standard_json::Output::new(&BTreeMap::new());
     let project = Project::try_from_yul_paths(
         solc.version(),
         paths,
         libraries,
         output_selection,
-        None,
+        Some(&mut solc_output),
         debug_config.as_ref(),
     )?;
+    solc_output.take_and_write_warnings();
+    solc_output.check_errors()?;
 
     let mut build = project.compile_to_evm(
         messages,
@@ -303,6 +386,8 @@ pub fn yul_to_evm(
         optimizer_settings,
         llvm_options,
         debug_config,
+        profile.as_ref(),
+        is_time_trace_enabled,
     )?;
     build.take_and_write_warnings();
     build.check_errors()?;
@@ -331,6 +416,8 @@ pub fn llvm_ir_to_evm(
     optimizer_settings: solx_codegen_evm::OptimizerSettings,
     llvm_options: Vec<String>,
     debug_config: Option<solx_codegen_evm::DebugConfig>,
+    profile: Option<solx_utils::ExecutionProfile>,
+    is_time_trace_enabled: bool,
 ) -> anyhow::Result<EVMBuild> {
     let libraries = solx_utils::Libraries::try_from(libraries)?;
     let linker_symbols = libraries.as_linker_symbols()?;
@@ -346,6 +433,8 @@ pub fn llvm_ir_to_evm(
         optimizer_settings,
         llvm_options,
         debug_config,
+        profile.as_ref(),
+        is_time_trace_enabled,
     )?;
     build.take_and_write_warnings();
     build.check_errors()?;
@@ -382,6 +471,8 @@ pub fn standard_output_evm(
     optimizer_settings: solx_codegen_evm::OptimizerSettings,
     llvm_options: Vec<String>,
     debug_config: Option<solx_codegen_evm::DebugConfig>,
+    profile: Option<solx_utils::ExecutionProfile>,
+    is_time_trace_enabled: bool,
 ) -> anyhow::Result<EVMBuild> {
     let mut profiler = solx_codegen_evm::Profiler::default();
 
@@ -433,6 +524,8 @@ pub fn standard_output_evm(
         optimizer_settings.clone(),
         llvm_options,
         debug_config.clone(),
+        profile.as_ref(),
+        is_time_trace_enabled,
     )?;
     run_solx_compile.borrow_mut().finish();
     build.take_and_write_warnings();
@@ -453,6 +546,8 @@ pub fn standard_output_evm(
         build
     };
     build.benchmarks = profiler.to_vec();
+    build.trace_events =
+        profiler.to_trace_events(solx_codegen_evm::TraceEvent::CATEGORY_PIPELINE, 0);
     Ok(build)
 }
 
@@ -467,11 +562,27 @@ pub fn standard_json_evm(
     include_paths: Vec<String>,
     allow_paths: Option<String>,
     use_import_callback: bool,
+    time_trace: Option<PathBuf>,
     debug_config: Option<solx_codegen_evm::DebugConfig>,
+    profile: Option<solx_utils::ExecutionProfile>,
 ) -> anyhow::Result<()> {
     let mut solc_input = solx_standard_json::Input::try_from(json_path.as_deref())?;
     let language = solc_input.language;
     let via_ir = solc_input.settings.via_ir;
+    if let Some(stop_after) = solc_input.settings.stop_after {
+        if language != solx_standard_json::InputLanguage::Solidity {
+            anyhow::bail!("`settings.stopAfter` is only supported for Solidity.");
+        }
+        if solc_input
+            .settings
+            .output_selection
+            .is_codegen_set_for_any()
+        {
+            anyhow::bail!(
+                "Requested output selection conflicts with `settings.stopAfter` set to `{stop_after}`: only AST, ABI, metadata, documentation, storage layouts, and method identifiers are available."
+            );
+        }
+    }
     let linker_symbols = solc_input.settings.libraries.as_linker_symbols()?;
 
     let optimization_mode = if let Ok(optimization) = std::env::var(SOLX_OPTIMIZATION_ENV) {
@@ -500,11 +611,33 @@ pub fn standard_json_evm(
     {
         optimizer_settings.enable_fallback_to_size();
     }
+    if let Some(ref passes) = solc_input.settings.optimizer.passes {
+        optimizer_settings.set_passes(passes.to_owned());
+    }
+    if let Some(objective) = solc_input.settings.optimizer.objective {
+        if optimizer_settings.is_fallback_to_size_enabled() || optimizer_settings.passes.is_some() {
+            anyhow::bail!(
+                "`settings.optimizer.objective` cannot be combined with the size fallback or custom LLVM passes."
+            );
+        }
+        optimizer_settings.set_objective(objective);
+    }
+    solx_codegen_evm::Optimizer::validate_passes(&optimizer_settings)?;
     let llvm_options = solc_input.settings.llvm_options.clone();
 
     let metadata_hash_type = solc_input.settings.metadata.bytecode_hash;
     let append_cbor = solc_input.settings.metadata.append_cbor;
 
+    let mut hash_output = solx_standard_json::Output::new(&solc_input.sources);
+    for (path, source) in solc_input.sources.iter() {
+        if let Err(error) = source.check_keccak256() {
+            hash_output.push_error(Some(path.to_owned()), error);
+        }
+    }
+    if hash_output.has_errors() {
+        hash_output.write_and_exit(&solc_input.settings.output_selection);
+    }
+
     let mut profiler = solx_codegen_evm::Profiler::default();
     let (mut solc_output, project) = match language {
         solx_standard_json::InputLanguage::Solidity => {
@@ -542,12 +675,28 @@ pub fn standard_json_evm(
             (solc_output, project)
         }
         solx_standard_json::InputLanguage::Yul => {
-            let run_solc_validate_yul = profiler.start_pipeline_element("solc_Yul_Validation");
-            let mut solc_output = solc.validate_yul_standard_json(&mut solc_input)?;
-            run_solc_validate_yul.borrow_mut().finish();
+            let (ast_json_sources, yul_sources): (BTreeMap<_, _>, BTreeMap<_, _>) =
+                std::mem::take(&mut solc_input.sources)
+                    .into_iter()
+                    .partition(|(_, source)| {
+                        source
+                            .content()
+                            .is_some_and(ProjectContractYul::is_ast_json)
+                    });
+            solc_input.sources = yul_sources;
+
+            let mut solc_output = if solc_input.sources.is_empty() {
+                solx_standard_json::Output::new(&ast_json_sources)
+            } else {
+                let run_solc_validate_yul = profiler.start_pipeline_element("solc_Yul_Validation");
+                let solc_output = solc.validate_yul_standard_json(&mut solc_input)?;
+                run_solc_validate_yul.borrow_mut().finish();
+                solc_output
+            };
             if solc_output.has_errors() {
                 solc_output.write_and_exit(&solc_input.settings.output_selection);
             }
+            solc_input.sources.extend(ast_json_sources);
 
             let run_solx_yul_project = profiler.start_pipeline_element("solx_Yul_IR_Analysis");
             let project = Project::try_from_yul_sources(
@@ -585,7 +734,7 @@ pub fn standard_json_evm(
     };
 
     let run_solx_compile = profiler.start_pipeline_element("solx_Compilation");
-    let build = project.compile_to_evm(
+    let mut build = project.compile_to_evm(
         messages,
         &solc_input.settings.output_selection,
         solc_input.settings.evm_version,
@@ -594,10 +743,17 @@ pub fn standard_json_evm(
         optimizer_settings.clone(),
         llvm_options,
         debug_config.clone(),
+        profile.as_ref(),
+        time_trace.is_some(),
     )?;
     run_solx_compile.borrow_mut().finish();
     let output_selection = solc_input.settings.output_selection.clone();
     if build.has_errors() {
+        if let Some(ref time_trace) = time_trace {
+            build.trace_events =
+                profiler.to_trace_events(solx_codegen_evm::TraceEvent::CATEGORY_PIPELINE, 0);
+            build.write_time_trace(time_trace.as_path())?;
+        }
         build.write_to_standard_json(
             &mut solc_output,
             &solc_input.settings.output_selection,
@@ -606,7 +762,7 @@ pub fn standard_json_evm(
         )?;
         solc_output.write_and_exit(&solc_input.settings.output_selection);
     }
-    let build = if output_selection.is_bytecode_set_for_any() {
+    let mut build = if output_selection.is_bytecode_set_for_any() {
         let run_solx_link = profiler.start_pipeline_element("solx_Linking");
         let build = build.link(linker_symbols);
         run_solx_link.borrow_mut().finish();
@@ -614,6 +770,161 @@ pub fn standard_json_evm(
     } else {
         build
     };
+    if let Some(ref time_trace) = time_trace {
+        build.trace_events =
+            profiler.to_trace_events(solx_codegen_evm::TraceEvent::CATEGORY_PIPELINE, 0);
+        build.write_time_trace(time_trace.as_path())?;
+    }
     build.write_to_standard_json(&mut solc_output, &output_selection, true, profiler.to_vec())?;
     solc_output.write_and_exit(&output_selection);
 }
+
+///
+/// Recompiles the sources embedded into the contract metadata and compares the result with the deployed bytecode.
+///
+pub fn verify(
+    solc: impl Solc,
+    metadata_path: &Path,
+    bytecode: &str,
+    messages: Arc<Mutex<Vec<solx_standard_json::OutputError>>>,
+) -> anyhow::Result<()> {
+    let deployed_bytecode = hex::decode(bytecode.trim().trim_start_matches("0x"))
+        .map_err(|error| anyhow::anyhow!("Bytecode hexadecimal decoding: {error}"))?;
+
+    let metadata = std::fs::read_to_string(metadata_path)
+        .map_err(|error| anyhow::anyhow!("Metadata file {metadata_path:?} reading: {error}"))?;
+    let mut metadata = VerificationMetadata::try_from_str(metadata.as_str())?;
+    if metadata.language != solx_standard_json::InputLanguage::Solidity {
+        anyhow::bail!("Only Solidity contracts can be verified.");
+    }
+    let full_path = metadata.compilation_target()?;
+
+    let solc_version = solc.version();
+    let solx_version = metadata
+        .solx
+        .solx_version
+        .as_ref()
+        .map(|version| version.to_string())
+        .unwrap_or_default();
+    if solx_version != crate::r#const::version() {
+        messages
+            .lock()
+            .expect("Sync")
+            .push(solx_standard_json::OutputError::new_warning(format!(
+                "The contract was compiled with `{DEFAULT_EXECUTABLE_NAME}` v{solx_version}, but v{} is used for verification.",
+                crate::r#const::version(),
+            )));
+    }
+    if metadata.solx.solc_version.as_ref() != Some(&solc_version.default)
+        || metadata.solx.solc_llvm_revision.as_ref() != Some(&solc_version.llvm_revision)
+    {
+        messages
+            .lock()
+            .expect("Sync")
+            .push(solx_standard_json::OutputError::new_warning(format!(
+                "The contract was compiled with `solc` v{}, but v{} is used for verification.",
+                metadata
+                    .solx
+                    .solc_version
+                    .as_ref()
+                    .map(|version| version.to_string())
+                    .unwrap_or_default(),
+                solc_version.default,
+            )));
+    }
+
+    if let Some(ref profile_hash) = metadata.solx.profile_hash {
+        anyhow::bail!(
+            "The contract was compiled with the execution profile `{profile_hash}`, which cannot be reproduced from the metadata."
+        );
+    }
+
+    let mut optimizer_settings =
+        metadata.solx.optimizer_settings.take().ok_or_else(|| {
+            anyhow::anyhow!("The metadata does not contain the optimizer settings.")
+        })?;
+    optimizer_settings.is_verify_each_enabled = false;
+    optimizer_settings.is_debug_logging_enabled = false;
+    let llvm_options = std::mem::take(&mut metadata.solx.llvm_options);
+    let metadata_hash_type = metadata.settings.metadata.bytecode_hash;
+    let append_cbor = metadata.settings.metadata.append_cbor;
+
+    let output_selection = solx_standard_json::InputSelection::new(BTreeSet::from([
+        solx_standard_json::InputSelector::RuntimeBytecodeObject,
+    ]));
+    let mut solc_input = solx_standard_json::Input::try_from_solidity_sources(
+        metadata.take_sources()?,
+        metadata.libraries()?,
+        std::mem::take(&mut metadata.settings.remappings),
+        solx_standard_json::InputOptimizer {
+            mode: Some(optimizer_settings.middle_end_as_char()),
+            size_fallback: Some(optimizer_settings.is_fallback_to_size_enabled()),
+            passes: optimizer_settings.passes.clone(),
+            objective: optimizer_settings.objective,
+        },
+        metadata.settings.evm_version,
+        metadata.settings.via_ir,
+        &output_selection,
+        solx_standard_json::InputMetadata::new(true, append_cbor, metadata_hash_type),
+        llvm_options.clone(),
+    )?;
+
+    let mut solc_output = solc.standard_json(&mut solc_input, false, None, &[], None)?;
+    solc_output.take_and_write_warnings();
+    solc_output.check_errors()?;
+
+    let linker_symbols = solc_input.settings.libraries.as_linker_symbols()?;
+    let project = Project::try_from_solc_output(
+        solc_version,
+        solc_input.settings.libraries.clone(),
+        metadata.settings.via_ir,
+        &mut solc_output,
+        None,
+    )?;
+    solc_output.take_and_write_warnings();
+    solc_output.check_errors()?;
+
+    let mut build = project.compile_to_evm(
+        messages,
+        &solc_input.settings.output_selection,
+        metadata.settings.evm_version,
+        metadata_hash_type,
+        append_cbor,
+        optimizer_settings,
+        llvm_options,
+        None,
+        None,
+        false,
+    )?;
+    build.take_and_write_warnings();
+    build.check_errors()?;
+    let mut build = build.link(linker_symbols);
+    build.take_and_write_warnings();
+    build.check_errors()?;
+
+    let runtime_object = build
+        .contracts
+        .remove(full_path.as_str())
+        .and_then(|contract| contract.runtime_object_result)
+        .ok_or_else(|| anyhow::anyhow!("Contract `{full_path}` is not found in the build."))??;
+    let compiled_bytecode = runtime_object
+        .bytecode
+        .as_deref()
+        .ok_or_else(|| anyhow::anyhow!("Contract `{full_path}` bytecode is not assembled."))?;
+    if !runtime_object.unlinked_symbols.is_empty() {
+        anyhow::bail!("Contract `{full_path}` has unlinked libraries.");
+    }
+
+    match VerificationStatus::compare(
+        compiled_bytecode,
+        deployed_bytecode.as_slice(),
+        runtime_object.immutables.as_ref(),
+        append_cbor,
+    ) {
+        Some(status) => {
+            writeln!(std::io::stdout(), "Contract `{full_path}`: {status}.")?;
+            Ok(())
+        }
+        None => anyhow::bail!("Contract `{full_path}`: the bytecode does not match."),
+    }
+}
@@ -0,0 +1,29 @@
+//!
+//! Execution mode of translation unit compilation.
+//!
+
+///
+/// Execution mode of translation unit compilation.
+///
+/// The mode can be selected by library users via [`crate::process::EXECUTION_MODE`] before
+/// the project is compiled. The `solx` executable always uses the subprocess mode.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionMode {
+    /// Each translation unit is compiled by a recursive `solx` process.
+    ///
+    /// Requires the `solx` executable, which can be overridden via [`crate::process::EXECUTABLE`].
+    #[default]
+    Subprocess,
+    /// Each translation unit is compiled by a worker thread of the current process with its own LLVM context.
+    ///
+    /// The EVM target must be initialized with `solx_codegen_evm::initialize_target` beforehand.
+    /// As LLVM options are process-global, translation units with different LLVM options, such as
+    /// the deploy and runtime code, or the ones requiring a spill area, are not compiled concurrently.
+    /// The `solx` executable is never spawned.
+    InProcess {
+        /// Whether panics of worker threads are reported as compilation errors
+        /// instead of being propagated to the caller.
+        is_panic_isolated: bool,
+    },
+}
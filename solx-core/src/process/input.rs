@@ -26,6 +26,8 @@ pub struct Input {
     pub evm_version: Option<solx_utils::EVMVersion>,
     /// The mapping of auxiliary identifiers, e.g. Yul object names, to full contract paths.
     pub identifier_paths: BTreeMap<String, String>,
+    /// The mapping of `solc` source file identifiers to source file paths.
+    pub source_paths: BTreeMap<usize, String>,
     /// Output selection for the compilation.
     pub output_selection: solx_standard_json::InputSelection,
     /// Immutables produced by the runtime code run.
@@ -38,6 +40,10 @@ pub struct Input {
     pub llvm_options: Vec<String>,
     /// The debug output config.
     pub debug_config: Option<solx_codegen_evm::DebugConfig>,
+    /// The execution profile for profile-guided optimization.
+    pub profile: Option<solx_utils::ExecutionProfileUnit>,
+    /// Whether the LLVM passes are traced.
+    pub is_time_trace_enabled: bool,
 }
 
 impl Input {
@@ -51,12 +57,15 @@ impl Input {
         code_segment: solx_utils::CodeSegment,
         evm_version: Option<solx_utils::EVMVersion>,
         identifier_paths: BTreeMap<String, String>,
+        source_paths: BTreeMap<usize, String>,
         output_selection: solx_standard_json::InputSelection,
         immutables: Option<BTreeMap<String, BTreeSet<u64>>>,
         metadata_bytes: Option<Vec<u8>>,
         optimizer_settings: solx_codegen_evm::OptimizerSettings,
         llvm_options: Vec<String>,
         debug_config: Option<solx_codegen_evm::DebugConfig>,
+        profile: Option<solx_utils::ExecutionProfileUnit>,
+        is_time_trace_enabled: bool,
     ) -> Self {
         Self {
             solc_version,
@@ -65,12 +74,15 @@ impl Input {
             code_segment,
             evm_version,
             identifier_paths,
+            source_paths,
             output_selection,
             immutables,
             metadata_bytes,
             optimizer_settings,
             llvm_options,
             debug_config,
+            profile,
+            is_time_trace_enabled,
         }
     }
 }
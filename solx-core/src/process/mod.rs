@@ -2,9 +2,11 @@
 //! Process for compiling a single compilation unit.
 //!
 
+pub mod execution_mode;
 pub mod input;
 pub mod output;
 
+use std::cell::Cell;
 use std::io::Read;
 use std::io::Write;
 use std::path::PathBuf;
@@ -12,15 +14,25 @@ use std::process::Command;
 use std::sync::OnceLock;
 use std::thread::Builder;
 
+use crate::error::stack_too_deep::StackTooDeep;
 use crate::error::Error;
 use crate::project::contract::Contract;
 
+use self::execution_mode::ExecutionMode;
 use self::input::Input as EVMInput;
 use self::output::Output as EVMOutput;
 
 /// The overridden executable name used when the compiler is run as a library.
 pub static EXECUTABLE: OnceLock<PathBuf> = OnceLock::new();
 
+/// The execution mode selected when the compiler is run as a library.
+pub static EXECUTION_MODE: OnceLock<ExecutionMode> = OnceLock::new();
+
+thread_local! {
+    /// The stack-too-deep error reported by LLVM in the current in-process worker thread.
+    static STACK_TOO_DEEP: Cell<Option<StackTooDeep>> = const { Cell::new(None) };
+}
+
 ///
 /// Read input from `stdin`, compile a contract, and write the output to `stdout`.
 ///
@@ -40,38 +52,9 @@ pub fn run() -> anyhow::Result<()> {
         ciborium::de::from_reader_with_recursion_limit(buffer.as_slice(), usize::MAX)
             .map_err(|error| anyhow::anyhow!("Input deserialziing error: {error}"))?;
 
-    let source_location =
-        solx_standard_json::OutputErrorSourceLocation::new(input.contract_name.path.clone());
-
     let result = Builder::new()
         .stack_size(crate::WORKER_THREAD_STACK_SIZE)
-        .spawn(move || {
-            Contract::compile_to_evm(
-                input.solc_version,
-                input.contract_name,
-                input.contract_ir,
-                input.code_segment,
-                input.evm_version,
-                input.identifier_paths,
-                input.output_selection,
-                input.immutables,
-                input.metadata_bytes,
-                input.optimizer_settings,
-                input.llvm_options,
-                input.debug_config,
-            )
-            .map(EVMOutput::new)
-            .map_err(|error| match error {
-                Error::Generic(error) => solx_standard_json::OutputError::new_error_with_data(
-                    None,
-                    error,
-                    Some(source_location),
-                    None,
-                )
-                .into(),
-                error => error,
-            })
-        })
+        .spawn(move || compile(input))
         .expect("Threading error")
         .join()
         .expect("Threading error");
@@ -159,6 +142,90 @@ where
     }
 }
 
+///
+/// Compiles a single contract in a worker thread of the current process.
+///
+/// LLVM options are process-global, so the worker waits until the translation units with other
+/// LLVM options, such as a different metadata size or spill area, are compiled.
+///
+/// The input goes through the same serialization as in [`call`], so it can be reused for the next pass.
+///
+pub fn call_in_process(
+    path: &str,
+    input: &EVMInput,
+    is_panic_isolated: bool,
+) -> crate::Result<EVMOutput> {
+    let mut buffer = Vec::with_capacity(crate::r#const::DEFAULT_SERDE_BUFFER_SIZE);
+    ciborium::into_writer(input, &mut buffer)
+        .unwrap_or_else(|error| panic!("In-process input serializing error: {error:?}"));
+    let input: EVMInput =
+        ciborium::de::from_reader_with_recursion_limit(buffer.as_slice(), usize::MAX)
+            .unwrap_or_else(|error| panic!("In-process input deserializing error: {error:?}"));
+
+    run_worker(path, is_panic_isolated, move || compile(input))
+}
+
+///
+/// Runs `worker` in a new in-process worker thread.
+///
+/// The stack-too-deep error recorded by [`evm_stack_error_handler_in_process`] replaces the
+/// result of `worker`. Panics are converted into compilation errors if `is_panic_isolated`
+/// is set, and are propagated to the caller otherwise.
+///
+fn run_worker<F>(path: &str, is_panic_isolated: bool, worker: F) -> crate::Result<EVMOutput>
+where
+    F: FnOnce() -> crate::Result<EVMOutput> + Send + 'static,
+{
+    let result = Builder::new()
+        .stack_size(crate::WORKER_THREAD_STACK_SIZE)
+        .spawn(move || {
+            let result = worker();
+            match STACK_TOO_DEEP.take() {
+                Some(stack_too_deep) => Err(Error::StackTooDeep(stack_too_deep)),
+                None => result,
+            }
+        })
+        .expect("Threading error")
+        .join();
+
+    match result {
+        Ok(result) => result,
+        Err(payload) if is_panic_isolated => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic payload".to_owned());
+            Err(solx_standard_json::OutputError::new_error_with_data(
+                None,
+                format!("In-process worker thread panicked: {message}"),
+                Some(solx_standard_json::OutputErrorSourceLocation::new(
+                    path.to_owned(),
+                )),
+                None,
+            )
+            .into())
+        }
+        Err(payload) => std::panic::resume_unwind(payload),
+    }
+}
+
+///
+/// Installs the LLVM stack-too-deep error handler for the selected execution mode.
+///
+pub fn install_stack_error_handler() {
+    match EXECUTION_MODE.get().copied().unwrap_or_default() {
+        ExecutionMode::Subprocess => {
+            inkwell::support::error_handling::install_stack_error_handler(evm_stack_error_handler)
+        }
+        ExecutionMode::InProcess { .. } => {
+            inkwell::support::error_handling::install_stack_error_handler(
+                evm_stack_error_handler_in_process,
+            )
+        }
+    }
+}
+
 ///
 /// Handles LLVM stack-too-deep errors.
 ///
@@ -170,7 +237,7 @@ where
 pub unsafe extern "C" fn evm_stack_error_handler(spill_area_size: u64) {
     let result: Result<EVMOutput, Error> = Err(Error::stack_too_deep(
         spill_area_size,
-        solx_codegen_evm::IS_SIZE_FALLBACK.load(std::sync::atomic::Ordering::Relaxed),
+        solx_codegen_evm::IS_SIZE_FALLBACK.get(),
     ));
     let mut buffer = Vec::with_capacity(crate::r#const::DEFAULT_SERDE_BUFFER_SIZE);
     ciborium::into_writer(&result, &mut buffer)
@@ -181,3 +248,106 @@ pub unsafe extern "C" fn evm_stack_error_handler(spill_area_size: u64) {
     unsafe { inkwell::support::shutdown_llvm() };
     std::process::exit(solx_utils::EXIT_CODE_SUCCESS);
 }
+
+///
+/// Handles LLVM stack-too-deep errors in the in-process worker threads.
+///
+/// The process must not be terminated, and LLVM frames must not be unwound, so the error is
+/// only recorded, and replaces the compilation result in [`run_worker`] after LLVM returns.
+///
+/// # Safety
+///
+/// This function is unsafe because it is called from the LLVM stackifier.
+///
+pub unsafe extern "C" fn evm_stack_error_handler_in_process(spill_area_size: u64) {
+    STACK_TOO_DEEP.set(Some(StackTooDeep {
+        spill_area_size,
+        is_size_fallback: solx_codegen_evm::IS_SIZE_FALLBACK.get(),
+    }));
+}
+
+///
+/// Compiles a single contract from the `input`.
+///
+fn compile(input: EVMInput) -> crate::Result<EVMOutput> {
+    let source_location =
+        solx_standard_json::OutputErrorSourceLocation::new(input.contract_name.path.clone());
+
+    Contract::compile_to_evm(
+        input.solc_version,
+        input.contract_name,
+        input.contract_ir,
+        input.code_segment,
+        input.evm_version,
+        input.identifier_paths,
+        input.source_paths,
+        input.output_selection,
+        input.immutables,
+        input.metadata_bytes,
+        input.optimizer_settings,
+        input.llvm_options,
+        input.debug_config,
+        input.profile,
+        input.is_time_trace_enabled,
+    )
+    .map(EVMOutput::new)
+    .map_err(|error| match error {
+        Error::Generic(error) => solx_standard_json::OutputError::new_error_with_data(
+            None,
+            error,
+            Some(source_location),
+            None,
+        )
+        .into(),
+        error => error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+
+    #[test]
+    fn worker_stack_too_deep() {
+        let result = super::run_worker("Test", false, || {
+            unsafe { super::evm_stack_error_handler_in_process(64) };
+            Err(Error::Generic("Code generation failure".to_owned()))
+        });
+
+        match result {
+            Err(Error::StackTooDeep(stack_too_deep)) => {
+                assert_eq!(stack_too_deep.spill_area_size, 64);
+                assert!(!stack_too_deep.is_size_fallback);
+            }
+            result => panic!("Expected a stack-too-deep error, found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn worker_panic_isolated() {
+        let result = super::run_worker("Test", true, || panic!("Worker failure"));
+
+        match result {
+            Err(Error::StandardJson(error)) => {
+                assert!(error
+                    .message
+                    .contains("In-process worker thread panicked: Worker failure"));
+                assert_eq!(
+                    error.source_location.map(|location| location.file),
+                    Some("Test".to_owned())
+                );
+            }
+            result => panic!("Expected a standard JSON error, found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn worker_panic_propagated() {
+        let result = std::panic::catch_unwind(|| {
+            super::run_worker("Test", false, || panic!("Worker failure"))
+        });
+
+        let payload = result.expect_err("The worker panic must be propagated");
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"Worker failure"));
+    }
+}
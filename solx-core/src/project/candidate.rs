@@ -0,0 +1,107 @@
+//!
+//! The contract compilation candidate of the optimization mode auto-tuning.
+//!
+
+use crate::process::output::Output as EVMProcessOutput;
+
+///
+/// The contract compilation candidate of the optimization mode auto-tuning.
+///
+#[derive(Debug)]
+pub struct Candidate {
+    /// The optimizer settings the candidate was compiled with.
+    pub optimizer_settings: solx_codegen_evm::OptimizerSettings,
+    /// Combined `solc` and `solx` metadata, which embeds the candidate settings.
+    pub metadata: Option<String>,
+    /// The deploy code compilation result.
+    pub deploy_object_result: crate::Result<EVMProcessOutput>,
+    /// The runtime code compilation result.
+    pub runtime_object_result: crate::Result<EVMProcessOutput>,
+}
+
+impl Candidate {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        optimizer_settings: solx_codegen_evm::OptimizerSettings,
+        metadata: Option<String>,
+        deploy_object_result: crate::Result<EVMProcessOutput>,
+        runtime_object_result: crate::Result<EVMProcessOutput>,
+    ) -> Self {
+        Self {
+            optimizer_settings,
+            metadata,
+            deploy_object_result,
+            runtime_object_result,
+        }
+    }
+
+    ///
+    /// Selects the best candidate according to `objective`.
+    ///
+    /// Candidates within the EVM code size limits are preferred. Ties are resolved in favor
+    /// of the earlier candidate. If no candidate can be measured, e.g. due to compilation errors
+    /// or bytecode not being requested, the first candidate is selected.
+    ///
+    pub fn select(candidates: Vec<Self>, objective: solx_utils::OptimizationObjective) -> Self {
+        let mut best_index = 0;
+        let mut best_cost = None;
+        for (index, candidate) in candidates.iter().enumerate() {
+            let cost = match candidate.cost(objective) {
+                Some(cost) => cost,
+                None => continue,
+            };
+            if best_cost.is_none_or(|best_cost| cost < best_cost) {
+                best_index = index;
+                best_cost = Some(cost);
+            }
+        }
+        candidates
+            .into_iter()
+            .nth(best_index)
+            .expect("Always exists")
+    }
+
+    ///
+    /// Returns the middle-end optimization level of the candidate.
+    ///
+    pub fn optimization_mode(&self) -> char {
+        self.optimizer_settings.middle_end_as_char()
+    }
+
+    ///
+    /// Returns the cost of the candidate according to `objective`.
+    ///
+    /// The cost is a tuple of the size limit violation flag and the objective value,
+    /// so candidates exceeding the limits always lose.
+    ///
+    fn cost(&self, objective: solx_utils::OptimizationObjective) -> Option<(bool, u64)> {
+        let deploy_bytecode = self
+            .deploy_object_result
+            .as_ref()
+            .ok()?
+            .object
+            .bytecode
+            .as_deref()?;
+        let runtime_bytecode = self
+            .runtime_object_result
+            .as_ref()
+            .ok()?
+            .object
+            .bytecode
+            .as_deref()?;
+
+        let deploy_size = deploy_bytecode.len() + runtime_bytecode.len();
+        let is_size_limit_exceeded = runtime_bytecode.len()
+            > solx_codegen_evm::RUNTIME_CODE_SIZE_LIMIT
+            || deploy_size > solx_codegen_evm::DEPLOY_CODE_SIZE_LIMIT;
+
+        let value = match objective {
+            solx_utils::OptimizationObjective::RuntimeSize => runtime_bytecode.len() as u64,
+            solx_utils::OptimizationObjective::DeploySize => deploy_size as u64,
+            solx_utils::OptimizationObjective::Gas => solx_utils::static_gas(runtime_bytecode),
+        };
+        Some((is_size_limit_exceeded, value))
+    }
+}
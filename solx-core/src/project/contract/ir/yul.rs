@@ -2,9 +2,12 @@
 //! The contract Yul source code.
 //!
 
+use solx_yul::yul::analyzer::Analyzer;
+use solx_yul::yul::error::Error as YulError;
 use solx_yul::yul::lexer::Lexer;
 use solx_yul::yul::parser::statement::object::Object;
 
+use crate::yul::parser::dialect::era::EraDialect;
 use crate::yul::parser::wrapper::Wrap;
 
 ///
@@ -24,9 +27,15 @@ impl Yul {
     ///
     /// Transforms the `solc` standard JSON output contract into a Yul object.
     ///
+    /// The source code can be either Yul, or the Yul AST JSON emitted by the `yulAst` output.
+    ///
+    /// If `is_analyzed` is set, the object is checked by the semantic analyzer. It is only needed
+    /// for Yul inputs, as the IR emitted by `solc` is valid by construction.
+    ///
     pub fn try_from_source(
         path: &str,
         source_code: &str,
+        is_analyzed: bool,
         debug_config: Option<&solx_codegen_evm::DebugConfig>,
     ) -> anyhow::Result<Option<Self>> {
         if source_code.is_empty() {
@@ -37,9 +46,21 @@ impl Yul {
             debug_config.dump_yul(path, source_code)?;
         }
 
-        let mut lexer = Lexer::new(source_code);
-        let mut object = Object::parse(&mut lexer, None, solx_utils::CodeSegment::Deploy)
-            .map_err(|error| anyhow::anyhow!("Yul parsing: {error:?}"))?;
+        let mut object = if Self::is_ast_json(source_code) {
+            serde_json::from_str::<Object<EraDialect>>(source_code)
+                .map_err(|error| anyhow::anyhow!("Yul AST JSON parsing: {error}"))?
+        } else {
+            let mut lexer = Lexer::new(source_code);
+            Object::parse(&mut lexer, None, solx_utils::CodeSegment::Deploy).map_err(|error| {
+                let message = format!("Yul parsing: {error}");
+                anyhow::Error::new(error).context(message)
+            })?
+        };
+        if is_analyzed {
+            Analyzer::analyze(&object).map_err(|errors| {
+                YulError::from_errors(errors.into_iter().map(YulError::from).collect())
+            })?;
+        }
 
         let runtime_code = object.inner_object.take().map(|object| {
             let dependencies = object.get_evm_dependencies(None);
@@ -61,4 +82,26 @@ impl Yul {
             runtime_code: runtime_code.map(Box::new),
         }))
     }
+
+    ///
+    /// Whether the source code is a Yul AST JSON.
+    ///
+    /// Yul source code always starts with the `object` keyword, so a leading curly bracket
+    /// is enough to tell them apart.
+    ///
+    pub fn is_ast_json(source_code: &str) -> bool {
+        source_code.trim_start().starts_with('{')
+    }
+
+    ///
+    /// Returns the Yul AST JSON with the runtime code object nested into the deploy code one,
+    /// as it was parsed from the source code.
+    ///
+    pub fn ast_json(&self) -> serde_json::Value {
+        let mut ast_json = serde_json::to_value(&self.object).expect("Always valid");
+        if let Some(runtime_code) = self.runtime_code.as_ref() {
+            ast_json["inner_object"] = runtime_code.ast_json();
+        }
+        ast_json
+    }
 }
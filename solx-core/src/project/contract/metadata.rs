@@ -19,6 +19,9 @@ pub struct Metadata<'a> {
     pub optimizer_settings: solx_codegen_evm::OptimizerSettings,
     /// The LLVM extra arguments.
     pub llvm_options: &'a [String],
+    /// The Keccak-256 hash of the contract execution profile used for profile-guided optimization.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_hash: Option<String>,
 }
 
 impl<'a> Metadata<'a> {
@@ -29,6 +32,7 @@ impl<'a> Metadata<'a> {
         solc_version: Option<&solx_standard_json::Version>,
         optimizer_settings: solx_codegen_evm::OptimizerSettings,
         llvm_options: &'a [String],
+        profile_hash: Option<String>,
     ) -> Self {
         Self {
             solc_version: solc_version.map(|version| version.default.to_owned()),
@@ -36,6 +40,7 @@ impl<'a> Metadata<'a> {
             solx_version: crate::version().parse().expect("Always valid"),
             optimizer_settings,
             llvm_options,
+            profile_hash,
         }
     }
 
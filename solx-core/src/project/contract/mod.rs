@@ -101,23 +101,69 @@ impl Contract {
         code_segment: solx_utils::CodeSegment,
         evm_version: Option<solx_utils::EVMVersion>,
         identifier_paths: BTreeMap<String, String>,
+        source_paths: BTreeMap<usize, String>,
         output_selection: solx_standard_json::InputSelection,
         immutables: Option<BTreeMap<String, BTreeSet<u64>>>,
         metadata_bytes: Option<Vec<u8>>,
         mut optimizer_settings: solx_codegen_evm::OptimizerSettings,
         llvm_options: Vec<String>,
         debug_config: Option<solx_codegen_evm::DebugConfig>,
+        profile: Option<solx_utils::ExecutionProfileUnit>,
+        is_time_trace_enabled: bool,
     ) -> Result<EVMContractObject, Error> {
         use solx_codegen_evm::WriteLLVM;
-        let mut profiler = solx_codegen_evm::Profiler::default();
+        let mut profiler = solx_codegen_evm::Profiler::new(is_time_trace_enabled);
 
         if let Some(ref metadata_bytes) = metadata_bytes {
             optimizer_settings.set_metadata_size(metadata_bytes.len() as u64);
         }
 
-        let solidity_data = solx_codegen_evm::ContextSolidityData::new(immutables);
-        let optimizer = solx_codegen_evm::Optimizer::new(optimizer_settings.clone());
+        let solidity_data = solx_codegen_evm::ContextSolidityData::new(immutables, source_paths);
+        let mut optimizer = solx_codegen_evm::Optimizer::new(optimizer_settings.clone());
+        optimizer.set_profile(profile);
         let output_bytecode = output_selection.is_bytecode_set_for_any();
+        let [output_llvm_ir, output_llvm_ir_optimized, output_llvm_remarks, output_ethir] =
+            match code_segment {
+                solx_utils::CodeSegment::Deploy => [
+                    solx_standard_json::InputSelector::BytecodeLLVMIR,
+                    solx_standard_json::InputSelector::BytecodeLLVMIROptimized,
+                    solx_standard_json::InputSelector::BytecodeLLVMRemarks,
+                    solx_standard_json::InputSelector::BytecodeEthIR,
+                ],
+                solx_utils::CodeSegment::Runtime => [
+                    solx_standard_json::InputSelector::RuntimeBytecodeLLVMIR,
+                    solx_standard_json::InputSelector::RuntimeBytecodeLLVMIROptimized,
+                    solx_standard_json::InputSelector::RuntimeBytecodeLLVMRemarks,
+                    solx_standard_json::InputSelector::RuntimeBytecodeEthIR,
+                ],
+            }
+            .map(|selector| {
+                output_selection.check_selection(
+                    contract_name.path.as_str(),
+                    contract_name.name.as_deref(),
+                    selector,
+                )
+            });
+
+        // The block map is extracted from the LLVM assembly, so the latter is required for both.
+        let output_assembly = match code_segment {
+            solx_utils::CodeSegment::Deploy => [
+                solx_standard_json::InputSelector::BytecodeLLVMAssembly,
+                solx_standard_json::InputSelector::BytecodeBlockMap,
+            ],
+            solx_utils::CodeSegment::Runtime => [
+                solx_standard_json::InputSelector::RuntimeBytecodeLLVMAssembly,
+                solx_standard_json::InputSelector::RuntimeBytecodeBlockMap,
+            ],
+        }
+        .into_iter()
+        .any(|selector| {
+            output_selection.check_selection(
+                contract_name.path.as_str(),
+                contract_name.name.as_deref(),
+                selector,
+            )
+        });
 
         match (contract_ir, code_segment) {
             (IR::Yul(mut yul), solx_utils::CodeSegment::Deploy) => {
@@ -134,9 +180,7 @@ impl Contract {
                     optimizer,
                     debug_config.clone(),
                 );
-                inkwell::support::error_handling::install_stack_error_handler(
-                    crate::process::evm_stack_error_handler,
-                );
+                crate::process::install_stack_error_handler();
                 deploy_context.set_solidity_data(solidity_data);
                 deploy_context
                     .set_yul_data(solx_codegen_evm::ContextYulData::new(identifier_paths));
@@ -152,11 +196,10 @@ impl Contract {
                 })?;
                 run_yul_lowering.borrow_mut().finish();
                 let deploy_build = deploy_context.build(
-                    output_selection.check_selection(
-                        contract_name.path.as_str(),
-                        contract_name.name.as_deref(),
-                        solx_standard_json::InputSelector::BytecodeLLVMAssembly,
-                    ),
+                    output_assembly,
+                    output_llvm_ir,
+                    output_llvm_ir_optimized,
+                    output_llvm_remarks,
                     output_bytecode,
                     false,
                     &mut profiler,
@@ -165,6 +208,10 @@ impl Contract {
                     deploy_code_identifier,
                     contract_name.clone(),
                     deploy_build.assembly,
+                    deploy_build.llvm_ir,
+                    deploy_build.llvm_ir_optimized,
+                    deploy_build.llvm_remarks,
+                    deploy_build.ethir,
                     deploy_build.bytecode,
                     true,
                     code_segment,
@@ -174,6 +221,10 @@ impl Contract {
                     deploy_build.is_size_fallback,
                     deploy_build.warnings,
                     profiler.to_vec(),
+                    profiler.to_trace_events(
+                        solx_codegen_evm::TraceEvent::CATEGORY_TRANSLATION_UNIT,
+                        0,
+                    ),
                 );
                 Ok(deploy_object)
             }
@@ -192,9 +243,7 @@ impl Contract {
                     optimizer.clone(),
                     debug_config.clone(),
                 );
-                inkwell::support::error_handling::install_stack_error_handler(
-                    crate::process::evm_stack_error_handler,
-                );
+                crate::process::install_stack_error_handler();
                 runtime_context.set_solidity_data(solidity_data);
                 runtime_context.set_yul_data(solx_codegen_evm::ContextYulData::new(
                     identifier_paths.clone(),
@@ -213,11 +262,10 @@ impl Contract {
                     })?;
                 run_yul_lowering.borrow_mut().finish();
                 let runtime_build = runtime_context.build(
-                    output_selection.check_selection(
-                        contract_name.path.as_str(),
-                        contract_name.name.as_deref(),
-                        solx_standard_json::InputSelector::RuntimeBytecodeLLVMAssembly,
-                    ),
+                    output_assembly,
+                    output_llvm_ir,
+                    output_llvm_ir_optimized,
+                    output_llvm_remarks,
                     output_bytecode,
                     false,
                     &mut profiler,
@@ -227,6 +275,10 @@ impl Contract {
                     runtime_code_identifier,
                     contract_name.clone(),
                     runtime_build.assembly,
+                    runtime_build.llvm_ir,
+                    runtime_build.llvm_ir_optimized,
+                    runtime_build.llvm_remarks,
+                    runtime_build.ethir,
                     runtime_build.bytecode,
                     true,
                     code_segment,
@@ -236,12 +288,17 @@ impl Contract {
                     runtime_build.is_size_fallback,
                     runtime_build.warnings,
                     profiler.to_vec(),
+                    profiler.to_trace_events(
+                        solx_codegen_evm::TraceEvent::CATEGORY_TRANSLATION_UNIT,
+                        0,
+                    ),
                 );
                 Ok(runtime_object)
             }
             (IR::EVMLegacyAssembly(mut deploy_code), solx_utils::CodeSegment::Deploy) => {
                 let evmla_data = solx_codegen_evm::ContextEVMLAData::new(
                     solc_version.expect("Always exists").default,
+                    output_ethir,
                 );
                 let deploy_code_identifier = contract_name.full_path.to_owned();
                 let mut deploy_code_dependencies =
@@ -261,9 +318,7 @@ impl Contract {
                     optimizer,
                     debug_config.clone(),
                 );
-                inkwell::support::error_handling::install_stack_error_handler(
-                    crate::process::evm_stack_error_handler,
-                );
+                crate::process::install_stack_error_handler();
                 deploy_context.set_solidity_data(solidity_data);
                 deploy_context.set_evmla_data(evmla_data);
                 let run_evm_assembly_lowering = profiler.start_evm_translation_unit(
@@ -280,12 +335,23 @@ impl Contract {
                         anyhow::anyhow!("{code_segment} code LLVM IR generator: {error}")
                     })?;
                 run_evm_assembly_lowering.borrow_mut().finish();
+                if let Some(run_ethir) = deploy_context
+                    .evmla_mut()
+                    .and_then(|evmla_data| evmla_data.ethir_run.take())
+                {
+                    profiler.insert_evm_translation_unit(
+                        contract_name.full_path.as_str(),
+                        code_segment,
+                        "EthIR",
+                        &optimizer_settings,
+                        run_ethir,
+                    );
+                }
                 let deploy_build = deploy_context.build(
-                    output_selection.check_selection(
-                        contract_name.path.as_str(),
-                        contract_name.name.as_deref(),
-                        solx_standard_json::InputSelector::BytecodeLLVMAssembly,
-                    ),
+                    output_assembly,
+                    output_llvm_ir,
+                    output_llvm_ir_optimized,
+                    output_llvm_remarks,
                     output_bytecode,
                     false,
                     &mut profiler,
@@ -294,6 +360,10 @@ impl Contract {
                     deploy_code_identifier,
                     contract_name.clone(),
                     deploy_build.assembly,
+                    deploy_build.llvm_ir,
+                    deploy_build.llvm_ir_optimized,
+                    deploy_build.llvm_remarks,
+                    deploy_build.ethir,
                     deploy_build.bytecode,
                     false,
                     code_segment,
@@ -303,6 +373,10 @@ impl Contract {
                     deploy_build.is_size_fallback,
                     deploy_build.warnings,
                     profiler.to_vec(),
+                    profiler.to_trace_events(
+                        solx_codegen_evm::TraceEvent::CATEGORY_TRANSLATION_UNIT,
+                        0,
+                    ),
                 );
                 Ok(deploy_object)
             }
@@ -310,6 +384,7 @@ impl Contract {
                 let runtime_code_identifier = format!("{}.{code_segment}", contract_name.full_path);
                 let evmla_data = solx_codegen_evm::ContextEVMLAData::new(
                     solc_version.expect("Always exists").default,
+                    output_ethir,
                 );
 
                 let runtime_llvm = inkwell::context::Context::create();
@@ -323,9 +398,7 @@ impl Contract {
                     optimizer.clone(),
                     debug_config.clone(),
                 );
-                inkwell::support::error_handling::install_stack_error_handler(
-                    crate::process::evm_stack_error_handler,
-                );
+                crate::process::install_stack_error_handler();
                 runtime_context.set_solidity_data(solidity_data);
                 runtime_context.set_evmla_data(evmla_data.clone());
                 let run_evm_assembly_lowering = profiler.start_evm_translation_unit(
@@ -342,12 +415,23 @@ impl Contract {
                         anyhow::anyhow!("{code_segment} code LLVM IR generator: {error}")
                     })?;
                 run_evm_assembly_lowering.borrow_mut().finish();
+                if let Some(run_ethir) = runtime_context
+                    .evmla_mut()
+                    .and_then(|evmla_data| evmla_data.ethir_run.take())
+                {
+                    profiler.insert_evm_translation_unit(
+                        contract_name.full_path.as_str(),
+                        code_segment,
+                        "EthIR",
+                        &optimizer_settings,
+                        run_ethir,
+                    );
+                }
                 let runtime_build = runtime_context.build(
-                    output_selection.check_selection(
-                        contract_name.path.as_str(),
-                        contract_name.name.as_deref(),
-                        solx_standard_json::InputSelector::RuntimeBytecodeLLVMAssembly,
-                    ),
+                    output_assembly,
+                    output_llvm_ir,
+                    output_llvm_ir_optimized,
+                    output_llvm_remarks,
                     output_bytecode,
                     false,
                     &mut profiler,
@@ -357,6 +441,10 @@ impl Contract {
                     runtime_code_identifier,
                     contract_name.clone(),
                     runtime_build.assembly,
+                    runtime_build.llvm_ir,
+                    runtime_build.llvm_ir_optimized,
+                    runtime_build.llvm_remarks,
+                    runtime_build.ethir,
                     runtime_build.bytecode,
                     false,
                     code_segment,
@@ -366,6 +454,10 @@ impl Contract {
                     runtime_build.is_size_fallback,
                     runtime_build.warnings,
                     profiler.to_vec(),
+                    profiler.to_trace_events(
+                        solx_codegen_evm::TraceEvent::CATEGORY_TRANSLATION_UNIT,
+                        0,
+                    ),
                 );
                 Ok(runtime_object)
             }
@@ -391,16 +483,13 @@ impl Contract {
                     optimizer,
                     debug_config,
                 );
-                inkwell::support::error_handling::install_stack_error_handler(
-                    crate::process::evm_stack_error_handler,
-                );
+                crate::process::install_stack_error_handler();
                 deploy_context.set_solidity_data(solidity_data);
                 let deploy_build = deploy_context.build(
-                    output_selection.check_selection(
-                        contract_name.path.as_str(),
-                        contract_name.name.as_deref(),
-                        solx_standard_json::InputSelector::BytecodeLLVMAssembly,
-                    ),
+                    output_assembly,
+                    output_llvm_ir,
+                    output_llvm_ir_optimized,
+                    output_llvm_remarks,
                     output_bytecode,
                     false,
                     &mut profiler,
@@ -409,6 +498,10 @@ impl Contract {
                     deploy_code_identifier,
                     contract_name.clone(),
                     deploy_build.assembly,
+                    deploy_build.llvm_ir,
+                    deploy_build.llvm_ir_optimized,
+                    deploy_build.llvm_remarks,
+                    deploy_build.ethir,
                     deploy_build.bytecode,
                     false,
                     code_segment,
@@ -418,6 +511,10 @@ impl Contract {
                     deploy_build.is_size_fallback,
                     deploy_build.warnings,
                     profiler.to_vec(),
+                    profiler.to_trace_events(
+                        solx_codegen_evm::TraceEvent::CATEGORY_TRANSLATION_UNIT,
+                        0,
+                    ),
                 );
                 Ok(deploy_object)
             }
@@ -443,16 +540,13 @@ impl Contract {
                     optimizer,
                     debug_config.clone(),
                 );
-                inkwell::support::error_handling::install_stack_error_handler(
-                    crate::process::evm_stack_error_handler,
-                );
+                crate::process::install_stack_error_handler();
                 runtime_context.set_solidity_data(solidity_data);
                 let runtime_build = runtime_context.build(
-                    output_selection.check_selection(
-                        contract_name.path.as_str(),
-                        contract_name.name.as_deref(),
-                        solx_standard_json::InputSelector::RuntimeBytecodeLLVMAssembly,
-                    ),
+                    output_assembly,
+                    output_llvm_ir,
+                    output_llvm_ir_optimized,
+                    output_llvm_remarks,
                     output_bytecode,
                     false,
                     &mut profiler,
@@ -461,6 +555,10 @@ impl Contract {
                     runtime_code_identifier,
                     contract_name.clone(),
                     runtime_build.assembly,
+                    runtime_build.llvm_ir,
+                    runtime_build.llvm_ir_optimized,
+                    runtime_build.llvm_remarks,
+                    runtime_build.ethir,
                     runtime_build.bytecode,
                     false,
                     code_segment,
@@ -470,6 +568,10 @@ impl Contract {
                     runtime_build.is_size_fallback,
                     runtime_build.warnings,
                     profiler.to_vec(),
+                    profiler.to_trace_events(
+                        solx_codegen_evm::TraceEvent::CATEGORY_TRANSLATION_UNIT,
+                        0,
+                    ),
                 );
                 Ok(runtime_object)
             }
@@ -2,6 +2,7 @@
 //! The project representation.
 //!
 
+pub mod candidate;
 pub mod contract;
 
 use std::collections::BTreeMap;
@@ -12,12 +13,16 @@ use std::sync::Mutex;
 use rayon::iter::IntoParallelIterator;
 use rayon::iter::ParallelIterator;
 
+use solx_yul::yul::error::Error as YulError;
+
 use crate::build::contract::Contract as EVMContractBuild;
 use crate::build::Build as EVMBuild;
 use crate::error::Error;
+use crate::process::execution_mode::ExecutionMode;
 use crate::process::input::Input as EVMProcessInput;
 use crate::process::output::Output as EVMProcessOutput;
 
+use self::candidate::Candidate;
 use self::contract::ir::evmla::EVMLegacyAssembly as ContractEVMLegacyAssembly;
 use self::contract::ir::llvm_ir::LLVMIR as ContractLLVMIR;
 use self::contract::ir::yul::Yul as ContractYul;
@@ -41,6 +46,8 @@ pub struct Project {
     pub ast_jsons: Option<BTreeMap<String, Option<serde_json::Value>>>,
     /// The mapping of auxiliary identifiers, e.g. Yul object names, to full contract paths.
     pub identifier_paths: BTreeMap<String, String>,
+    /// The mapping of `solc` source file identifiers to source file paths.
+    pub source_paths: BTreeMap<usize, String>,
     /// The library addresses.
     pub libraries: solx_utils::Libraries,
 }
@@ -54,6 +61,7 @@ impl Project {
         solc_version: Option<solx_standard_json::Version>,
         contracts: BTreeMap<String, Contract>,
         ast_jsons: Option<BTreeMap<String, Option<serde_json::Value>>>,
+        source_paths: BTreeMap<usize, String>,
         libraries: solx_utils::Libraries,
     ) -> Self {
         let mut identifier_paths = BTreeMap::new();
@@ -75,6 +83,7 @@ impl Project {
             contracts,
             ast_jsons,
             identifier_paths,
+            source_paths,
             libraries,
         }
     }
@@ -115,6 +124,11 @@ impl Project {
             solx_evm_assembly::Assembly::preprocess_dependencies(legacy_assemblies)?;
         }
 
+        let source_paths = solc_output
+            .sources
+            .iter()
+            .map(|(path, source)| (source.id, path.to_owned()))
+            .collect::<BTreeMap<usize, String>>();
         let ast_jsons = solc_output
             .sources
             .iter_mut()
@@ -157,8 +171,13 @@ impl Project {
 
                 let result = if via_ir {
                     contract.ir.as_deref().map(|ir| {
-                        ContractYul::try_from_source(name.full_path.as_str(), ir, debug_config)
-                            .map(|yul| yul.map(ContractIR::from))
+                        ContractYul::try_from_source(
+                            name.full_path.as_str(),
+                            ir,
+                            false,
+                            debug_config,
+                        )
+                        .map(|yul| yul.map(ContractIR::from))
                     })
                 } else {
                     legacy_assembly.as_ref().map(|legacy_assembly| {
@@ -206,6 +225,7 @@ impl Project {
             Some(solc_version.to_owned()),
             contracts,
             Some(ast_jsons),
+            source_paths,
             libraries,
         ))
     }
@@ -249,6 +269,8 @@ impl Project {
     ///
     /// Parses the Yul `sources` and returns a Yul project.
     ///
+    /// The sources can be either Yul, or the Yul AST JSONs emitted by the `yulAst` output.
+    ///
     pub fn try_from_yul_sources(
         solc_version: &solx_standard_json::Version,
         sources: BTreeMap<String, solx_standard_json::InputSource>,
@@ -262,7 +284,7 @@ impl Project {
             .map(|(path, mut source)| {
                 let source_code = match source.try_resolve() {
                     Ok(()) => source.take_content().expect("Always exists"),
-                    Err(error) => return (path, Err(error)),
+                    Err(error) => return (path, Err(error), None),
                 };
 
                 let metadata = if output_selection.check_selection(
@@ -283,10 +305,11 @@ impl Project {
                 let ir = match ContractYul::try_from_source(
                     path.as_str(),
                     source_code.as_str(),
+                    true,
                     debug_config,
                 ) {
                     Ok(ir) => ir,
-                    Err(error) => return (path, Err(error)),
+                    Err(error) => return (path, Err(error), Some(source_code)),
                 };
 
                 let name = solx_utils::ContractName::new(
@@ -307,18 +330,30 @@ impl Project {
                     None,
                     None,
                 );
-                (full_path, Ok(contract))
+                (full_path, Ok(contract), None)
             })
-            .collect::<BTreeMap<String, anyhow::Result<Contract>>>();
+            .collect::<Vec<(String, anyhow::Result<Contract>, Option<String>)>>();
 
         let mut contracts = BTreeMap::new();
-        for (path, result) in results.into_iter() {
+        for (path, result, source_code) in results.into_iter() {
             match result {
                 Ok(contract) => {
                     contracts.insert(path, contract);
                 }
                 Err(error) => match solc_output {
-                    Some(ref mut solc_output) => solc_output.push_error(Some(path), error),
+                    Some(ref mut solc_output) => match error.downcast::<YulError>() {
+                        Ok(error) => {
+                            for error in error.into_errors() {
+                                Self::push_yul_error(
+                                    solc_output,
+                                    path.as_str(),
+                                    source_code.as_deref(),
+                                    error,
+                                );
+                            }
+                        }
+                        Err(error) => solc_output.push_error(Some(path), error),
+                    },
                     None => anyhow::bail!(error),
                 },
             }
@@ -328,10 +363,57 @@ impl Project {
             Some(solc_version.to_owned()),
             contracts,
             None,
+            BTreeMap::new(),
             libraries,
         ))
     }
 
+    ///
+    /// Pushes the Yul parsing or analysis `error` of the source at `path` to the standard JSON output.
+    ///
+    /// The errors point at the offending token or node if the source code is Yul, and not Yul AST JSON,
+    /// and their formatted messages include the source code snippet.
+    ///
+    fn push_yul_error(
+        solc_output: &mut solx_standard_json::Output,
+        path: &str,
+        source_code: Option<&str>,
+        error: YulError,
+    ) {
+        let (source_location, sources) = match (source_code, error.location()) {
+            (Some(source_code), Some(location)) if !ContractYul::is_ast_json(source_code) => {
+                let offset = location.offset(source_code) as isize;
+                let source_location =
+                    solx_standard_json::OutputErrorSourceLocation::new_with_offsets(
+                        path.to_owned(),
+                        offset,
+                        offset,
+                    );
+                let sources = BTreeMap::from([(
+                    path.to_owned(),
+                    solx_standard_json::InputSource::from(source_code.to_owned()),
+                )]);
+                (source_location, Some(sources))
+            }
+            _ => (
+                solx_standard_json::OutputErrorSourceLocation::new(path.to_owned()),
+                None,
+            ),
+        };
+        let message = match error {
+            YulError::Analyzer(error) => format!("Yul analysis: {error}"),
+            error => format!("Yul parsing: {error}"),
+        };
+        solc_output
+            .errors
+            .push(solx_standard_json::OutputError::new_error_with_data(
+                None,
+                message,
+                Some(source_location),
+                sources.as_ref(),
+            ));
+    }
+
     ///
     /// Reads the LLVM IR source code `paths` and returns an LLVM IR project.
     ///
@@ -433,6 +515,7 @@ impl Project {
             None,
             contracts,
             None,
+            BTreeMap::new(),
             libraries,
         ))
     }
@@ -450,6 +533,8 @@ impl Project {
         optimizer_settings: solx_codegen_evm::OptimizerSettings,
         llvm_options: Vec<String>,
         debug_config: Option<solx_codegen_evm::DebugConfig>,
+        profile: Option<&solx_utils::ExecutionProfile>,
+        is_time_trace_enabled: bool,
     ) -> anyhow::Result<EVMBuild> {
         let results = self
             .contracts
@@ -457,7 +542,18 @@ impl Project {
             .map(|(path, mut contract)| {
                 let contract_name = contract.name.clone();
 
-                let metadata = contract.metadata.take();
+                let profile_units = profile
+                    .and_then(|profile| profile.contracts.get(contract_name.full_path.as_str()));
+                let profile_hash = profile_units.map(|profile_units| {
+                    solx_utils::Keccak256Hash::from_slice(
+                        serde_json::to_vec(profile_units)
+                            .expect("Always valid")
+                            .as_slice(),
+                    )
+                    .to_string()
+                });
+
+                let solc_metadata = contract.metadata.take();
                 let abi = contract.abi.take();
                 let method_identifiers = contract.method_identifiers.take();
                 let userdoc = contract.userdoc.take();
@@ -466,6 +562,18 @@ impl Project {
                 let transient_storage_layout = contract.transient_storage_layout.take();
                 let legacy_assembly = contract.legacy_assembly.take();
                 let yul = contract.yul.take();
+                let yul_ast = match contract.ir {
+                    Some(ContractIR::Yul(ref deploy_code))
+                        if output_selection.check_selection(
+                            contract_name.path.as_str(),
+                            contract_name.name.as_deref(),
+                            solx_standard_json::InputSelector::YulAST,
+                        ) =>
+                    {
+                        Some(deploy_code.ast_json())
+                    }
+                    _ => None,
+                };
 
                 let (deploy_code_ir, runtime_code_ir): (ContractIR, ContractIR) = match contract.ir
                 {
@@ -497,6 +605,15 @@ impl Project {
                         (deploy_code.into(), runtime_code.into())
                     }
                     None => {
+                        let metadata = solc_metadata.map(|metadata| {
+                            ContractMetadata::new(
+                                self.solc_version.as_ref(),
+                                optimizer_settings.clone(),
+                                llvm_options.as_slice(),
+                                profile_hash,
+                            )
+                            .insert_into(metadata.as_str())
+                        });
                         let build = EVMContractBuild::new(
                             contract_name,
                             None,
@@ -510,62 +627,113 @@ impl Project {
                             transient_storage_layout,
                             legacy_assembly,
                             yul,
+                            None,
+                            None,
                         );
                         return (path, build);
                     }
                 };
 
-                let (runtime_object_result, metadata) = {
-                    let metadata_bytes = Self::cbor_metadata(
-                        metadata.as_deref(),
-                        self.solc_version.as_ref(),
-                        &optimizer_settings,
-                        llvm_options.as_slice(),
-                        metadata_hash_type,
-                        append_cbor,
-                    );
-
-                    let mut input = EVMProcessInput::new(
-                        self.solc_version.clone(),
-                        contract_name.clone(),
-                        runtime_code_ir,
-                        solx_utils::CodeSegment::Runtime,
-                        evm_version,
-                        self.identifier_paths.clone(),
-                        output_selection.to_owned(),
-                        None,
-                        metadata_bytes,
-                        optimizer_settings.clone(),
-                        llvm_options.clone(),
-                        debug_config.clone(),
-                    );
+                let mut runtime_input = EVMProcessInput::new(
+                    self.solc_version.clone(),
+                    contract_name.clone(),
+                    runtime_code_ir,
+                    solx_utils::CodeSegment::Runtime,
+                    evm_version,
+                    self.identifier_paths.clone(),
+                    self.source_paths.clone(),
+                    output_selection.to_owned(),
+                    None,
+                    None,
+                    optimizer_settings.clone(),
+                    llvm_options.clone(),
+                    debug_config.clone(),
+                    profile_units
+                        .and_then(|profile_units| {
+                            profile_units.get(&solx_utils::CodeSegment::Runtime)
+                        })
+                        .cloned(),
+                    is_time_trace_enabled,
+                );
+                let mut deploy_input = EVMProcessInput::new(
+                    self.solc_version.clone(),
+                    contract_name.clone(),
+                    deploy_code_ir,
+                    solx_utils::CodeSegment::Deploy,
+                    evm_version,
+                    self.identifier_paths.clone(),
+                    self.source_paths.clone(),
+                    output_selection.to_owned(),
+                    None,
+                    None,
+                    optimizer_settings.clone(),
+                    llvm_options.clone(),
+                    debug_config.clone(),
+                    profile_units
+                        .and_then(|profile_units| {
+                            profile_units.get(&solx_utils::CodeSegment::Deploy)
+                        })
+                        .cloned(),
+                    is_time_trace_enabled,
+                );
 
-                    let result = Self::run_multi_pass_pipeline(path.as_str(), &mut input);
-                    (result, metadata)
+                let candidate_settings = match optimizer_settings.objective {
+                    Some(_) => optimizer_settings.auto_candidates(),
+                    None => vec![optimizer_settings.clone()],
                 };
-
-                let immutables = runtime_object_result
-                    .as_ref()
-                    .ok()
-                    .and_then(|output| output.object.immutables.to_owned());
-                let deploy_object_result: crate::Result<EVMProcessOutput> = {
-                    let mut input = EVMProcessInput::new(
-                        self.solc_version.clone(),
-                        contract_name.clone(),
-                        deploy_code_ir,
-                        solx_utils::CodeSegment::Deploy,
-                        evm_version,
-                        self.identifier_paths.clone(),
-                        output_selection.to_owned(),
-                        immutables,
-                        None,
-                        optimizer_settings.clone(),
-                        llvm_options.clone(),
-                        debug_config.clone(),
-                    );
-
-                    Self::run_multi_pass_pipeline(path.as_str(), &mut input)
+                let candidates = candidate_settings
+                    .into_iter()
+                    .map(|candidate_settings| {
+                        let metadata = solc_metadata.as_deref().map(|metadata| {
+                            ContractMetadata::new(
+                                self.solc_version.as_ref(),
+                                candidate_settings.clone(),
+                                llvm_options.as_slice(),
+                                profile_hash.clone(),
+                            )
+                            .insert_into(metadata)
+                        });
+
+                        runtime_input.metadata_bytes = Self::cbor_metadata(
+                            metadata.as_deref(),
+                            self.solc_version.as_ref(),
+                            metadata_hash_type,
+                            append_cbor,
+                        );
+                        runtime_input.optimizer_settings = candidate_settings.clone();
+                        let runtime_object_result =
+                            Self::run_multi_pass_pipeline(path.as_str(), &mut runtime_input);
+
+                        deploy_input.immutables = runtime_object_result
+                            .as_ref()
+                            .ok()
+                            .and_then(|output| output.object.immutables.to_owned());
+                        deploy_input.optimizer_settings = candidate_settings.clone();
+                        let deploy_object_result =
+                            Self::run_multi_pass_pipeline(path.as_str(), &mut deploy_input);
+
+                        Candidate::new(
+                            candidate_settings,
+                            metadata,
+                            deploy_object_result,
+                            runtime_object_result,
+                        )
+                    })
+                    .collect::<Vec<Candidate>>();
+                let (candidate, optimization_mode) = match optimizer_settings.objective {
+                    Some(objective) => {
+                        let candidate = Candidate::select(candidates, objective);
+                        let optimization_mode = candidate.optimization_mode();
+                        (candidate, Some(optimization_mode))
+                    }
+                    None => (candidates.into_iter().next().expect("Always exists"), None),
                 };
+                let Candidate {
+                    metadata,
+                    deploy_object_result,
+                    runtime_object_result,
+                    ..
+                } = candidate;
 
                 let build = EVMContractBuild::new(
                     contract_name,
@@ -582,6 +750,8 @@ impl Project {
                     transient_storage_layout,
                     legacy_assembly,
                     yul,
+                    yul_ast,
+                    optimization_mode,
                 );
                 (path, build)
             })
@@ -596,8 +766,6 @@ impl Project {
     fn cbor_metadata(
         metadata: Option<&str>,
         solc_version: Option<&solx_standard_json::Version>,
-        optimizer_settings: &solx_codegen_evm::OptimizerSettings,
-        llvm_options: &[String],
         metadata_hash_type: solx_utils::MetadataHashType,
         append_cbor: bool,
     ) -> Option<Vec<u8>> {
@@ -605,18 +773,15 @@ impl Project {
             return None;
         }
 
-        let metadata = metadata.map(|metadata| {
-            ContractMetadata::new(solc_version, optimizer_settings.clone(), llvm_options)
-                .insert_into(metadata)
+        let metadata_hash = metadata.and_then(|metadata| match metadata_hash_type {
+            solx_utils::MetadataHashType::None => None,
+            solx_utils::MetadataHashType::IPFS => {
+                Some(solx_utils::IPFSHash::from_slice(metadata.as_bytes()).to_vec())
+            }
+            solx_utils::MetadataHashType::Swarm => {
+                Some(solx_utils::SwarmHash::from_slice(metadata.as_bytes()).to_vec())
+            }
         });
-        let metadata_hash = metadata
-            .as_ref()
-            .and_then(|metadata| match metadata_hash_type {
-                solx_utils::MetadataHashType::None => None,
-                solx_utils::MetadataHashType::IPFS => {
-                    Some(solx_utils::IPFSHash::from_slice(metadata.as_bytes()).to_vec())
-                }
-            });
 
         let mut cbor_version_parts = Vec::with_capacity(3);
         cbor_version_parts.push((
@@ -641,7 +806,7 @@ impl Project {
         match metadata_hash {
             Some(hash) => {
                 let cbor = solx_utils::CBOR::new(
-                    Some((solx_utils::MetadataHashType::IPFS, hash.as_slice())),
+                    Some((metadata_hash_type, hash.as_slice())),
                     cbor_data.0,
                     cbor_data.1,
                 );
@@ -660,14 +825,33 @@ impl Project {
     /// It is expected to run up to 4 passes in the process of handling stack too deep errors
     /// and turning on the size fallback to overcome the EVM bytecode size limit.
     ///
+    /// Each pass is run according to the selected execution mode, and is recorded as a worker
+    /// process trace event attributed to the current thread.
+    /// The resulting spill area size and size fallback are recorded in the output object.
+    ///
     fn run_multi_pass_pipeline(
         path: &str,
         input: &mut EVMProcessInput,
     ) -> crate::Result<EVMProcessOutput> {
+        let mut profiler = solx_codegen_evm::Profiler::default();
         let mut result: crate::Result<EVMProcessOutput>;
         let mut pass_count = 0;
+        let mut is_size_fallback = false;
         loop {
-            result = crate::process::call(path, input);
+            let run_process = profiler.start_pipeline_element(
+                format!("{path}:{}/Process({pass_count})", input.code_segment).as_str(),
+            );
+            result = match crate::process::EXECUTION_MODE
+                .get()
+                .copied()
+                .unwrap_or_default()
+            {
+                ExecutionMode::Subprocess => crate::process::call(path, input),
+                ExecutionMode::InProcess { is_panic_isolated } => {
+                    crate::process::call_in_process(path, input, is_panic_isolated)
+                }
+            };
+            run_process.borrow_mut().finish();
             pass_count += 1;
             match result {
                 Err(Error::StackTooDeep(ref stack_too_deep)) => {
@@ -675,6 +859,7 @@ impl Project {
 
                     if stack_too_deep.is_size_fallback {
                         input.optimizer_settings.switch_to_size_fallback();
+                        is_size_fallback = true;
                     }
                     input
                         .optimizer_settings
@@ -685,6 +870,18 @@ impl Project {
                 _ => break,
             }
         }
+        if let Ok(ref mut output) = result {
+            output.object.spill_area_size = input.optimizer_settings.spill_area_size();
+            output.object.is_size_fallback |= is_size_fallback;
+            output.object.trace_events.extend(
+                profiler.to_trace_events(
+                    solx_codegen_evm::TraceEvent::CATEGORY_PROCESS,
+                    rayon::current_thread_index()
+                        .map(|index| index as u64 + 1)
+                        .unwrap_or_default(),
+                ),
+            );
+        }
         result
     }
 }
@@ -0,0 +1,208 @@
+//!
+//! The contract metadata used for verification.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+///
+/// The contract metadata used for verification.
+///
+/// Only the fields required to reproduce the compilation are deserialized.
+///
+#[derive(Debug, serde::Deserialize)]
+pub struct Metadata {
+    /// The source language.
+    pub language: solx_standard_json::InputLanguage,
+    /// The source files used in the compilation.
+    pub sources: BTreeMap<String, Source>,
+    /// The `solc` compiler settings.
+    pub settings: Settings,
+    /// The `solx` extension inserted by `crate::project::contract::metadata::Metadata`.
+    #[serde(skip)]
+    pub solx: Solx,
+}
+
+///
+/// The metadata source file.
+///
+#[derive(Debug, serde::Deserialize)]
+pub struct Source {
+    /// The Keccak-256 hash of the source code.
+    pub keccak256: String,
+    /// The source code, if the literal content flag was enabled.
+    pub content: Option<String>,
+}
+
+///
+/// The metadata `solc` compiler settings.
+///
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Settings {
+    /// The contract to verify as a source path and contract name pair.
+    pub compilation_target: BTreeMap<String, String>,
+    /// The target EVM version.
+    #[serde(default)]
+    pub evm_version: Option<solx_utils::EVMVersion>,
+    /// The linker library addresses in the `<path>:<name>` format.
+    #[serde(default)]
+    pub libraries: BTreeMap<String, String>,
+    /// The remappings.
+    #[serde(default)]
+    pub remappings: BTreeSet<String>,
+    /// Whether Solidity was compiled via IR.
+    #[serde(default, rename = "viaIR")]
+    pub via_ir: bool,
+    /// The metadata settings.
+    #[serde(default)]
+    pub metadata: SettingsMetadata,
+}
+
+///
+/// The metadata settings of the metadata.
+///
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsMetadata {
+    /// Whether the CBOR metadata was appended.
+    #[serde(
+        rename = "appendCBOR",
+        default = "SettingsMetadata::default_append_cbor"
+    )]
+    pub append_cbor: bool,
+    /// The metadata hash type.
+    #[serde(default = "SettingsMetadata::default_bytecode_hash")]
+    pub bytecode_hash: solx_utils::MetadataHashType,
+}
+
+impl Default for SettingsMetadata {
+    fn default() -> Self {
+        Self {
+            append_cbor: Self::default_append_cbor(),
+            bytecode_hash: Self::default_bytecode_hash(),
+        }
+    }
+}
+
+impl SettingsMetadata {
+    ///
+    /// The default append CBOR flag.
+    ///
+    fn default_append_cbor() -> bool {
+        true
+    }
+
+    ///
+    /// The default metadata hash type.
+    ///
+    fn default_bytecode_hash() -> solx_utils::MetadataHashType {
+        solx_utils::MetadataHashType::IPFS
+    }
+}
+
+///
+/// The `solx` extension of the metadata.
+///
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct Solx {
+    /// The `solc` version.
+    pub solc_version: Option<semver::Version>,
+    /// The LLVM `solc` revision.
+    pub solc_llvm_revision: Option<semver::Version>,
+    /// `solx` compiler version.
+    pub solx_version: Option<semver::Version>,
+    /// The LLVM compiler optimizer settings.
+    pub optimizer_settings: Option<solx_codegen_evm::OptimizerSettings>,
+    /// The LLVM extra arguments.
+    #[serde(default)]
+    pub llvm_options: Vec<String>,
+    /// The Keccak-256 hash of the contract execution profile used for profile-guided optimization.
+    pub profile_hash: Option<String>,
+}
+
+impl Metadata {
+    ///
+    /// Parses the metadata JSON string.
+    ///
+    pub fn try_from_str(metadata: &str) -> anyhow::Result<Self> {
+        let mut object: serde_json::Value = serde_json::from_str(metadata)
+            .map_err(|error| anyhow::anyhow!("Metadata parsing: {error}"))?;
+        let solx = object
+            .as_object_mut()
+            .and_then(|object| object.remove(env!("CARGO_PKG_NAME")))
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The metadata has not been emitted by `{}`: the `{}` section is missing.",
+                    crate::r#const::DEFAULT_EXECUTABLE_NAME,
+                    env!("CARGO_PKG_NAME"),
+                )
+            })?;
+
+        let mut metadata: Self = serde_json::from_value(object)
+            .map_err(|error| anyhow::anyhow!("Metadata parsing: {error}"))?;
+        metadata.solx = serde_json::from_value(solx).map_err(|error| {
+            anyhow::anyhow!(
+                "Metadata `{}` section parsing: {error}",
+                env!("CARGO_PKG_NAME")
+            )
+        })?;
+        Ok(metadata)
+    }
+
+    ///
+    /// Returns the full path of the contract to verify in the `<path>:<name>` format.
+    ///
+    pub fn compilation_target(&self) -> anyhow::Result<String> {
+        let mut targets = self.settings.compilation_target.iter();
+        match (targets.next(), targets.next()) {
+            (Some((path, name)), None) => Ok(format!("{path}:{name}")),
+            _ => anyhow::bail!("The metadata must contain exactly one compilation target."),
+        }
+    }
+
+    ///
+    /// Returns the linker library addresses.
+    ///
+    pub fn libraries(&self) -> anyhow::Result<solx_utils::Libraries> {
+        let mut libraries = BTreeMap::new();
+        for (full_path, address) in self.settings.libraries.iter() {
+            let (path, name) = full_path.rsplit_once(':').ok_or_else(|| {
+                anyhow::anyhow!("Library `{full_path}` contract name is missing.")
+            })?;
+            libraries
+                .entry(path.to_owned())
+                .or_insert_with(BTreeMap::new)
+                .insert(name.to_owned(), address.to_owned());
+        }
+        Ok(solx_utils::Libraries::from(libraries))
+    }
+
+    ///
+    /// Takes the embedded source code and checks it against the recorded hashes.
+    ///
+    pub fn take_sources(
+        &mut self,
+    ) -> anyhow::Result<BTreeMap<String, solx_standard_json::InputSource>> {
+        let mut sources = BTreeMap::new();
+        for (path, source) in self.sources.iter_mut() {
+            let content = source.content.take().ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Source `{path}` is not embedded into the metadata. Compile with `--metadata-literal` to verify offline."
+                )
+            })?;
+            let hash = solx_utils::Keccak256Hash::from_slice(content.as_bytes());
+            if hash.as_str().trim_start_matches("0x") != source.keccak256.trim_start_matches("0x") {
+                anyhow::bail!(
+                    "Source `{path}` content does not match its Keccak-256 hash `{}`.",
+                    source.keccak256
+                );
+            }
+            sources.insert(
+                path.to_owned(),
+                solx_standard_json::InputSource::from(content),
+            );
+        }
+        Ok(sources)
+    }
+}
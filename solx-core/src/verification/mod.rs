@@ -0,0 +1,6 @@
+//!
+//! The offline bytecode verification.
+//!
+
+pub mod metadata;
+pub mod status;
@@ -0,0 +1,106 @@
+//!
+//! The bytecode verification status.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+///
+/// The bytecode verification status.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The bytecode is identical, including the CBOR metadata.
+    Full,
+    /// The bytecode is identical, except for the CBOR metadata.
+    Partial,
+}
+
+impl Status {
+    ///
+    /// Compares the recompiled runtime bytecode with the deployed one.
+    ///
+    /// The immutable values are zeroed in the deployed bytecode before comparison, as they are
+    /// only known at deploy time. Returns `None` if the bytecode does not match.
+    ///
+    pub fn compare(
+        compiled: &[u8],
+        deployed: &[u8],
+        immutables: Option<&BTreeMap<String, BTreeSet<u64>>>,
+        has_cbor: bool,
+    ) -> Option<Self> {
+        let mut deployed = deployed.to_vec();
+        for offset in immutables
+            .into_iter()
+            .flat_map(|immutables| immutables.values().flatten())
+        {
+            let start = usize::try_from(*offset).ok()?;
+            let end = start.checked_add(solx_utils::BYTE_LENGTH_FIELD)?;
+            deployed.get_mut(start..end)?.fill(0);
+        }
+
+        if compiled == deployed.as_slice() {
+            return Some(Self::Full);
+        }
+        if has_cbor && Self::strip_cbor(compiled)? == Self::strip_cbor(deployed.as_slice())? {
+            return Some(Self::Partial);
+        }
+        None
+    }
+
+    ///
+    /// Strips the CBOR metadata, whose length is encoded in the last two bytes of the bytecode.
+    ///
+    /// Returns `None` if the bytecode is too short to contain the CBOR metadata of the encoded length.
+    ///
+    fn strip_cbor(bytecode: &[u8]) -> Option<&[u8]> {
+        let length_bytes = bytecode.last_chunk::<2>()?;
+        let cbor_length = u16::from_be_bytes(*length_bytes) as usize + length_bytes.len();
+        let code_length = bytecode.len().checked_sub(cbor_length)?;
+        bytecode.get(..code_length)
+    }
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Full => write!(f, "full match"),
+            Self::Partial => write!(f, "partial match"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Status;
+
+    #[test]
+    fn partial() {
+        let compiled = [0x60, 0x00, 0xa1, 0x01, 0x00, 0x01];
+        let deployed = [0x60, 0x00, 0xa1, 0x02, 0x00, 0x01];
+        assert_eq!(
+            Status::compare(&compiled, &deployed, None, true),
+            Some(Status::Partial)
+        );
+    }
+
+    #[test]
+    fn cbor_length_out_of_range() {
+        let compiled = [0x60, 0x00, 0xa1, 0x01, 0x00, 0x01];
+        let deployed = [0x60, 0x00, 0xff, 0xff];
+        assert_eq!(Status::compare(&compiled, &deployed, None, true), None);
+    }
+
+    #[test]
+    fn immutable_out_of_range() {
+        let immutables = std::collections::BTreeMap::from([(
+            "1".to_owned(),
+            std::collections::BTreeSet::from([u64::MAX]),
+        )]);
+        let bytecode = [0x60, 0x00];
+        assert_eq!(
+            Status::compare(&bytecode, &bytecode, Some(&immutables), false),
+            None
+        );
+    }
+}
@@ -713,11 +713,12 @@ impl FunctionCall {
                         .expect("Always valid");
                 }
 
-                if context.optimizer().settings().spill_area_size().is_some()
-                    && std::env::var(solx_utils::ENV_DISABLE_UNSAFE_MEMORY_ASM_STACK_TOO_DEEP_CHECK)
-                        .is_err()
-                {
-                    anyhow::bail!(solx_utils::ERROR_UNSAFE_MEMORY_ASM_STACK_TOO_DEEP);
+                let (source_id, start, end) = match location.solidity {
+                    Some(solidity) => (Some(solidity.source_id), solidity.start, solidity.end),
+                    None => (None, -1, -1),
+                };
+                if let Some(solidity_data) = context.solidity_mut() {
+                    solidity_data.add_unsafe_asm(source_id, start, end);
                 }
 
                 Ok(None)
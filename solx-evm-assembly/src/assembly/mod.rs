@@ -343,6 +343,7 @@ impl solx_codegen_evm::WriteLLVM for Assembly {
     fn into_llvm(self, context: &mut solx_codegen_evm::Context) -> anyhow::Result<()> {
         let full_path = self.full_path().to_owned();
 
+        let mut run_ethir = solx_codegen_evm::Run::default();
         let (code_segment, blocks) = if let Ok(runtime_code) = self.runtime_code() {
             if let Some(debug_config) = context.debug_config() {
                 debug_config.dump_evmla(full_path.as_str(), self.to_string().as_str())?;
@@ -393,12 +394,24 @@ impl solx_codegen_evm::WriteLLVM for Assembly {
             Some(code_segment),
             blocks,
         )?;
-        if let Some(debug_config) = context.debug_config() {
-            let mut path = full_path.to_owned();
-            if let solx_utils::CodeSegment::Runtime = code_segment {
-                path.push_str(format!(".{code_segment}").as_str());
+        run_ethir.finish();
+        context.evmla_mut().expect("Always exists").ethir_run = Some(run_ethir);
+        let output_ethir = context.evmla().expect("Always exists").output_ethir;
+        if output_ethir || context.debug_config().is_some() {
+            let ethir = ethereal_ir.to_string();
+            if let Some(debug_config) = context.debug_config() {
+                let mut path = full_path.to_owned();
+                if let solx_utils::CodeSegment::Runtime = code_segment {
+                    path.push_str(format!(".{code_segment}").as_str());
+                }
+                debug_config.dump_ethir(path.as_str(), ethir.as_str())?;
+                if debug_config.is_dot_enabled {
+                    debug_config.dump_ethir_dot(path.as_str(), ethereal_ir.to_dot().as_str())?;
+                }
+            }
+            if output_ethir {
+                context.evmla_mut().expect("Always exists").ethir = Some(ethir);
             }
-            debug_config.dump_ethir(path.as_str(), ethereal_ir.to_string().as_str())?;
         }
 
         let mut entry = solx_codegen_evm::EntryFunction::new(ethereal_ir);
@@ -1094,11 +1094,14 @@ impl solx_codegen_evm::WriteLLVM for Element {
                         .expect("Always valid");
                 }
 
-                if context.optimizer().settings().spill_area_size().is_some()
-                    && std::env::var(solx_utils::ENV_DISABLE_UNSAFE_MEMORY_ASM_STACK_TOO_DEEP_CHECK)
-                        .is_err()
-                {
-                    anyhow::bail!(solx_utils::ERROR_UNSAFE_MEMORY_ASM_STACK_TOO_DEEP);
+                if let Some(solidity_data) = context.solidity_mut() {
+                    solidity_data.add_unsafe_asm(
+                        self.instruction
+                            .source
+                            .and_then(|source| usize::try_from(source).ok()),
+                        self.instruction.begin,
+                        self.instruction.end,
+                    );
                 }
                 Ok(None)
             }
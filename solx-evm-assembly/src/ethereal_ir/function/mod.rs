@@ -1148,6 +1148,91 @@ impl Function {
             }
         }
     }
+
+    ///
+    /// Writes the function blocks and the jumps between them as a Graphviz DOT cluster.
+    ///
+    /// The blocks are keyed by their tag, instance, and initial stack hash. The blocks duplicated
+    /// for several initial stack states are highlighted.
+    ///
+    pub fn write_dot_cluster<W>(&self, f: &mut W) -> std::fmt::Result
+    where
+        W: std::fmt::Write,
+    {
+        writeln!(f, "    subgraph \"cluster_{}\" {{", self.name)?;
+        writeln!(f, "        label=\"{}\";", self.name)?;
+        for (key, blocks) in self.blocks.iter() {
+            for block in blocks.iter() {
+                let instance = block.instance.unwrap_or_default();
+                write!(
+                    f,
+                    "        {} [label=\"block_{key}/{instance}\\nstack: {:016x}\"",
+                    Self::dot_node(self.name.as_str(), key, instance),
+                    block.initial_stack.hash(),
+                )?;
+                if blocks.len() > 1 {
+                    write!(f, ", style=filled, fillcolor=lightsalmon")?;
+                }
+                writeln!(f, "];")?;
+            }
+        }
+        for (key, blocks) in self.blocks.iter() {
+            for block in blocks.iter() {
+                for (predecessor_key, predecessor_instance) in block.predecessors.iter() {
+                    writeln!(
+                        f,
+                        "        {} -> {};",
+                        Self::dot_node(self.name.as_str(), predecessor_key, *predecessor_instance),
+                        Self::dot_node(self.name.as_str(), key, block.instance.unwrap_or_default()),
+                    )?;
+                }
+            }
+        }
+        writeln!(f, "    }}")
+    }
+
+    ///
+    /// Writes the calls of defined functions as Graphviz DOT edges to the callee entry blocks.
+    ///
+    /// The edges must be written outside of the clusters, since Graphviz places a node into the
+    /// first cluster it is mentioned in.
+    ///
+    pub fn write_dot_calls<W>(&self, f: &mut W) -> std::fmt::Result
+    where
+        W: std::fmt::Write,
+    {
+        for (key, blocks) in self.blocks.iter() {
+            for block in blocks.iter() {
+                for element in block.elements.iter() {
+                    if let InstructionName::RecursiveCall {
+                        ref name,
+                        ref entry_key,
+                        ..
+                    } = element.instruction.name
+                    {
+                        writeln!(
+                            f,
+                            "    {} -> {} [style=dashed, label=\"call\"];",
+                            Self::dot_node(
+                                self.name.as_str(),
+                                key,
+                                block.instance.unwrap_or_default()
+                            ),
+                            Self::dot_node(format!("{name}_{entry_key}").as_str(), entry_key, 0),
+                        )?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the Graphviz DOT identifier of the block instance.
+    ///
+    fn dot_node(function_name: &str, key: &solx_codegen_evm::BlockKey, instance: usize) -> String {
+        format!("\"{function_name}:block_{key}/{instance}\"")
+    }
 }
 
 impl solx_codegen_evm::WriteLLVM for Function {
@@ -41,6 +41,9 @@ impl EtherealIR {
     /// The blocks hashmap initial capacity.
     pub const BLOCKS_HASHMAP_DEFAULT_CAPACITY: usize = 64;
 
+    /// The Graphviz DOT string initial capacity.
+    pub const DOT_STRING_DEFAULT_CAPACITY: usize = 4096;
+
     ///
     /// Assembles a sequence of functions from the sequence of instructions.
     ///
@@ -93,6 +96,38 @@ impl EtherealIR {
 
         Ok(blocks)
     }
+
+    ///
+    /// Returns the control flow graph of all functions in the Graphviz DOT format.
+    ///
+    /// Each function is drawn as a cluster of blocks, and the defined function calls are drawn
+    /// as dashed edges between the clusters.
+    ///
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::with_capacity(Self::DOT_STRING_DEFAULT_CAPACITY);
+        self.write_dot(&mut dot).expect("Always valid");
+        dot
+    }
+
+    ///
+    /// Writes the control flow graph of all functions in the Graphviz DOT format.
+    ///
+    fn write_dot<W>(&self, f: &mut W) -> std::fmt::Result
+    where
+        W: std::fmt::Write,
+    {
+        writeln!(f, "digraph ethir {{")?;
+        writeln!(f, "    node [shape=box, fontname=monospace];")?;
+        self.entry_function.write_dot_cluster(f)?;
+        for (_key, function) in self.defined_functions.iter() {
+            function.write_dot_cluster(f)?;
+        }
+        self.entry_function.write_dot_calls(f)?;
+        for (_key, function) in self.defined_functions.iter() {
+            function.write_dot_calls(f)?;
+        }
+        writeln!(f, "}}")
+    }
 }
 
 impl solx_codegen_evm::WriteLLVM for EtherealIR {
@@ -5,12 +5,14 @@
 pub mod metadata;
 pub mod optimizer;
 pub mod selection;
+pub mod stop_after;
 
 use std::collections::BTreeSet;
 
 use self::metadata::Metadata;
 use self::optimizer::Optimizer;
 use self::selection::Selection;
+use self::stop_after::StopAfter;
 
 ///
 /// The `solc --standard-json` input settings.
@@ -46,6 +48,9 @@ pub struct Settings {
     /// The metadata settings.
     #[serde(default)]
     pub metadata: Metadata,
+    /// The compilation stage to stop after.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_after: Option<StopAfter>,
 
     /// The extra LLVM options.
     #[serde(default, skip_serializing)]
@@ -83,6 +88,7 @@ impl Settings {
 
             output_selection,
             metadata,
+            stop_after: None,
             llvm_options,
         }
     }
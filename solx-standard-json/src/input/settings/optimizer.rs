@@ -20,6 +20,12 @@ pub struct Optimizer {
         skip_serializing_if = "Option::is_none"
     )]
     pub size_fallback: Option<bool>,
+    /// The custom LLVM middle-end pass pipeline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub passes: Option<String>,
+    /// The objective of the per-contract optimization mode auto-tuning, which overrides the mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub objective: Option<solx_utils::OptimizationObjective>,
 }
 
 impl Default for Optimizer {
@@ -39,6 +45,8 @@ impl Optimizer {
         Self {
             mode: Some(mode),
             size_fallback: Some(size_fallback),
+            passes: None,
+            objective: None,
         }
     }
 
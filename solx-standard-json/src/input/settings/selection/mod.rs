@@ -70,6 +70,21 @@ impl Selection {
                 .or(name.and_then(|name| file.get(name)))
             {
                 match selector {
+                    // The IRs and remarks are large, and the stack diagnostics and block maps are
+                    // rarely needed, so they are not a part of any multi-item selector.
+                    Selector::YulAST
+                    | Selector::BytecodeLLVMIR
+                    | Selector::BytecodeLLVMIROptimized
+                    | Selector::BytecodeLLVMRemarks
+                    | Selector::BytecodeEthIR
+                    | Selector::BytecodeStackDiagnostics
+                    | Selector::BytecodeBlockMap
+                    | Selector::RuntimeBytecodeLLVMIR
+                    | Selector::RuntimeBytecodeLLVMIROptimized
+                    | Selector::RuntimeBytecodeLLVMRemarks
+                    | Selector::RuntimeBytecodeEthIR
+                    | Selector::RuntimeBytecodeStackDiagnostics
+                    | Selector::RuntimeBytecodeBlockMap => return contract.contains(&selector),
                     Selector::MethodIdentifiers
                     | Selector::EVMLegacyAssembly
                     | Selector::GasEstimates
@@ -182,6 +197,17 @@ impl Selection {
         false
     }
 
+    ///
+    /// Checks if any output that requires code generation is requested for at least one contract.
+    ///
+    pub fn is_codegen_set_for_any(&self) -> bool {
+        self.inner
+            .values()
+            .flat_map(|file| file.values())
+            .flatten()
+            .any(|selector| !selector.is_frontend())
+    }
+
     ///
     /// Whether the selection is empty.
     ///
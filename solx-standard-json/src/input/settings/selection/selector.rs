@@ -39,6 +39,9 @@ pub enum Selector {
     /// The Yul IR.
     #[serde(rename = "ir", alias = "irOptimized")]
     Yul,
+    /// The Yul AST JSON, which is only produced by the Yul pipeline.
+    #[serde(rename = "yulAst")]
+    YulAST,
     /// The compilation pipeline benchmarks.
     #[serde(rename = "benchmarks")]
     Benchmarks,
@@ -55,6 +58,24 @@ pub enum Selector {
     /// The deploy LLVM assembly.
     #[serde(rename = "evm.bytecode.llvmAssembly")]
     BytecodeLLVMAssembly,
+    /// The deploy unoptimized LLVM IR.
+    #[serde(rename = "evm.bytecode.llvmIr")]
+    BytecodeLLVMIR,
+    /// The deploy optimized LLVM IR.
+    #[serde(rename = "evm.bytecode.llvmIrOptimized")]
+    BytecodeLLVMIROptimized,
+    /// The deploy LLVM optimization remarks.
+    #[serde(rename = "evm.bytecode.llvmRemarks")]
+    BytecodeLLVMRemarks,
+    /// The deploy EthIR, which is only produced by the EVM legacy assembly pipeline.
+    #[serde(rename = "evm.bytecode.ethir")]
+    BytecodeEthIR,
+    /// The deploy stack-too-deep resolution diagnostics.
+    #[serde(rename = "evm.bytecode.stackDiagnostics")]
+    BytecodeStackDiagnostics,
+    /// The deploy mapping of bytecode offsets to LLVM basic blocks.
+    #[serde(rename = "evm.bytecode.blockMap")]
+    BytecodeBlockMap,
     /// The deploy bytecode opcodes.
     #[serde(rename = "evm.bytecode.opcodes")]
     BytecodeOpcodes,
@@ -79,6 +100,24 @@ pub enum Selector {
     /// The runtime LLVM assembly.
     #[serde(rename = "evm.deployedBytecode.llvmAssembly")]
     RuntimeBytecodeLLVMAssembly,
+    /// The runtime unoptimized LLVM IR.
+    #[serde(rename = "evm.deployedBytecode.llvmIr")]
+    RuntimeBytecodeLLVMIR,
+    /// The runtime optimized LLVM IR.
+    #[serde(rename = "evm.deployedBytecode.llvmIrOptimized")]
+    RuntimeBytecodeLLVMIROptimized,
+    /// The runtime LLVM optimization remarks.
+    #[serde(rename = "evm.deployedBytecode.llvmRemarks")]
+    RuntimeBytecodeLLVMRemarks,
+    /// The runtime EthIR, which is only produced by the EVM legacy assembly pipeline.
+    #[serde(rename = "evm.deployedBytecode.ethir")]
+    RuntimeBytecodeEthIR,
+    /// The runtime stack-too-deep resolution diagnostics.
+    #[serde(rename = "evm.deployedBytecode.stackDiagnostics")]
+    RuntimeBytecodeStackDiagnostics,
+    /// The runtime mapping of bytecode offsets to LLVM basic blocks.
+    #[serde(rename = "evm.deployedBytecode.blockMap")]
+    RuntimeBytecodeBlockMap,
     /// The runtime bytecode opcodes.
     #[serde(rename = "evm.deployedBytecode.opcodes")]
     RuntimeBytecodeOpcodes,
@@ -113,11 +152,18 @@ impl Selector {
     pub fn is_received_from_solc(&self) -> bool {
         !matches!(
             self,
-            Self::Benchmarks
+            Self::YulAST
+                | Self::Benchmarks
                 | Self::EVM
                 | Self::Bytecode
                 | Self::BytecodeObject
                 | Self::BytecodeLLVMAssembly
+                | Self::BytecodeLLVMIR
+                | Self::BytecodeLLVMIROptimized
+                | Self::BytecodeLLVMRemarks
+                | Self::BytecodeEthIR
+                | Self::BytecodeStackDiagnostics
+                | Self::BytecodeBlockMap
                 | Self::BytecodeLinkReferences
                 | Self::BytecodeSourceMap
                 | Self::BytecodeFunctionDebugData
@@ -125,6 +171,12 @@ impl Selector {
                 | Self::RuntimeBytecode
                 | Self::RuntimeBytecodeObject
                 | Self::RuntimeBytecodeLLVMAssembly
+                | Self::RuntimeBytecodeLLVMIR
+                | Self::RuntimeBytecodeLLVMIROptimized
+                | Self::RuntimeBytecodeLLVMRemarks
+                | Self::RuntimeBytecodeEthIR
+                | Self::RuntimeBytecodeStackDiagnostics
+                | Self::RuntimeBytecodeBlockMap
                 | Self::RuntimeBytecodeSourceMap
                 | Self::RuntimeBytecodeFunctionDebugData
                 | Self::RuntimeBytecodeGeneratedSources
@@ -134,6 +186,24 @@ impl Selector {
         )
     }
 
+    ///
+    /// Whether the selector is available right after the `solc` analysis, without code generation.
+    ///
+    pub fn is_frontend(&self) -> bool {
+        matches!(
+            self,
+            Self::AST
+                | Self::ABI
+                | Self::Metadata
+                | Self::DeveloperDocumentation
+                | Self::UserDocumentation
+                | Self::StorageLayout
+                | Self::TransientStorageLayout
+                | Self::MethodIdentifiers
+                | Self::Benchmarks
+        )
+    }
+
     ///
     /// Converts a multi-item selector into a group of single-item selectors.
     ///
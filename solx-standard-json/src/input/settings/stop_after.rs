@@ -0,0 +1,35 @@
+//!
+//! The `solc --standard-json` input settings compilation stage to stop after.
+//!
+
+///
+/// The `solc --standard-json` input settings compilation stage to stop after.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum StopAfter {
+    /// Stop after parsing, so only the AST is produced.
+    #[serde(rename = "parsing")]
+    Parsing,
+    /// Stop after the `solc` analysis, so only the frontend data is produced.
+    /// This stage is `solx`-specific and is not passed to `solc`.
+    #[serde(rename = "analysis")]
+    Analysis,
+}
+
+impl StopAfter {
+    ///
+    /// Whether the stage is supported by `solc`.
+    ///
+    pub fn is_passed_to_solc(&self) -> bool {
+        matches!(self, Self::Parsing)
+    }
+}
+
+impl std::fmt::Display for StopAfter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parsing => write!(f, "parsing"),
+            Self::Analysis => write!(f, "analysis"),
+        }
+    }
+}
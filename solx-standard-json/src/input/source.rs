@@ -17,6 +17,9 @@ pub struct Source {
     /// The source file URLs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub urls: Option<Vec<String>>,
+    /// The Keccak-256 hash of the source code, checked against the provided or resolved content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keccak256: Option<String>,
 }
 
 impl Source {
@@ -42,46 +45,81 @@ impl Source {
         Ok(Self {
             content: Some(content),
             urls: None,
+            keccak256: None,
         })
     }
 
     ///
     /// Tries to resolve the source code.
     ///
-    /// At the moment only one URL pointing to the file system is supported.
+    /// At the moment only URLs pointing to the file system are supported.
+    /// The URLs are tried in order until one is read successfully. If the Keccak-256 hash is set,
+    /// the URLs whose content does not match it are skipped as well.
     ///
     pub fn try_resolve(&mut self) -> anyhow::Result<()> {
         match (self.content.as_ref(), self.urls.as_ref()) {
-            (Some(_), None) => Ok(()),
+            (Some(_), None) => self.check_keccak256(),
             (None, Some(urls)) => {
                 let mut errors = Vec::with_capacity(urls.len());
                 for url in urls.iter() {
                     let url_path = PathBuf::from(url);
-                    match Source::try_from_path(url_path.as_path()) {
+                    let resolved = Source::try_from_path(url_path.as_path()).and_then(|resolved| {
+                        let resolved = Self {
+                            keccak256: self.keccak256.clone(),
+                            ..resolved
+                        };
+                        resolved
+                            .check_keccak256()
+                            .map_err(|error| anyhow::anyhow!("File {url_path:?}: {error}"))?;
+                        Ok(resolved)
+                    });
+                    match resolved {
                         Ok(resolved) => {
                             *self = resolved;
-                            break;
+                            return Ok(());
                         }
                         Err(error) => errors.push(error),
                     }
                 }
-                if !errors.is_empty() {
-                    anyhow::bail!(
-                        "{}",
-                        errors
-                            .into_iter()
-                            .map(|error| error.to_string())
-                            .collect::<Vec<String>>()
-                            .join("\n")
-                    );
+                if errors.is_empty() {
+                    return Ok(());
                 }
-                Ok(())
+                anyhow::bail!(
+                    "{}",
+                    errors
+                        .into_iter()
+                        .map(|error| error.to_string())
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                );
             }
             (Some(_), Some(_)) => anyhow::bail!("Both `content` and `urls` cannot be set."),
             (None, None) => anyhow::bail!("Either `content` or `urls` must be set."),
         }
     }
 
+    ///
+    /// Checks the source code against the Keccak-256 hash, if both are set.
+    ///
+    pub fn check_keccak256(&self) -> anyhow::Result<()> {
+        let (Some(expected), Some(content)) = (self.keccak256.as_deref(), self.content.as_deref())
+        else {
+            return Ok(());
+        };
+
+        let actual = solx_utils::Keccak256Hash::from_slice(content.as_bytes());
+        if !actual
+            .as_str()
+            .trim_start_matches("0x")
+            .eq_ignore_ascii_case(expected.trim_start_matches("0x"))
+        {
+            anyhow::bail!(
+                "Mismatch between content and supplied hash: expected `{expected}`, found `{actual}`."
+            );
+        }
+        Ok(())
+    }
+
     ///
     /// Takes ownership of the source code and returns it.
     ///
@@ -102,6 +140,7 @@ impl From<String> for Source {
         Self {
             content: Some(content),
             urls: None,
+            keccak256: None,
         }
     }
 }
@@ -111,6 +150,7 @@ impl From<&Path> for Source {
         Self {
             content: None,
             urls: Some(vec![path.to_string_lossy().to_string()]),
+            keccak256: None,
         }
     }
 }
@@ -18,9 +18,11 @@ pub use self::input::settings::metadata::Metadata as InputMetadata;
 pub use self::input::settings::optimizer::Optimizer as InputOptimizer;
 pub use self::input::settings::selection::selector::Selector as InputSelector;
 pub use self::input::settings::selection::Selection as InputSelection;
+pub use self::input::settings::stop_after::StopAfter as InputStopAfter;
 pub use self::input::settings::Settings as InputSettings;
 pub use self::input::source::Source as InputSource;
 pub use self::input::Input;
+pub use self::output::contract::evm::bytecode::stack_diagnostics::StackDiagnostics as OutputContractEVMBytecodeStackDiagnostics;
 pub use self::output::contract::evm::bytecode::Bytecode as OutputContractEVMBytecode;
 pub use self::output::contract::evm::EVM as OutputContractEVM;
 pub use self::output::contract::Contract as OutputContract;
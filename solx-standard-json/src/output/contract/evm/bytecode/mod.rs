@@ -3,10 +3,12 @@
 //!
 
 pub mod link_reference;
+pub mod stack_diagnostics;
 
 use std::collections::BTreeMap;
 
 use self::link_reference::LinkReference;
+use self::stack_diagnostics::StackDiagnostics;
 
 ///
 /// The `solc --standard-json` output contract EVM bytecode.
@@ -20,6 +22,24 @@ pub struct Bytecode {
     /// Text assembly from LLVM.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub llvm_assembly: Option<String>,
+    /// Unoptimized LLVM IR.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub llvm_ir: Option<String>,
+    /// Optimized LLVM IR.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub llvm_ir_optimized: Option<String>,
+    /// LLVM optimization remarks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub llvm_remarks: Option<Vec<solx_utils::LLVMRemark>>,
+    /// EthIR, produced only by the EVM legacy assembly pipeline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ethir: Option<String>,
+    /// Stack-too-deep resolution diagnostics.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stack_diagnostics: Option<StackDiagnostics>,
+    /// The mapping of bytecode offsets to LLVM basic blocks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_map: Option<solx_utils::BlockMap>,
     /// Link references placeholder.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub link_references: Option<BTreeMap<String, BTreeMap<String, Vec<LinkReference>>>>,
@@ -51,6 +71,12 @@ impl Bytecode {
     pub fn new(
         object: Option<String>,
         llvm_assembly: Option<String>,
+        llvm_ir: Option<String>,
+        llvm_ir_optimized: Option<String>,
+        llvm_remarks: Option<Vec<solx_utils::LLVMRemark>>,
+        ethir: Option<String>,
+        stack_diagnostics: Option<StackDiagnostics>,
+        block_map: Option<solx_utils::BlockMap>,
         unlinked_symbols: Option<BTreeMap<String, Vec<u64>>>,
         benchmarks: Vec<(String, u64)>,
 
@@ -84,6 +110,12 @@ impl Bytecode {
         Self {
             object,
             llvm_assembly,
+            llvm_ir,
+            llvm_ir_optimized,
+            llvm_remarks,
+            ethir,
+            stack_diagnostics,
+            block_map,
             link_references,
             benchmarks,
 
@@ -101,6 +133,12 @@ impl Bytecode {
     pub fn is_empty(&self) -> bool {
         self.object.is_none()
             && self.llvm_assembly.is_none()
+            && self.llvm_ir.is_none()
+            && self.llvm_ir_optimized.is_none()
+            && self.llvm_remarks.is_none()
+            && self.ethir.is_none()
+            && self.stack_diagnostics.is_none()
+            && self.block_map.is_none()
             && self.link_references.is_none()
             && self.benchmarks.is_empty()
             && self.opcodes.is_none()
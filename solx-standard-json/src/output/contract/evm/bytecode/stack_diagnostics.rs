@@ -0,0 +1,43 @@
+//!
+//! The `solc --standard-json` output contract EVM bytecode stack diagnostics.
+//!
+
+///
+/// The `solc --standard-json` output contract EVM bytecode stack diagnostics.
+///
+/// Describes the measures taken to resolve stack-too-deep errors in the code segment.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StackDiagnostics {
+    /// The spill area size in bytes, if spilling was required.
+    pub spill_area_size: Option<u64>,
+    /// Whether the size fallback was activated.
+    pub is_size_fallback: bool,
+}
+
+impl StackDiagnostics {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(spill_area_size: Option<u64>, is_size_fallback: bool) -> Self {
+        Self {
+            spill_area_size,
+            is_size_fallback,
+        }
+    }
+}
+
+impl std::fmt::Display for StackDiagnostics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.spill_area_size {
+            Some(spill_area_size) => write!(f, "spill area: {spill_area_size}B")?,
+            None => write!(f, "spill area: none")?,
+        }
+        write!(
+            f,
+            ", size fallback: {}",
+            if self.is_size_fallback { "yes" } else { "no" }
+        )
+    }
+}
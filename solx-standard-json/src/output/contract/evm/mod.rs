@@ -29,6 +29,9 @@ pub struct EVM {
     /// The contract gas estimates.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub gas_estimates: Option<serde_json::Value>,
+    /// The optimization mode selected by the auto-tuning.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optimization_mode: Option<char>,
 
     /// The extra EVM legacy assembly metadata.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -33,6 +33,9 @@ pub struct Contract {
     /// The contract Yul IR code.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ir: Option<String>,
+    /// The contract Yul AST JSON.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub yul_ast: Option<serde_json::Value>,
     /// The EVM data of the contract.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub evm: Option<EVM>,
@@ -50,6 +53,7 @@ impl Contract {
             && self.devdoc.is_none()
             && self.userdoc.is_none()
             && self.ir.is_none()
+            && self.yul_ast.is_none()
             && self.evm.is_none()
     }
 }
@@ -133,7 +133,7 @@ impl Compiler for LLVMIRCompiler {
             }
         };
 
-        Ok(EVMInput::new(builds, None, last_contract))
+        Ok(EVMInput::new(builds, None, last_contract, vec![]))
     }
 
     fn all_modes(&self) -> Vec<Mode> {
@@ -534,7 +534,12 @@ impl Compiler for SolidityCompiler {
             }
         }
 
-        Ok(EVMInput::new(builds, method_identifiers, last_contract))
+        Ok(EVMInput::new(
+            builds,
+            method_identifiers,
+            last_contract,
+            vec![],
+        ))
     }
 
     fn all_modes(&self) -> Vec<Mode> {
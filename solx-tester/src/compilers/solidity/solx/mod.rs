@@ -18,6 +18,7 @@ use solx_standard_json::CollectableError;
 use crate::compilers::mode::Mode;
 use crate::compilers::Compiler;
 use crate::revm::input::Input as EVMInput;
+use crate::revm::profiler::symbols::Symbols;
 
 use self::mode::Mode as SolxMode;
 
@@ -29,6 +30,8 @@ pub struct SolidityCompiler {
     pub path: PathBuf,
     /// `solx` compiler version.
     pub version: semver::Version,
+    /// Whether to request the block maps, which are used to map bytecode to functions in gas profiles.
+    pub emit_block_maps: bool,
 }
 
 impl SolidityCompiler {
@@ -40,7 +43,11 @@ impl SolidityCompiler {
     ///
     pub fn try_from_path(path: PathBuf) -> anyhow::Result<Self> {
         let version = Self::version(path.as_path())?;
-        Ok(Self { path, version })
+        Ok(Self {
+            path,
+            version,
+            emit_block_maps: false,
+        })
     }
 
     ///
@@ -105,6 +112,46 @@ impl SolidityCompiler {
             })
     }
 
+    ///
+    /// Inserts the block map selectors if gas profiling symbols are requested.
+    ///
+    pub fn insert_block_map_selectors(
+        &self,
+        selectors: &mut BTreeSet<solx_standard_json::InputSelector>,
+    ) {
+        if self.emit_block_maps {
+            selectors.insert(solx_standard_json::InputSelector::BytecodeBlockMap);
+            selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeBlockMap);
+        }
+    }
+
+    ///
+    /// Extracts the gas profiling symbols of the deploy and runtime code from the contract output.
+    ///
+    pub fn symbols(
+        contract: &str,
+        evm: &solx_standard_json::OutputContractEVM,
+    ) -> anyhow::Result<Vec<Symbols>> {
+        let mut symbols = Vec::with_capacity(2);
+        for (bytecode, is_deploy) in [
+            (evm.bytecode.as_ref(), true),
+            (evm.deployed_bytecode.as_ref(), false),
+        ] {
+            let Some((object, block_map)) = bytecode.and_then(|bytecode| {
+                Some((bytecode.object.as_ref()?, bytecode.block_map.as_ref()?))
+            }) else {
+                continue;
+            };
+            symbols.push(Symbols::new(
+                contract.to_owned(),
+                is_deploy,
+                hex::decode(object.as_str())?,
+                block_map.to_owned(),
+            ));
+        }
+        Ok(symbols)
+    }
+
     ///
     /// Runs the `solx` subprocess and returns its version.
     ///
@@ -268,6 +315,7 @@ impl Compiler for SolidityCompiler {
         } else {
             solx_standard_json::InputSelector::EVMLegacyAssembly
         });
+        self.insert_block_map_selectors(&mut selectors);
         let solx_input = solx_standard_json::Input::try_from_solidity_sources(
             sources_json,
             libraries,
@@ -307,6 +355,7 @@ impl Compiler for SolidityCompiler {
         let last_contract = Self::get_last_contract(&solx_output, &sources)?;
 
         let mut builds = HashMap::with_capacity(solx_output.contracts.len());
+        let mut symbols = Vec::new();
         for (file, source) in solx_output.contracts.iter() {
             for (name, contract) in source.iter() {
                 let deploy_code = match contract
@@ -327,7 +376,11 @@ impl Compiler for SolidityCompiler {
                     Some(deployed_bytecode) => deployed_bytecode.len() / 2,
                     None => 0,
                 };
-                builds.insert(format!("{file}:{name}"), (deploy_code, runtime_code_size));
+                let full_name = format!("{file}:{name}");
+                if let Some(evm) = contract.evm.as_ref() {
+                    symbols.extend(Self::symbols(full_name.as_str(), evm)?);
+                }
+                builds.insert(full_name, (deploy_code, runtime_code_size));
             }
         }
 
@@ -335,6 +388,7 @@ impl Compiler for SolidityCompiler {
             builds,
             Some(method_identifiers),
             last_contract,
+            symbols,
         ))
     }
 
@@ -76,6 +76,7 @@ impl Compiler for YulCompiler {
                 selectors.insert(solx_standard_json::InputSelector::MethodIdentifiers);
                 selectors.insert(solx_standard_json::InputSelector::Metadata);
                 selectors.insert(solx_standard_json::InputSelector::Yul);
+                solx.insert_block_map_selectors(&mut selectors);
                 let solx_input = solx_standard_json::Input::from_yul_sources(
                     sources,
                     libraries.to_owned(),
@@ -99,6 +100,7 @@ impl Compiler for YulCompiler {
                 solx_output.check_errors()?;
 
                 let mut builds = HashMap::with_capacity(solx_output.contracts.len());
+                let mut symbols = Vec::new();
                 for (file, contracts) in solx_output.contracts.into_iter() {
                     for (_name, contract) in contracts.into_iter() {
                         let evm = contract.evm.as_ref().ok_or_else(|| {
@@ -134,11 +136,12 @@ impl Compiler for YulCompiler {
                             })?
                             .len()
                             / 2;
+                        symbols.extend(SolxCompiler::symbols(file.as_str(), evm)?);
                         builds.insert(file.clone(), (deploy_code, runtime_code_size));
                     }
                 }
 
-                Ok(EVMInput::new(builds, None, last_contract))
+                Ok(EVMInput::new(builds, None, last_contract, symbols))
             }
             Self::Solc | Self::SolcLLVM => {
                 let language = SolcStandardJsonInputLanguage::Yul;
@@ -209,7 +212,7 @@ impl Compiler for YulCompiler {
                     }
                 }
 
-                Ok(EVMInput::new(builds, None, last_contract))
+                Ok(EVMInput::new(builds, None, last_contract, vec![]))
             }
         }
     }
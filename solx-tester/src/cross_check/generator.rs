@@ -0,0 +1,341 @@
+//!
+//! The random Yul program generator.
+//!
+
+use std::fmt::Write;
+
+///
+/// The random Yul program generator.
+///
+/// Generates deterministic programs from a seed. The runtime code only uses builtins whose
+/// results do not depend on the outside world, so they can be compared between the reference
+/// interpreter and the compiled bytecode. All loops are bounded, and every program ends with
+/// `return` of the memory words it has written.
+///
+#[derive(Debug)]
+pub struct Generator {
+    /// The pseudo-random number generator state.
+    state: u64,
+    /// The variables visible in each of the nested scopes.
+    scopes: Vec<Vec<String>>,
+    /// The counter used to make variable names unique.
+    variables_count: usize,
+    /// The remaining statement budget.
+    budget: usize,
+}
+
+impl Generator {
+    /// The maximum number of statements in a program.
+    const STATEMENTS_LIMIT: usize = 48;
+
+    /// The maximum expression depth.
+    const EXPRESSION_DEPTH_LIMIT: usize = 4;
+
+    /// The maximum block nesting depth.
+    const BLOCK_DEPTH_LIMIT: usize = 3;
+
+    /// The number of memory words the program may write and return.
+    const MEMORY_WORDS: u64 = 8;
+
+    /// The number of storage slots the program may access.
+    const STORAGE_SLOTS: u64 = 4;
+
+    /// The number of calldata words the program may read.
+    pub const CALLDATA_WORDS: u64 = 2;
+
+    /// The binary builtins.
+    const BINARY: [&'static str; 21] = [
+        "add",
+        "sub",
+        "mul",
+        "div",
+        "sdiv",
+        "mod",
+        "smod",
+        "exp",
+        "lt",
+        "gt",
+        "slt",
+        "sgt",
+        "eq",
+        "and",
+        "or",
+        "xor",
+        "shl",
+        "shr",
+        "sar",
+        "byte",
+        "signextend",
+    ];
+
+    /// The unary builtins.
+    const UNARY: [&'static str; 2] = ["iszero", "not"];
+
+    /// The ternary builtins.
+    const TERNARY: [&'static str; 2] = ["addmod", "mulmod"];
+
+    /// The interesting constants.
+    const CONSTANTS: [&'static str; 8] = [
+        "0",
+        "1",
+        "2",
+        "31",
+        "32",
+        "255",
+        "0x8000000000000000000000000000000000000000000000000000000000000000",
+        "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    ];
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: seed ^ 0x9E37_79B9_7F4A_7C15,
+            scopes: vec![],
+            variables_count: 0,
+            budget: Self::STATEMENTS_LIMIT,
+        }
+    }
+
+    ///
+    /// Generates a program with the specified object name.
+    ///
+    pub fn program(&mut self, name: &str) -> String {
+        self.scopes = vec![vec![]];
+        self.variables_count = 0;
+        self.budget = Self::STATEMENTS_LIMIT;
+
+        let mut body = String::new();
+        let helper = Self::BINARY[self.below(Self::BINARY.len() as u64) as usize];
+        let _ = writeln!(body, "function helper(a, b) -> r {{ r := {helper}(a, b) }}");
+        for index in 0..Self::CALLDATA_WORDS {
+            let variable = self.declare();
+            let _ = writeln!(body, "let {variable} := calldataload({})", index * 32);
+        }
+        while self.budget > 0 {
+            body.push_str(self.statement(0).as_str());
+        }
+        for index in 0..Self::MEMORY_WORDS {
+            if self.below(2) == 0 {
+                let value = self.expression(1);
+                let _ = writeln!(body, "mstore({}, {value})", index * 32);
+            }
+        }
+        let _ = writeln!(body, "return(0, {})", Self::MEMORY_WORDS * 32);
+
+        format!(
+            r#"object "{name}" {{
+    code {{
+        {{
+            let size := datasize("{name}_deployed")
+            codecopy(0, dataoffset("{name}_deployed"), size)
+            return(0, size)
+        }}
+    }}
+    object "{name}_deployed" {{
+        code {{
+            {{
+{body}
+            }}
+        }}
+    }}
+}}
+"#
+        )
+    }
+
+    ///
+    /// Generates the calldata of the specified number of inputs.
+    ///
+    pub fn calldata(&mut self, inputs: usize) -> Vec<Vec<u8>> {
+        (0..inputs)
+            .map(|_| {
+                (0..Self::CALLDATA_WORDS)
+                    .flat_map(|_| {
+                        let mut word = [0u8; solx_utils::BYTE_LENGTH_FIELD];
+                        match self.below(3) {
+                            0 => word[solx_utils::BYTE_LENGTH_FIELD - 1] = self.below(4) as u8,
+                            1 => word.iter_mut().for_each(|byte| *byte = 0xFF),
+                            _ => word.iter_mut().for_each(|byte| *byte = self.next() as u8),
+                        }
+                        word
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    ///
+    /// Generates a statement at the specified block depth.
+    ///
+    fn statement(&mut self, depth: usize) -> String {
+        self.budget = self.budget.saturating_sub(1);
+
+        let kinds = if depth < Self::BLOCK_DEPTH_LIMIT {
+            9
+        } else {
+            6
+        };
+        match self.below(kinds) {
+            0 | 1 => {
+                let value = self.expression(0);
+                let variable = self.declare();
+                format!("let {variable} := {value}\n")
+            }
+            2 => {
+                let value = self.expression(0);
+                let variable = self.variable();
+                format!("{variable} := {value}\n")
+            }
+            3 => {
+                let slot = self.below(Self::STORAGE_SLOTS);
+                let value = self.expression(0);
+                format!("sstore({slot}, {value})\n")
+            }
+            4 => {
+                let offset = self.below(Self::MEMORY_WORDS) * 32;
+                let value = self.expression(0);
+                format!("mstore({offset}, {value})\n")
+            }
+            5 => {
+                let topic = self.expression(1);
+                format!("log1(0, 32, {topic})\n")
+            }
+            6 => {
+                let condition = self.expression(1);
+                let body = if self.below(8) == 0 {
+                    "revert(0, 32)\n".to_owned()
+                } else {
+                    self.block(depth + 1)
+                };
+                format!("if {condition} {{\n{body}}}\n")
+            }
+            7 => {
+                let condition = self.expression(1);
+                let case = self.block(depth + 1);
+                let default = self.block(depth + 1);
+                format!(
+                    "switch and({condition}, 1)\ncase 0 {{\n{case}}}\ndefault {{\n{default}}}\n"
+                )
+            }
+            _ => {
+                let counter = format!("i{}", self.variables_count);
+                self.variables_count += 1;
+                let bound = self.below(4) + 1;
+                let body = self.block(depth + 1);
+                format!(
+                    "for {{ let {counter} := 0 }} lt({counter}, {bound}) {{ {counter} := add({counter}, 1) }} {{\n{body}}}\n"
+                )
+            }
+        }
+    }
+
+    ///
+    /// Generates a block of statements in a new scope.
+    ///
+    fn block(&mut self, depth: usize) -> String {
+        self.scopes.push(vec![]);
+        let mut block = String::new();
+        for _ in 0..=self.below(3) {
+            if self.budget == 0 {
+                break;
+            }
+            block.push_str(self.statement(depth).as_str());
+        }
+        self.scopes.pop();
+        block
+    }
+
+    ///
+    /// Generates an expression at the specified depth.
+    ///
+    fn expression(&mut self, depth: usize) -> String {
+        let kinds = if depth < Self::EXPRESSION_DEPTH_LIMIT {
+            10
+        } else {
+            4
+        };
+        match self.below(kinds) {
+            0 => Self::CONSTANTS[self.below(Self::CONSTANTS.len() as u64) as usize].to_owned(),
+            1 | 2 => self.variable(),
+            3 => match self.below(3) {
+                0 => format!("mload({})", self.below(Self::MEMORY_WORDS) * 32),
+                1 => format!("sload({})", self.below(Self::STORAGE_SLOTS)),
+                _ => format!(
+                    "calldataload({})",
+                    self.below(Self::CALLDATA_WORDS * 32 + 1)
+                ),
+            },
+            4..=6 => {
+                let name = Self::BINARY[self.below(Self::BINARY.len() as u64) as usize];
+                let left = self.expression(depth + 1);
+                let right = self.expression(depth + 1);
+                format!("{name}({left}, {right})")
+            }
+            7 => {
+                let name = Self::UNARY[self.below(Self::UNARY.len() as u64) as usize];
+                let operand = self.expression(depth + 1);
+                format!("{name}({operand})")
+            }
+            8 => {
+                let name = Self::TERNARY[self.below(Self::TERNARY.len() as u64) as usize];
+                let first = self.expression(depth + 1);
+                let second = self.expression(depth + 1);
+                let modulo = self.expression(depth + 1);
+                format!("{name}({first}, {second}, {modulo})")
+            }
+            _ => {
+                let left = self.expression(depth + 1);
+                let right = self.expression(depth + 1);
+                format!("helper({left}, {right})")
+            }
+        }
+    }
+
+    ///
+    /// Declares a new variable in the current scope.
+    ///
+    fn declare(&mut self) -> String {
+        let variable = format!("v{}", self.variables_count);
+        self.variables_count += 1;
+        self.scopes
+            .last_mut()
+            .expect("Always exists")
+            .push(variable.clone());
+        variable
+    }
+
+    ///
+    /// Returns a random visible variable.
+    ///
+    /// Loop counters are never returned, so the loops are always bounded.
+    ///
+    fn variable(&mut self) -> String {
+        let visible = self.scopes.iter().flatten().count() as u64;
+        let index = self.below(visible) as usize;
+        self.scopes
+            .iter()
+            .flatten()
+            .nth(index)
+            .cloned()
+            .expect("Always exists")
+    }
+
+    ///
+    /// Returns a pseudo-random number below `bound`.
+    ///
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    ///
+    /// Returns the next pseudo-random number.
+    ///
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+}
@@ -0,0 +1,496 @@
+//!
+//! The reference Yul interpreter cross-check.
+//!
+
+pub mod generator;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use colored::Colorize;
+use revm::context::result::ExecutionResult;
+use solx_yul::yul::interpreter::environment::Environment as InterpreterEnvironment;
+use solx_yul::yul::interpreter::host::Host;
+use solx_yul::yul::interpreter::outcome::Outcome;
+use solx_yul::yul::interpreter::status::Status;
+use solx_yul::yul::interpreter::Interpreter;
+use solx_yul::yul::lexer::Lexer;
+use solx_yul::yul::parser::dialect::DefaultDialect;
+use solx_yul::yul::parser::statement::expression::function_call::name::Name;
+use solx_yul::yul::parser::statement::object::Object;
+use solx_yul::yul::visitor_mut::VisitorMut;
+
+use crate::compilers::mode::Mode;
+use crate::compilers::solidity::solx::SolidityCompiler as SolxCompiler;
+use crate::compilers::yul::mode::Mode as YulMode;
+use crate::compilers::yul::YulCompiler;
+use crate::compilers::Compiler;
+use crate::directories::matter_labs::test::metadata::Metadata;
+use crate::directories::matter_labs::test::DEFAULT_CALLER_ADDRESS;
+use crate::revm::revm_type_conversions::web3_address_to_revm_address;
+use crate::revm::REVM;
+use crate::test::case::input::calldata::Calldata;
+use crate::test::case::input::environment::Environment;
+
+use self::generator::Generator;
+
+///
+/// The reference Yul interpreter cross-check.
+///
+/// Compiles Yul objects with `solx`, executes them on REVM, and compares the runtime outcomes
+/// with the ones of the reference interpreter from `solx-yul`: the status, return data, logs,
+/// and the final storage. The deploy code is only compared by status, as the interpreter does
+/// not materialize the code of objects.
+///
+/// Objects whose runtime code depends on the outside world, gas, or its own code are skipped,
+/// as the interpreter has no access to them.
+///
+pub struct CrossCheck {
+    /// The `solx` Yul compiler.
+    compiler: YulCompiler,
+    /// The compilation mode.
+    mode: Mode,
+}
+
+///
+/// The cross-check verdict.
+///
+#[derive(Debug)]
+enum Verdict {
+    /// The outcomes match.
+    Passed,
+    /// The object cannot be checked.
+    Skipped(String),
+    /// The outcomes differ.
+    Failed(String),
+}
+
+///
+/// The host of the interpreter, describing an empty world.
+///
+struct EmptyHost;
+
+impl Host for EmptyHost {}
+
+///
+/// Collects the names of all called functions.
+///
+#[derive(Default)]
+struct NameCollector {
+    /// The collected names.
+    names: BTreeSet<String>,
+}
+
+impl VisitorMut<DefaultDialect> for NameCollector {
+    fn visit_name_mut(&mut self, name: &mut Name) {
+        self.names.insert(name.to_string());
+    }
+}
+
+impl CrossCheck {
+    /// The default directory with the Yul corpus.
+    pub const DEFAULT_DIRECTORY: &'static str = "tests/yul";
+
+    /// The number of inputs executed for each generated program.
+    const GENERATED_INPUTS: usize = 4;
+
+    /// The builtins whose results cannot be reproduced by the interpreter.
+    const UNSUPPORTED_BUILTINS: [&'static str; 24] = [
+        "gas",
+        "msize",
+        "pc",
+        "call",
+        "callcode",
+        "delegatecall",
+        "staticcall",
+        "create",
+        "create2",
+        "balance",
+        "selfbalance",
+        "extcodesize",
+        "extcodecopy",
+        "extcodehash",
+        "blockhash",
+        "blobhash",
+        "blobbasefee",
+        "codesize",
+        "codecopy",
+        "datasize",
+        "dataoffset",
+        "datacopy",
+        "returndatasize",
+        "returndatacopy",
+    ];
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(solx: PathBuf) -> anyhow::Result<Self> {
+        let compiler = YulCompiler::Solx(Arc::new(SolxCompiler::try_from_path(solx)?));
+        let mode = YulMode::new(solx_codegen_evm::OptimizerSettings::cycles()).into();
+        Ok(Self { compiler, mode })
+    }
+
+    ///
+    /// Checks the Yul files in `paths` and `generated` programs generated from `seed`.
+    ///
+    /// Directories are searched for `.yul` files recursively.
+    ///
+    pub fn run(&self, paths: Vec<PathBuf>, generated: usize, seed: u64) -> anyhow::Result<()> {
+        let paths = if paths.is_empty() {
+            vec![PathBuf::from(Self::DEFAULT_DIRECTORY)]
+        } else {
+            paths
+        };
+
+        let mut checks = Vec::new();
+        for path in paths.into_iter() {
+            let files = if path.is_dir() {
+                glob::glob(format!("{}/**/*.yul", path.to_string_lossy()).as_str())?
+                    .collect::<Result<Vec<PathBuf>, glob::GlobError>>()?
+            } else {
+                vec![path]
+            };
+            for file in files.into_iter() {
+                let source = std::fs::read_to_string(file.as_path())
+                    .map_err(|error| anyhow::anyhow!("File {file:?} reading: {error}"))?;
+                let name = file.to_string_lossy().to_string();
+                for (case, calldata) in Self::corpus_inputs(source.as_str())?.into_iter() {
+                    checks.push((format!("{name}::{case}"), source.clone(), calldata));
+                }
+            }
+        }
+        let mut generator = Generator::new(seed);
+        for index in 0..generated {
+            let name = format!("Generated_{seed}_{index}");
+            let source = generator.program(name.as_str());
+            let calldata = generator.calldata(Self::GENERATED_INPUTS);
+            checks.push((name, source, calldata));
+        }
+
+        let mut failed = 0;
+        for (name, source, calldata) in checks.iter() {
+            match self.check(name.as_str(), source.as_str(), calldata.as_slice()) {
+                Ok(Verdict::Passed) => println!("{:>7} {name}", "PASSED".green()),
+                Ok(Verdict::Skipped(reason)) => {
+                    println!("{:>7} {name} ({reason})", "SKIPPED".bright_black())
+                }
+                Ok(Verdict::Failed(reason)) => {
+                    failed += 1;
+                    println!("{:>7} {name}: {reason}", "FAILED".bright_red());
+                    if name.starts_with("Generated_") {
+                        println!("{source}");
+                    }
+                }
+                Err(error) => {
+                    failed += 1;
+                    println!("{:>7} {name}: {error}", "INVALID".red());
+                }
+            }
+        }
+        println!(
+            "     {} {} objects, {failed} failed",
+            "Checked".bright_green().bold(),
+            checks.len(),
+        );
+        if failed > 0 {
+            anyhow::bail!("");
+        }
+        Ok(())
+    }
+
+    ///
+    /// Extracts the calldata of the runtime inputs of each test case from the test metadata.
+    ///
+    /// Inputs with values, storage overrides, or calldata referencing contract instances are
+    /// not supported and ignored.
+    ///
+    fn corpus_inputs(source: &str) -> anyhow::Result<Vec<(String, Vec<Vec<u8>>)>> {
+        let metadata = Metadata::from_str(source)?;
+        if metadata.ignore {
+            return Ok(vec![]);
+        }
+
+        let mut cases = Vec::with_capacity(metadata.cases.len());
+        for case in metadata.cases.into_iter().filter(|case| !case.ignore) {
+            let mut calldata = Vec::with_capacity(case.inputs.len());
+            for input in case.inputs.into_iter() {
+                if input.method == "#deployer" || input.value.is_some() || !input.storage.is_empty()
+                {
+                    continue;
+                }
+                let Ok(mut input_calldata) =
+                    Calldata::try_from_matter_labs(input.calldata, &BTreeMap::new())
+                else {
+                    continue;
+                };
+                if input.method != "#fallback" {
+                    let Ok(selector) =
+                        u32::from_str_radix(input.method.as_str(), solx_utils::BASE_HEXADECIMAL)
+                    else {
+                        continue;
+                    };
+                    input_calldata.push_selector(selector);
+                }
+                calldata.push(input_calldata.inner);
+            }
+            cases.push((case.name, calldata));
+        }
+        Ok(cases)
+    }
+
+    ///
+    /// Checks the object with the specified runtime calldata.
+    ///
+    fn check(&self, name: &str, source: &str, calldata: &[Vec<u8>]) -> anyhow::Result<Verdict> {
+        let mut lexer = Lexer::new(source);
+        let mut object =
+            Object::<DefaultDialect>::parse(&mut lexer, None, solx_utils::CodeSegment::Deploy)?;
+        let Some(runtime) = object.inner_object.as_deref_mut() else {
+            return Ok(Verdict::Skipped("no runtime object".to_owned()));
+        };
+        let mut collector = NameCollector::default();
+        collector.visit_object_mut(runtime);
+        if let Some(builtin) = Self::UNSUPPORTED_BUILTINS
+            .iter()
+            .find(|builtin| collector.names.contains(**builtin))
+        {
+            return Ok(Verdict::Skipped(format!("`{builtin}` is not supported")));
+        }
+        let object = object;
+        let runtime = object.inner_object.as_deref().expect("Always exists");
+
+        let build = self.compiler.compile_for_evm(
+            name.to_owned(),
+            vec![(name.to_owned(), source.to_owned())],
+            solx_utils::Libraries::default(),
+            &self.mode,
+            None,
+            vec![],
+            None,
+        )?;
+        let (deploy_code, _) = build
+            .builds
+            .get(build.last_contract.as_str())
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("The build of `{name}` not found"))?;
+
+        let environment = Environment::default();
+        let caller = web3::types::Address::from_str(DEFAULT_CALLER_ADDRESS).expect("Always valid");
+        let mut vm = REVM::new(false);
+        vm.set_account(&caller, web3::types::U256::from(1) << 100);
+
+        let mut interpreter = Interpreter::new(
+            Self::interpreter_environment(&vm, &environment, &caller),
+            EmptyHost,
+        );
+
+        let deploy_result = vm
+            .execute_transaction(REVM::new_deploy_transaction(
+                caller,
+                None,
+                deploy_code,
+                &environment,
+            ))
+            .map_err(|error| anyhow::anyhow!("REVM deploy transaction: {error:?}"))?;
+        let deploy_outcome = match interpreter.execute(&object) {
+            Ok(outcome) => outcome,
+            Err(error) => return Ok(Verdict::Skipped(format!("interpreter: {error}"))),
+        };
+        if Self::revm_status(&deploy_result) != Self::interpreter_status(&deploy_outcome) {
+            return Ok(Verdict::Failed(format!(
+                "deploy status: REVM {:?}, interpreter {:?}",
+                Self::revm_status(&deploy_result),
+                deploy_outcome.status,
+            )));
+        }
+        let address = match deploy_result {
+            ExecutionResult::Success {
+                output: revm::context::result::Output::Create(_, Some(address)),
+                ..
+            } => address,
+            _ => return Ok(Verdict::Passed),
+        };
+        interpreter.environment.address = num::BigUint::from_bytes_be(address.as_slice());
+
+        for (index, calldata) in calldata.iter().enumerate() {
+            let tx = revm::context::TxEnv::builder()
+                .caller(web3_address_to_revm_address(&caller))
+                .data(revm::primitives::Bytes::from(calldata.to_owned()))
+                .to(address)
+                .gas_price(REVM::gas_price(&environment))
+                .gas_limit(REVM::BLOCK_GAS_LIMIT)
+                .chain_id(Some(vm.chain_id()))
+                .build_fill();
+            let result = vm
+                .execute_transaction(tx)
+                .map_err(|error| anyhow::anyhow!("REVM runtime transaction: {error:?}"))?;
+
+            interpreter.environment.calldata = calldata.to_owned();
+            let outcome = match interpreter.execute(runtime) {
+                Ok(outcome) => outcome,
+                Err(error) => return Ok(Verdict::Skipped(format!("interpreter: {error}"))),
+            };
+
+            if let Some(mismatch) = Self::compare(&result, &outcome) {
+                return Ok(Verdict::Failed(format!("input #{index}: {mismatch}")));
+            }
+        }
+
+        let revm_storage: BTreeMap<num::BigUint, num::BigUint> = vm
+            .db()
+            .cache
+            .accounts
+            .get(&address)
+            .and_then(|account| account.account.as_ref())
+            .map(|account| {
+                account
+                    .storage
+                    .iter()
+                    .filter(|(_, value)| !value.is_zero())
+                    .map(|(key, value)| {
+                        (
+                            num::BigUint::from_bytes_be(key.to_be_bytes::<32>().as_slice()),
+                            num::BigUint::from_bytes_be(value.to_be_bytes::<32>().as_slice()),
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let interpreter_storage: BTreeMap<num::BigUint, num::BigUint> = interpreter
+            .storage
+            .iter()
+            .filter(|(_, value)| **value != num::BigUint::default())
+            .map(|(key, value)| (key.to_owned(), value.to_owned()))
+            .collect();
+        if revm_storage != interpreter_storage {
+            return Ok(Verdict::Failed(format!(
+                "storage: REVM {revm_storage:?}, interpreter {interpreter_storage:?}"
+            )));
+        }
+
+        Ok(Verdict::Passed)
+    }
+
+    ///
+    /// Compares the REVM execution result with the interpreter outcome.
+    ///
+    /// Returns the description of the first mismatch, if any.
+    ///
+    fn compare(result: &ExecutionResult, outcome: &Outcome) -> Option<String> {
+        let (status, return_data, logs) = match result {
+            ExecutionResult::Success { output, logs, .. } => (
+                Self::revm_status(result),
+                output.data().to_vec(),
+                logs.as_slice(),
+            ),
+            ExecutionResult::Revert { output, .. } => {
+                (Self::revm_status(result), output.to_vec(), [].as_slice())
+            }
+            ExecutionResult::Halt { .. } => (Self::revm_status(result), vec![], [].as_slice()),
+        };
+
+        if status != Self::interpreter_status(outcome) {
+            return Some(format!(
+                "status: REVM {status:?}, interpreter {:?}",
+                outcome.status
+            ));
+        }
+        if status != Status::Invalid && return_data != outcome.return_data {
+            return Some(format!(
+                "return data: REVM 0x{}, interpreter 0x{}",
+                hex::encode(return_data.as_slice()),
+                hex::encode(outcome.return_data.as_slice()),
+            ));
+        }
+        let logs = logs
+            .iter()
+            .map(|log| {
+                (
+                    log.data
+                        .topics()
+                        .iter()
+                        .map(|topic| num::BigUint::from_bytes_be(topic.as_slice()))
+                        .collect::<Vec<num::BigUint>>(),
+                    log.data.data.to_vec(),
+                )
+            })
+            .collect::<Vec<(Vec<num::BigUint>, Vec<u8>)>>();
+        let interpreter_logs = outcome
+            .logs
+            .iter()
+            .map(|log| (log.topics.to_owned(), log.data.to_owned()))
+            .collect::<Vec<(Vec<num::BigUint>, Vec<u8>)>>();
+        if logs != interpreter_logs {
+            return Some(format!(
+                "logs: REVM {logs:?}, interpreter {interpreter_logs:?}"
+            ));
+        }
+        None
+    }
+
+    ///
+    /// Returns the interpreter status equivalent of the REVM execution result.
+    ///
+    /// `Stop` and `Return` are not distinguished, as REVM reports both as success.
+    ///
+    fn revm_status(result: &ExecutionResult) -> Status {
+        match result {
+            ExecutionResult::Success { .. } => Status::Return,
+            ExecutionResult::Revert { .. } => Status::Revert,
+            ExecutionResult::Halt { .. } => Status::Invalid,
+        }
+    }
+
+    ///
+    /// Returns the interpreter status with `Stop` merged into `Return`.
+    ///
+    fn interpreter_status(outcome: &Outcome) -> Status {
+        match outcome.status {
+            Status::Stop => Status::Return,
+            status => status,
+        }
+    }
+
+    ///
+    /// Returns the interpreter environment matching the REVM block and transaction.
+    ///
+    fn interpreter_environment(
+        vm: &REVM,
+        environment: &Environment,
+        caller: &web3::types::Address,
+    ) -> InterpreterEnvironment {
+        let block = vm.block();
+        let caller = num::BigUint::from_bytes_be(caller.as_bytes());
+        InterpreterEnvironment {
+            address: num::BigUint::default(),
+            caller: caller.clone(),
+            call_value: num::BigUint::default(),
+            calldata: vec![],
+            origin: caller,
+            gas_price: Self::to_biguint(REVM::gas_price(environment)),
+            gas: num::BigUint::default(),
+            gas_limit: Self::to_biguint(block.gas_limit),
+            chain_id: Self::to_biguint(vm.chain_id()),
+            number: Self::to_biguint(block.number),
+            timestamp: Self::to_biguint(block.timestamp),
+            coinbase: num::BigUint::from_bytes_be(block.beneficiary.as_slice()),
+            base_fee: Self::to_biguint(block.basefee),
+            blob_base_fee: num::BigUint::default(),
+            prevrandao: block
+                .prevrandao
+                .map(|prevrandao| num::BigUint::from_bytes_be(prevrandao.as_slice()))
+                .unwrap_or_default(),
+        }
+    }
+
+    ///
+    /// Converts a REVM integer of any width into a big integer.
+    ///
+    fn to_biguint(value: impl ToString) -> num::BigUint {
+        num::BigUint::from_str(value.to_string().as_str()).expect("Always valid")
+    }
+}
@@ -277,6 +277,7 @@ impl Buildable for EthereumTest {
             vec![case],
             mode,
             self.index_entity.group.clone(),
+            evm_input.symbols,
         ))
     }
 }
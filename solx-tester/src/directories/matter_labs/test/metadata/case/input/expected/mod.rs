@@ -36,10 +36,8 @@ impl Expected {
     ///
     pub fn successful_evm_interpreter_benchmark(exception: bool) -> Self {
         Self::Single(Variant::Extended(Extended {
-            return_data: vec![],
-            events: vec![],
             exception,
-            compiler_version: None,
+            ..Default::default()
         }))
     }
 
@@ -66,7 +64,7 @@ impl Expected {
             .ok_or_else(|| anyhow::anyhow!("Version is not covered"))?;
         Ok(match variant {
             Variant::Simple(_) => false,
-            Variant::Extended(inner) => inner.exception,
+            Variant::Extended(inner) => inner.is_exception(),
         })
     }
 }
@@ -14,6 +14,7 @@ use self::event::Event;
 #[derive(Debug, Default, Clone, Deserialize)]
 pub struct Extended {
     /// The return data values.
+    #[serde(default)]
     pub return_data: Vec<String>,
     /// The emitted events.
     #[serde(default)]
@@ -21,6 +22,23 @@ pub struct Extended {
     /// Whether an exception is expected,
     #[serde(default)]
     pub exception: bool,
+    /// The expected custom error signature, e.g. `InsufficientBalance(uint256,uint256)`.
+    /// Implies an exception.
+    pub error: Option<String>,
+    /// The expected custom error arguments.
+    #[serde(default)]
+    pub arguments: Vec<String>,
+    /// The expected `Error(string)` revert reason. Implies an exception.
+    pub revert_string: Option<String>,
     /// The compiler version filter.
     pub compiler_version: Option<semver::VersionReq>,
 }
+
+impl Extended {
+    ///
+    /// Whether an exception is expected, either explicitly or by a revert reason.
+    ///
+    pub fn is_exception(&self) -> bool {
+        self.exception || self.error.is_some() || self.revert_string.is_some()
+    }
+}
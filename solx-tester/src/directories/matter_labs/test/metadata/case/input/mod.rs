@@ -9,6 +9,7 @@ pub mod storage;
 use std::collections::HashMap;
 
 use crate::directories::matter_labs::test::default_caller_address;
+use crate::directories::matter_labs::test::metadata::environment::Environment;
 use crate::directories::matter_labs::test::simple_tests_instance;
 
 use self::calldata::Calldata;
@@ -39,6 +40,8 @@ pub struct Input {
     /// The initial contracts storage.
     #[serde(default)]
     pub storage: HashMap<String, Storage>,
+    /// The block and transaction environment overrides.
+    pub environment: Option<Environment>,
 
     /// The expected return data.
     pub expected: Option<Expected>,
@@ -57,6 +60,7 @@ impl Input {
             method: "#deployer".to_string(),
             value: None,
             storage: HashMap::new(),
+            environment: None,
 
             expected: Some(Expected::successful_deployer_expected(instance.clone())),
         }
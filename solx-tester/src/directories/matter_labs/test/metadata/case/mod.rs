@@ -13,6 +13,8 @@ use crate::revm::address_iterator::AddressIterator;
 use crate::test::case::input::value::Value;
 use crate::test::instance::Instance;
 
+use super::environment::Environment;
+
 use self::input::expected::Expected;
 use self::input::Input;
 
@@ -47,9 +49,11 @@ impl Case {
         mut self,
         contracts: &BTreeMap<String, String>,
         instances: &BTreeMap<String, Instance>,
+        environment: &Environment,
     ) -> anyhow::Result<Self> {
         self.normalize_deployer_calls(contracts, instances)?;
         self.normalize_expected();
+        self.normalize_environment(environment);
         Ok(self)
     }
 
@@ -108,6 +112,15 @@ impl Case {
         }
     }
 
+    ///
+    /// Applies the test-level environment to inputs, keeping the input-level overrides.
+    ///
+    pub fn normalize_environment(&mut self, environment: &Environment) {
+        for input in self.inputs.iter_mut() {
+            input.environment = Some(environment.merge(input.environment.as_ref()));
+        }
+    }
+
     ///
     /// Sets all variables, including instance addresses, but except libraries.
     ///
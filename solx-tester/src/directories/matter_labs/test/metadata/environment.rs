@@ -0,0 +1,52 @@
+//!
+//! The Matter Labs compiler test metadata block and transaction environment.
+//!
+
+///
+/// The Matter Labs compiler test metadata block and transaction environment.
+///
+/// Each field overrides the corresponding REVM default. The values are parsed the same way as
+/// calldata and storage values, so hexadecimal literals and `<instance>.address` are supported.
+///
+#[derive(Debug, Default, Clone, serde::Deserialize)]
+pub struct Environment {
+    /// The block number.
+    pub block_number: Option<String>,
+    /// The block timestamp.
+    pub timestamp: Option<String>,
+    /// The block base fee.
+    pub basefee: Option<String>,
+    /// The block prevrandao.
+    pub prevrandao: Option<String>,
+    /// The chain ID.
+    pub chain_id: Option<String>,
+    /// The transaction gas price.
+    pub gas_price: Option<String>,
+    /// The transaction origin, which is independent of the caller.
+    pub origin: Option<String>,
+}
+
+impl Environment {
+    ///
+    /// Returns the environment with the fields of `self` overridden by the ones of `other`.
+    ///
+    pub fn merge(&self, other: Option<&Self>) -> Self {
+        let other = match other {
+            Some(other) => other,
+            None => return self.to_owned(),
+        };
+
+        Self {
+            block_number: other
+                .block_number
+                .clone()
+                .or_else(|| self.block_number.clone()),
+            timestamp: other.timestamp.clone().or_else(|| self.timestamp.clone()),
+            basefee: other.basefee.clone().or_else(|| self.basefee.clone()),
+            prevrandao: other.prevrandao.clone().or_else(|| self.prevrandao.clone()),
+            chain_id: other.chain_id.clone().or_else(|| self.chain_id.clone()),
+            gas_price: other.gas_price.clone().or_else(|| self.gas_price.clone()),
+            origin: other.origin.clone().or_else(|| self.origin.clone()),
+        }
+    }
+}
@@ -3,11 +3,13 @@
 //!
 
 pub mod case;
+pub mod environment;
 
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
 use self::case::Case;
+use self::environment::Environment;
 
 ///
 /// The Matter Labs compiler test metadata.
@@ -26,6 +28,9 @@ pub struct Metadata {
     /// The test libraries for linking.
     #[serde(default)]
     pub libraries: BTreeMap<String, BTreeMap<String, String>>,
+    /// The block and transaction environment overrides for all inputs.
+    #[serde(default)]
+    pub environment: Environment,
     /// If the entire test file must be ignored.
     #[serde(default)]
     pub ignore: bool,
@@ -325,13 +325,17 @@ impl Buildable for MatterLabsTest {
                 }
             }
 
-            let case = match case.to_owned().normalize(&contracts, &instances) {
-                Ok(case) => case,
-                Err(error) => {
-                    Summary::invalid(summary, test_description, error);
-                    return None;
-                }
-            };
+            let case =
+                match case
+                    .to_owned()
+                    .normalize(&contracts, &instances, &self.metadata.environment)
+                {
+                    Ok(case) => case,
+                    Err(error) => {
+                        Summary::invalid(summary, test_description, error);
+                        return None;
+                    }
+                };
 
             match case.set_variables(&mut instances, address_iterator.clone(), &mode) {
                 Ok(_) => {}
@@ -365,6 +369,7 @@ impl Buildable for MatterLabsTest {
             cases,
             mode,
             self.metadata.group.clone(),
+            evm_input.symbols,
         ))
     }
 }
@@ -8,12 +8,16 @@
 #![allow(clippy::type_complexity)]
 
 pub(crate) mod compilers;
+pub(crate) mod cross_check;
 pub(crate) mod directories;
 pub(crate) mod filters;
+pub(crate) mod reproduction;
 pub(crate) mod revm;
+pub(crate) mod snapshot;
 pub(crate) mod summary;
 pub(crate) mod test;
 pub(crate) mod toolchain;
+pub(crate) mod trace_converter;
 pub(crate) mod utils;
 pub(crate) mod workflow;
 
@@ -33,15 +37,19 @@ pub use crate::compilers::solidity::solc::SolidityCompiler as SolcCompiler;
 pub use crate::compilers::solidity::solx::SolidityCompiler as SolxCompiler;
 pub use crate::compilers::yul::YulCompiler;
 pub use crate::compilers::Compiler;
+pub use crate::cross_check::CrossCheck;
 pub use crate::directories::ethereum::test::EthereumTest;
 pub use crate::directories::ethereum::EthereumDirectory;
 pub use crate::directories::matter_labs::MatterLabsDirectory;
 pub use crate::directories::Buildable;
 pub use crate::directories::Collection;
 pub use crate::filters::Filters;
+pub use crate::reproduction::Reproduction;
 pub use crate::revm::REVM;
+pub use crate::snapshot::Snapshot;
 pub use crate::summary::Summary;
 pub use crate::toolchain::Toolchain;
+pub use crate::trace_converter::TraceConverter;
 pub use crate::workflow::Workflow;
 
 /// The debug directory path.
@@ -107,8 +115,10 @@ impl<'a> SolxTester<'a> {
         toolchain: Toolchain,
         solx: Option<PathBuf>,
         enable_trace: bool,
+        dump_failures: Option<PathBuf>,
+        gas_profile: Option<PathBuf>,
     ) -> anyhow::Result<()> {
-        let tests = self.all_tests(toolchain, solx)?;
+        let tests = self.all_tests(toolchain, solx, gas_profile.is_some())?;
 
         let _: Vec<()> = tests
             .into_par_iter()
@@ -126,7 +136,12 @@ impl<'a> SolxTester<'a> {
                     specialized_debug_config,
                 ) {
                     if let Workflow::BuildAndRun = self.workflow {
-                        test.run_revm(self.summary.clone(), enable_trace)
+                        test.run_revm(
+                            self.summary.clone(),
+                            enable_trace,
+                            dump_failures.as_deref(),
+                            gas_profile.as_deref(),
+                        )
                     };
                 }
             })
@@ -138,9 +153,16 @@ impl<'a> SolxTester<'a> {
     ///
     /// Returns all tests from all directories.
     ///
-    fn all_tests(&self, toolchain: Toolchain, solx: Option<PathBuf>) -> anyhow::Result<Vec<Test>> {
+    fn all_tests(
+        &self,
+        toolchain: Toolchain,
+        solx: Option<PathBuf>,
+        emit_block_maps: bool,
+    ) -> anyhow::Result<Vec<Test>> {
         let solx_path = solx.unwrap_or_else(|| PathBuf::from("solx"));
-        let solidity_compiler = Arc::new(SolxCompiler::try_from_path(solx_path)?);
+        let mut solidity_compiler = SolxCompiler::try_from_path(solx_path)?;
+        solidity_compiler.emit_block_maps = emit_block_maps;
+        let solidity_compiler = Arc::new(solidity_compiler);
         let llvm_ir_compiler = Arc::new(LLVMIRCompiler::Solx(solidity_compiler.clone()));
 
         let (solidity_compiler, yul_compiler, llvm_ir_compiler): (
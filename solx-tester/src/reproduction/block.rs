@@ -0,0 +1,73 @@
+//!
+//! The reproduction block environment.
+//!
+
+use std::str::FromStr;
+
+///
+/// The reproduction block environment.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Block {
+    /// The block number.
+    pub number: String,
+    /// The block timestamp.
+    pub timestamp: String,
+    /// The block coinbase.
+    pub coinbase: String,
+    /// The block base fee.
+    pub basefee: u64,
+    /// The block difficulty.
+    pub difficulty: String,
+    /// The block prevrandao.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prevrandao: Option<String>,
+    /// The block gas limit.
+    pub gas_limit: u64,
+    /// The chain ID.
+    pub chain_id: u64,
+}
+
+impl Block {
+    ///
+    /// Captures the block environment.
+    ///
+    pub fn new(block: &revm::context::BlockEnv, chain_id: u64) -> Self {
+        Self {
+            number: format!("{:#x}", block.number),
+            timestamp: format!("{:#x}", block.timestamp),
+            coinbase: block.beneficiary.to_string(),
+            basefee: block.basefee,
+            difficulty: format!("{:#x}", block.difficulty),
+            prevrandao: block.prevrandao.map(|prevrandao| prevrandao.to_string()),
+            gas_limit: block.gas_limit,
+            chain_id,
+        }
+    }
+
+    ///
+    /// Applies the block environment on top of the REVM defaults.
+    ///
+    pub fn apply(self, vm: &mut crate::revm::REVM) -> anyhow::Result<()> {
+        let mut block = vm.block().to_owned();
+        block.number = revm::primitives::U256::from_str(self.number.as_str())
+            .map_err(|error| anyhow::anyhow!("Invalid block number: {error}"))?;
+        block.timestamp = revm::primitives::U256::from_str(self.timestamp.as_str())
+            .map_err(|error| anyhow::anyhow!("Invalid block timestamp: {error}"))?;
+        block.beneficiary = revm::primitives::Address::from_str(self.coinbase.as_str())
+            .map_err(|error| anyhow::anyhow!("Invalid block coinbase: {error}"))?;
+        block.basefee = self.basefee;
+        block.difficulty = revm::primitives::U256::from_str(self.difficulty.as_str())
+            .map_err(|error| anyhow::anyhow!("Invalid block difficulty: {error}"))?;
+        block.prevrandao = match self.prevrandao {
+            Some(prevrandao) => Some(
+                revm::primitives::B256::from_str(prevrandao.as_str())
+                    .map_err(|error| anyhow::anyhow!("Invalid block prevrandao: {error}"))?,
+            ),
+            None => None,
+        };
+        block.gas_limit = self.gas_limit;
+        vm.set_block(block, self.chain_id);
+        Ok(())
+    }
+}
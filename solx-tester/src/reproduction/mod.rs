@@ -0,0 +1,154 @@
+//!
+//! The self-contained reproduction of a failed test input.
+//!
+
+pub mod block;
+pub mod recording;
+pub mod step;
+pub mod transaction;
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use colored::Colorize;
+use revm::context::result::ExecutionResult;
+
+use crate::revm::revm_type_conversions::revm_bytes_to_vec_value;
+use crate::revm::REVM;
+use crate::test::case::input::output::Output;
+
+use self::step::Step;
+
+///
+/// The self-contained reproduction of a failed test input.
+///
+/// Contains the transactions of the test case up to the failed one, so the latter can be
+/// re-executed without the test corpus and compilers.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Reproduction {
+    /// The test selector.
+    pub test: String,
+    /// The compiler mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    /// The transactions of the test case, the last of which has failed.
+    pub steps: Vec<Step>,
+    /// The expected output.
+    pub expected: Output,
+    /// The output found during the test run.
+    pub found: Output,
+}
+
+impl Reproduction {
+    ///
+    /// Writes the reproduction to the specified directory.
+    ///
+    pub fn write(&self, directory: &Path) -> anyhow::Result<PathBuf> {
+        let mut file_name = self.test.clone();
+        if let Some(mode) = self.mode.as_ref() {
+            file_name.push('_');
+            file_name.push_str(mode.as_str());
+        }
+        let file_name = crate::utils::str_to_file_name(file_name.as_str());
+
+        std::fs::create_dir_all(directory)?;
+        let mut path = directory.to_path_buf();
+        path.push(format!("{file_name}.{}", solx_utils::EXTENSION_JSON));
+
+        let json = serde_json::to_vec_pretty(self).expect("Always valid");
+        std::fs::write(path.as_path(), json)
+            .map_err(|error| anyhow::anyhow!("Reproduction {path:?} writing: {error}"))?;
+        Ok(path)
+    }
+
+    ///
+    /// Reads the reproduction from the file system.
+    ///
+    pub fn read(path: &Path) -> anyhow::Result<Self> {
+        let json = std::fs::read(path)
+            .map_err(|error| anyhow::anyhow!("Reproduction {path:?} reading: {error}"))?;
+        serde_json::from_slice(json.as_slice())
+            .map_err(|error| anyhow::anyhow!("Reproduction {path:?} parsing: {error}"))
+    }
+
+    ///
+    /// Re-executes the recorded transactions on a fresh REVM instance.
+    ///
+    /// Returns whether the output of the last one matches the expected one.
+    ///
+    pub fn replay(self, enable_trace: bool) -> anyhow::Result<bool> {
+        let mut vm = REVM::new(enable_trace);
+
+        let mut last = None;
+        let count = self.steps.len();
+        for (index, step) in self.steps.into_iter().enumerate() {
+            let is_deploy = step.transaction.is_deploy();
+            let tx = step
+                .apply(&mut vm)
+                .map_err(|error| anyhow::anyhow!("Step {index} is invalid: {error}"))?;
+            let result = vm
+                .execute_transaction(tx)
+                .map_err(|error| anyhow::anyhow!("Step {index} execution: {error:?}"))?;
+            if index + 1 == count {
+                last = Some((is_deploy, result));
+            }
+        }
+        let (is_deploy, result) =
+            last.ok_or_else(|| anyhow::anyhow!("The reproduction has no transactions"))?;
+
+        let (output, gas_used, halt_reason) = match result {
+            ExecutionResult::Success {
+                reason: _,
+                gas_used,
+                gas_refunded: _,
+                logs,
+                output,
+            } => ((output, logs).into(), gas_used, None),
+            ExecutionResult::Revert { gas_used, output } => {
+                let return_data_value = revm_bytes_to_vec_value(output);
+                (Output::new(return_data_value, true, vec![]), gas_used, None)
+            }
+            ExecutionResult::Halt { reason, gas_used } => {
+                (Output::new(vec![], true, vec![]), gas_used, Some(reason))
+            }
+        };
+
+        let is_successful = output == self.expected;
+        println!(
+            "{:>7} {}{} ({} transaction, gas {gas_used})",
+            if is_successful {
+                "PASSED".green()
+            } else {
+                "FAILED".bright_red()
+            },
+            self.test,
+            self.mode.map(|mode| format!(" {mode}")).unwrap_or_default(),
+            if is_deploy { "deploy" } else { "runtime" },
+        );
+        if !is_successful {
+            println!(
+                "expected {}\nfound {}",
+                ron::ser::to_string_pretty(&self.expected, ron::ser::PrettyConfig::default())
+                    .expect("Always valid"),
+                ron::ser::to_string_pretty(&output, ron::ser::PrettyConfig::default())
+                    .expect("Always valid"),
+            );
+        }
+        if let Some(reason) = halt_reason {
+            println!("halted with {reason:?}");
+        }
+
+        Ok(is_successful)
+    }
+}
+
+///
+/// Decodes a `0x`-prefixed hexadecimal string.
+///
+pub fn decode_hex(string: &str) -> anyhow::Result<Vec<u8>> {
+    let string = string
+        .strip_prefix("0x")
+        .ok_or_else(|| anyhow::anyhow!("Expected a `0x`-prefixed hexadecimal string"))?;
+    hex::decode(string).map_err(|error| anyhow::anyhow!("Invalid hexadecimal string: {error}"))
+}
@@ -0,0 +1,54 @@
+//!
+//! The recording of a test case.
+//!
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::reproduction::step::Step;
+use crate::reproduction::Reproduction;
+use crate::test::case::input::output::Output;
+use crate::test::description::TestDescription;
+
+///
+/// The transactions of a test case executed so far.
+///
+/// Only the transactions and the state set by the tester between them are recorded, so the
+/// passing inputs do not pay for capturing the whole REVM state. The reproduction is only
+/// written if an input fails.
+///
+#[derive(Debug, Default)]
+pub struct Recording {
+    /// The recorded steps.
+    steps: Vec<Step>,
+}
+
+impl Recording {
+    ///
+    /// Records the step which is about to be executed.
+    ///
+    pub fn push(&mut self, step: Step) {
+        self.steps.push(step);
+    }
+
+    ///
+    /// Writes the reproduction of the failed test, which is the last recorded step, to the
+    /// specified directory.
+    ///
+    pub fn write(
+        &self,
+        directory: &Path,
+        test: &TestDescription,
+        expected: &Output,
+        found: &Output,
+    ) -> anyhow::Result<PathBuf> {
+        let reproduction = Reproduction {
+            test: test.selector.to_string(),
+            mode: test.mode.as_ref().map(|mode| mode.to_string()),
+            steps: self.steps.clone(),
+            expected: expected.to_owned(),
+            found: found.to_owned(),
+        };
+        reproduction.write(directory)
+    }
+}
@@ -0,0 +1,154 @@
+//!
+//! The reproduction step.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::reproduction::block::Block;
+use crate::reproduction::transaction::Transaction;
+use crate::revm::revm_type_conversions::web3_address_to_revm_address;
+use crate::revm::REVM;
+
+///
+/// The reproduction step, which is a transaction of the test case along with the account
+/// balances, storage, and origin set by the tester right before it.
+///
+/// All values are stored as hexadecimal strings to keep the file readable.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Step {
+    /// The account balances set before the transaction.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub balances: BTreeMap<String, String>,
+    /// The account storage values set before the transaction.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub storage: BTreeMap<String, BTreeMap<String, String>>,
+    /// The block environment.
+    pub block: Block,
+    /// The transaction origin, if it differs from the transaction caller.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<String>,
+    /// The transaction.
+    pub transaction: Transaction,
+}
+
+impl Step {
+    ///
+    /// Records the transaction which is about to be executed, and the state set before it.
+    ///
+    pub fn new(
+        vm: &REVM,
+        tx: &revm::context::TxEnv,
+        balances: &[(web3::types::Address, web3::types::U256)],
+        storage: &[(
+            web3::types::Address,
+            &HashMap<web3::types::U256, web3::types::U256>,
+        )],
+    ) -> Self {
+        let balances = balances
+            .iter()
+            .map(|(address, balance)| {
+                (
+                    Self::address_to_string(address),
+                    Self::u256_to_string(balance),
+                )
+            })
+            .collect();
+        let storage = storage
+            .iter()
+            .map(|(address, storage)| {
+                let storage = storage
+                    .iter()
+                    .map(|(key, value)| (Self::u256_to_string(key), Self::u256_to_string(value)))
+                    .collect();
+                (Self::address_to_string(address), storage)
+            })
+            .collect();
+
+        Self {
+            balances,
+            storage,
+            block: Block::new(vm.block(), vm.chain_id()),
+            origin: vm
+                .origin()
+                .map(|origin| format!("0x{}", hex::encode(origin.as_slice()))),
+            transaction: Transaction::new(tx),
+        }
+    }
+
+    ///
+    /// Sets the recorded state and block environment, and returns the transaction to execute.
+    ///
+    pub fn apply(self, vm: &mut REVM) -> anyhow::Result<revm::context::TxEnv> {
+        for (address, balance) in self.balances.into_iter() {
+            let address = Self::address_from_str(address.as_str())?;
+            let balance = Self::u256_from_str(balance.as_str())
+                .map_err(|error| anyhow::anyhow!("Invalid balance: {error}"))?;
+            vm.set_account(&address, balance);
+        }
+        for (address, values) in self.storage.into_iter() {
+            let address = Self::address_from_str(address.as_str())?;
+            let mut storage = HashMap::with_capacity(values.len());
+            for (key, value) in values.into_iter() {
+                let key = Self::u256_from_str(key.as_str())
+                    .map_err(|error| anyhow::anyhow!("Invalid storage key: {error}"))?;
+                let value = Self::u256_from_str(value.as_str())
+                    .map_err(|error| anyhow::anyhow!("Invalid storage value: {error}"))?;
+                storage.insert(key, value);
+            }
+            vm.extend_account_storage(&address, storage);
+        }
+        self.block
+            .apply(vm)
+            .map_err(|error| anyhow::anyhow!("Block is invalid: {error}"))?;
+        let origin = self
+            .origin
+            .map(|origin| Self::address_from_str(origin.as_str()))
+            .transpose()?;
+        vm.set_origin(origin.as_ref().map(web3_address_to_revm_address));
+        self.transaction
+            .try_into_revm()
+            .map_err(|error| anyhow::anyhow!("Transaction is invalid: {error}"))
+    }
+
+    ///
+    /// Formats the address as a `0x`-prefixed hexadecimal string.
+    ///
+    fn address_to_string(address: &web3::types::Address) -> String {
+        format!("0x{}", crate::utils::address_as_string(address))
+    }
+
+    ///
+    /// Formats the value as a `0x`-prefixed hexadecimal string.
+    ///
+    fn u256_to_string(value: &web3::types::U256) -> String {
+        format!("0x{}", crate::utils::u256_as_string(value))
+    }
+
+    ///
+    /// Parses a `0x`-prefixed hexadecimal address.
+    ///
+    fn address_from_str(string: &str) -> anyhow::Result<web3::types::Address> {
+        let bytes = crate::reproduction::decode_hex(string)
+            .map_err(|error| anyhow::anyhow!("Invalid address `{string}`: {error}"))?;
+        if bytes.len() != solx_utils::BYTE_LENGTH_ETH_ADDRESS {
+            anyhow::bail!(
+                "Invalid address `{string}`: expected {} bytes",
+                solx_utils::BYTE_LENGTH_ETH_ADDRESS
+            );
+        }
+        Ok(web3::types::Address::from_slice(bytes.as_slice()))
+    }
+
+    ///
+    /// Parses a `0x`-prefixed hexadecimal value.
+    ///
+    fn u256_from_str(string: &str) -> anyhow::Result<web3::types::U256> {
+        let string = string
+            .strip_prefix("0x")
+            .ok_or_else(|| anyhow::anyhow!("Expected a `0x`-prefixed hexadecimal string"))?;
+        web3::types::U256::from_str(string).map_err(|error| anyhow::anyhow!("`{string}`: {error}"))
+    }
+}
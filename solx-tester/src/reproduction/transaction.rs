@@ -0,0 +1,83 @@
+//!
+//! The reproduction transaction.
+//!
+
+use std::str::FromStr;
+
+///
+/// The reproduction transaction.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Transaction {
+    /// The transaction caller.
+    pub caller: String,
+    /// The callee address. `None` means a deploy transaction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    /// The calldata, or the deploy code followed by constructor arguments.
+    pub data: String,
+    /// The value in wei.
+    pub value: String,
+    /// The gas limit.
+    pub gas_limit: u64,
+    /// The gas price.
+    pub gas_price: u128,
+    /// The chain ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain_id: Option<u64>,
+}
+
+impl Transaction {
+    ///
+    /// Captures the transaction.
+    ///
+    pub fn new(tx: &revm::context::TxEnv) -> Self {
+        let to = match tx.kind {
+            revm::primitives::TxKind::Create => None,
+            revm::primitives::TxKind::Call(address) => Some(address.to_string()),
+        };
+
+        Self {
+            caller: tx.caller.to_string(),
+            to,
+            data: format!("0x{}", hex::encode(tx.data.as_ref())),
+            value: format!("{:#x}", tx.value),
+            gas_limit: tx.gas_limit,
+            gas_price: tx.gas_price,
+            chain_id: tx.chain_id,
+        }
+    }
+
+    ///
+    /// Whether the transaction is a deploy.
+    ///
+    pub fn is_deploy(&self) -> bool {
+        self.to.is_none()
+    }
+
+    ///
+    /// Converts the transaction into the REVM one.
+    ///
+    pub fn try_into_revm(self) -> anyhow::Result<revm::context::TxEnv> {
+        let caller = revm::primitives::Address::from_str(self.caller.as_str())
+            .map_err(|error| anyhow::anyhow!("Invalid caller `{}`: {error}", self.caller))?;
+        let data = crate::reproduction::decode_hex(self.data.as_str())
+            .map_err(|error| anyhow::anyhow!("Invalid data: {error}"))?;
+        let value = revm::primitives::U256::from_str(self.value.as_str())
+            .map_err(|error| anyhow::anyhow!("Invalid value `{}`: {error}", self.value))?;
+
+        let builder = revm::context::TxEnv::builder()
+            .caller(caller)
+            .data(revm::primitives::Bytes::from(data))
+            .value(value)
+            .gas_price(self.gas_price)
+            .gas_limit(self.gas_limit)
+            .chain_id(self.chain_id);
+        let builder = match self.to {
+            Some(to) => builder.to(revm::primitives::Address::from_str(to.as_str())
+                .map_err(|error| anyhow::anyhow!("Invalid callee `{to}`: {error}"))?),
+            None => builder.create(),
+        };
+        Ok(builder.build_fill())
+    }
+}
@@ -5,6 +5,7 @@
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 
+use crate::revm::profiler::symbols::Symbols;
 use crate::test::instance::Instance;
 
 ///
@@ -18,6 +19,8 @@ pub struct Input {
     pub method_identifiers: Option<BTreeMap<String, BTreeMap<String, u32>>>,
     /// The last contract name.
     pub last_contract: String,
+    /// The contract symbols for gas profiling.
+    pub symbols: Vec<Symbols>,
 }
 
 impl Input {
@@ -28,11 +31,13 @@ impl Input {
         builds: HashMap<String, (Vec<u8>, usize)>,
         method_identifiers: Option<BTreeMap<String, BTreeMap<String, u32>>>,
         last_contract: String,
+        symbols: Vec<Symbols>,
     ) -> Self {
         Self {
             builds,
             method_identifiers,
             last_contract,
+            symbols,
         }
     }
 
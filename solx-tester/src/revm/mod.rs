@@ -3,12 +3,15 @@
 ///
 pub mod address_iterator;
 pub mod input;
+pub mod origin;
+pub mod profiler;
 pub mod revm_type_conversions;
 
 use std::collections::HashMap;
 use std::convert::Infallible;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::sync::Arc;
 use std::time::Instant;
 
 use colored::Colorize;
@@ -26,7 +29,11 @@ use revm::{
 
 use crate::revm::revm_type_conversions::web3_u256_to_revm_u256;
 use crate::test::case::input::calldata::Calldata;
+use crate::test::case::input::environment::Environment;
 
+use self::profiler::profile::Profile;
+use self::profiler::symbols::Symbols;
+use self::profiler::GasProfiler;
 use self::revm_type_conversions::web3_address_to_revm_address;
 
 /// The overloaded REVM Context type.
@@ -36,7 +43,7 @@ type Context = revm::context::Context<
     revm::context::CfgEnv,
     revm::database::State<revm::database::EmptyDB>,
     revm::context::Journal<revm::database::State<revm::database::EmptyDB>>,
-    (),
+    self::origin::Chain,
     revm::context::LocalContext,
 >;
 
@@ -57,6 +64,16 @@ pub enum REVM {
             EthFrame,
         >,
     ),
+    /// REVM with the gas profiler.
+    Profiling(
+        Evm<
+            Context,
+            GasProfiler,
+            EthInstructions<EthInterpreter, Context>,
+            EthPrecompiles,
+            EthFrame,
+        >,
+    ),
 }
 
 impl REVM {
@@ -105,23 +122,61 @@ impl REVM {
             REVM::Tracing(Evm::new_with_inspector(
                 Self::context(),
                 TracerEip3155::new_stdout(),
-                EthInstructions::new_mainnet(),
+                Self::instructions(),
                 EthPrecompiles::default(),
             ))
         } else {
             REVM::Default(Evm::new(
                 Self::context(),
-                EthInstructions::new_mainnet(),
+                Self::instructions(),
                 EthPrecompiles::default(),
             ))
         };
         match evm {
             REVM::Default(ref mut evm) => Self::set_data(evm),
             REVM::Tracing(ref mut evm) => Self::set_data(evm),
+            REVM::Profiling(ref mut evm) => Self::set_data(evm),
         }
         evm
     }
 
+    ///
+    /// A shortcut constructor of REVM with the gas profiler.
+    ///
+    pub fn new_profiling(symbols: Arc<Vec<Symbols>>) -> Self {
+        let mut evm = Evm::new_with_inspector(
+            Self::context(),
+            GasProfiler::new(symbols),
+            Self::instructions(),
+            EthPrecompiles::default(),
+        );
+        Self::set_data(&mut evm);
+        REVM::Profiling(evm)
+    }
+
+    ///
+    /// Sets the gas profile root frame name for the following transactions.
+    ///
+    /// Does nothing if the gas profiler is disabled.
+    ///
+    pub fn set_profile_root(&mut self, root: String) {
+        if let REVM::Profiling(evm) = self {
+            evm.inspector.set_root(root);
+        }
+    }
+
+    ///
+    /// Takes the collected gas profile.
+    ///
+    /// Returns `None` if the gas profiler is disabled.
+    ///
+    pub fn take_profile(&mut self) -> Option<Profile> {
+        match self {
+            REVM::Profiling(evm) => Some(evm.inspector.take_profile()),
+            _ => None,
+        }
+    }
+
     ///
     /// Executes the transaction.
     ///
@@ -133,6 +188,7 @@ impl REVM {
         match self {
             REVM::Default(vm) => vm.transact_commit(tx),
             REVM::Tracing(vm) => vm.inspect_tx_commit(tx),
+            REVM::Profiling(vm) => vm.inspect_tx_commit(tx),
         }
     }
 
@@ -165,14 +221,16 @@ impl REVM {
         caller: web3::types::Address,
         value: Option<u128>,
         code: Vec<u8>,
+        environment: &Environment,
     ) -> revm::context::TxEnv {
         revm::context::TxEnv::builder()
             .caller(web3_address_to_revm_address(&caller))
             .data(revm::primitives::Bytes::from(code))
             .value(revm::primitives::U256::from(value.unwrap_or_default()))
             .create()
-            .gas_price(Self::GAS_PRICE as u128)
+            .gas_price(Self::gas_price(environment))
             .gas_limit(Self::BLOCK_GAS_LIMIT)
+            .chain_id(Some(environment.chain_id.unwrap_or(Self::CHAIND_ID)))
             .build_fill()
     }
 
@@ -184,17 +242,34 @@ impl REVM {
         caller: web3::types::Address,
         calldata: Calldata,
         value: Option<u128>,
+        environment: &Environment,
     ) -> revm::context::TxEnv {
         revm::context::TxEnv::builder()
             .caller(web3_address_to_revm_address(&caller))
             .data(revm::primitives::Bytes::from(calldata.inner))
             .value(revm::primitives::U256::from(value.unwrap_or_default()))
             .to(web3_address_to_revm_address(&address))
-            .gas_price(Self::GAS_PRICE as u128)
+            .gas_price(Self::gas_price(environment))
             .gas_limit(Self::BLOCK_GAS_LIMIT)
+            .chain_id(Some(environment.chain_id.unwrap_or(Self::CHAIND_ID)))
             .build_fill()
     }
 
+    ///
+    /// Returns the transaction gas price.
+    ///
+    pub fn gas_price(environment: &Environment) -> u128 {
+        environment.gas_price.unwrap_or(Self::GAS_PRICE as u128)
+    }
+
+    ///
+    /// Returns the balance the caller needs to pay for the transaction gas.
+    ///
+    pub fn gas_fee_reserve(environment: &Environment) -> web3::types::U256 {
+        web3::types::U256::from(Self::gas_price(environment))
+            * web3::types::U256::from(Self::BLOCK_GAS_LIMIT)
+    }
+
     ///
     /// Sets the account data and balance.
     ///
@@ -276,11 +351,115 @@ impl REVM {
         let block = match self {
             REVM::Default(evm) => &mut evm.block,
             REVM::Tracing(evm) => &mut evm.block,
+            REVM::Profiling(evm) => &mut evm.block,
         };
         block.number = number;
         block.timestamp = timestamp;
     }
 
+    ///
+    /// Sets the block environment of the input with the specified index.
+    ///
+    /// The fields not overridden by `environment` are reset to the defaults, and the block
+    /// number and timestamp are derived from the input index.
+    ///
+    pub fn set_environment(&mut self, environment: &Environment, input_index: usize) {
+        let number = environment
+            .block_number
+            .map(web3_u256_to_revm_u256)
+            .unwrap_or_else(|| revm::primitives::U256::from(input_index + 1));
+        let timestamp = environment
+            .timestamp
+            .map(web3_u256_to_revm_u256)
+            .unwrap_or_else(|| {
+                revm::primitives::U256::from(
+                    ((input_index + 1) as u128) * Self::BLOCK_TIMESTAMP_STEP,
+                )
+            });
+        self.set_block_data(number, timestamp);
+
+        let prevrandao = environment
+            .prevrandao
+            .map(web3_u256_to_revm_u256)
+            .unwrap_or_else(|| {
+                revm::primitives::U256::from_str(Self::BLOCK_PREVRANDAO).expect("Always valid")
+            });
+        let (block, cfg) = match self {
+            REVM::Default(evm) => (&mut evm.ctx.block, &mut evm.ctx.cfg),
+            REVM::Tracing(evm) => (&mut evm.ctx.block, &mut evm.ctx.cfg),
+            REVM::Profiling(evm) => (&mut evm.ctx.block, &mut evm.ctx.cfg),
+        };
+        block.basefee = environment.basefee.unwrap_or(Self::BASE_FEE);
+        block.difficulty = prevrandao;
+        block.prevrandao = Some(revm::primitives::B256::from(prevrandao.to_be_bytes::<32>()));
+        cfg.chain_id = environment.chain_id.unwrap_or(Self::CHAIND_ID);
+
+        self.set_origin(
+            environment
+                .origin
+                .as_ref()
+                .map(web3_address_to_revm_address),
+        );
+    }
+
+    ///
+    /// Returns the block environment.
+    ///
+    pub fn block(&self) -> &revm::context::BlockEnv {
+        match self {
+            REVM::Default(evm) => &evm.ctx.block,
+            REVM::Tracing(evm) => &evm.ctx.block,
+            REVM::Profiling(evm) => &evm.ctx.block,
+        }
+    }
+
+    ///
+    /// Returns the chain ID.
+    ///
+    pub fn chain_id(&self) -> u64 {
+        match self {
+            REVM::Default(evm) => evm.ctx.cfg.chain_id,
+            REVM::Tracing(evm) => evm.ctx.cfg.chain_id,
+            REVM::Profiling(evm) => evm.ctx.cfg.chain_id,
+        }
+    }
+
+    ///
+    /// Returns the transaction origin override.
+    ///
+    pub fn origin(&self) -> Option<revm::primitives::Address> {
+        match self {
+            REVM::Default(evm) => evm.ctx.chain.origin,
+            REVM::Tracing(evm) => evm.ctx.chain.origin,
+            REVM::Profiling(evm) => evm.ctx.chain.origin,
+        }
+    }
+
+    ///
+    /// Overrides the transaction origin independently of the transaction caller.
+    ///
+    pub fn set_origin(&mut self, origin: Option<revm::primitives::Address>) {
+        let chain = match self {
+            REVM::Default(evm) => &mut evm.ctx.chain,
+            REVM::Tracing(evm) => &mut evm.ctx.chain,
+            REVM::Profiling(evm) => &mut evm.ctx.chain,
+        };
+        chain.origin = origin;
+    }
+
+    ///
+    /// Overwrites the block environment and the chain ID.
+    ///
+    pub fn set_block(&mut self, block: revm::context::BlockEnv, chain_id: u64) {
+        let (block_env, cfg) = match self {
+            REVM::Default(evm) => (&mut evm.ctx.block, &mut evm.ctx.cfg),
+            REVM::Tracing(evm) => (&mut evm.ctx.block, &mut evm.ctx.cfg),
+            REVM::Profiling(evm) => (&mut evm.ctx.block, &mut evm.ctx.cfg),
+        };
+        *block_env = block;
+        cfg.chain_id = chain_id;
+    }
+
     ///
     /// Returns the calldata gas cost.
     ///
@@ -332,6 +511,7 @@ impl REVM {
         match self {
             REVM::Default(evm) => evm.db(),
             REVM::Tracing(evm) => evm.db(),
+            REVM::Profiling(evm) => evm.db(),
         }
     }
 
@@ -342,6 +522,7 @@ impl REVM {
         match self {
             REVM::Default(evm) => evm.db_mut(),
             REVM::Tracing(evm) => evm.db_mut(),
+            REVM::Profiling(evm) => evm.db_mut(),
         }
     }
 
@@ -352,9 +533,19 @@ impl REVM {
         match self {
             REVM::Default(evm) => evm.ctx.db(),
             REVM::Tracing(evm) => evm.ctx.db(),
+            REVM::Profiling(evm) => evm.ctx.db(),
         }
     }
 
+    ///
+    /// Builds the mainnet instruction table with the `ORIGIN` override.
+    ///
+    fn instructions() -> EthInstructions<EthInterpreter, Context> {
+        let mut instructions = EthInstructions::new_mainnet();
+        instructions.insert_instruction(origin::OPCODE, origin::instruction());
+        instructions
+    }
+
     ///
     /// Builds the default context for REVM.
     ///
@@ -431,6 +622,7 @@ impl REVM {
         evm.block.gas_limit = Self::BLOCK_GAS_LIMIT;
         evm.block.timestamp = revm::primitives::U256::from(Self::BLOCK_TIMESTAMP);
         evm.tx.chain_id = Some(Self::CHAIND_ID);
+        evm.cfg.chain_id = Self::CHAIND_ID;
         evm.cfg.disable_nonce_check = true;
     }
 }
@@ -0,0 +1,53 @@
+//!
+//! The `ORIGIN` instruction override.
+//!
+
+use revm::interpreter::instructions::Instruction;
+use revm::interpreter::interpreter::EthInterpreter;
+use revm::interpreter::Host;
+use revm::interpreter::InstructionContext;
+use revm::interpreter::InstructionResult;
+
+use crate::revm::Context;
+
+/// The `ORIGIN` opcode.
+pub const OPCODE: u8 = 0x32;
+/// The `ORIGIN` static gas cost.
+pub const GAS_COST: u64 = 2;
+
+///
+/// The tester-specific chain context of REVM.
+///
+/// It is owned by the EVM context, so each REVM instance keeps its own overrides.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Chain {
+    /// The transaction origin override.
+    pub origin: Option<revm::primitives::Address>,
+}
+
+///
+/// Returns the `ORIGIN` instruction, which pushes the origin override of the context if it
+/// is set, and the transaction caller otherwise.
+///
+/// REVM derives `tx.origin` from the transaction caller, so the override is the only way
+/// to make it differ from the `msg.sender` of the outermost call.
+///
+pub fn instruction() -> Instruction<EthInterpreter, Context> {
+    Instruction::new(origin, GAS_COST)
+}
+
+///
+/// The `ORIGIN` instruction implementation.
+///
+fn origin(context: InstructionContext<'_, Context, EthInterpreter>) {
+    let origin = context
+        .host
+        .chain
+        .origin
+        .unwrap_or_else(|| context.host.caller());
+    let value = revm::primitives::U256::from_be_bytes(origin.into_word().0);
+    if !context.interpreter.stack.push(value) {
+        context.interpreter.halt(InstructionResult::StackOverflow);
+    }
+}
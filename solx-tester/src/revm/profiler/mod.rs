@@ -0,0 +1,254 @@
+//!
+//! The REVM gas profiler.
+//!
+
+pub mod profile;
+pub mod symbols;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use revm::interpreter::interpreter::EthInterpreter;
+use revm::interpreter::interpreter_types::Jumps;
+use revm::interpreter::CallInputs;
+use revm::interpreter::CallOutcome;
+use revm::interpreter::CreateInputs;
+use revm::interpreter::CreateOutcome;
+use revm::interpreter::Interpreter;
+
+use self::profile::Profile;
+use self::symbols::Symbols;
+
+///
+/// The REVM gas profiler.
+///
+/// Measures the gas spent by each executed instruction and aggregates it by opcode, by program
+/// counter, and by call stack. Call stack frames are named after the contract and the LLVM
+/// function the program counter belongs to, if the contract symbols are known.
+///
+#[derive(Debug)]
+pub struct GasProfiler {
+    /// The symbols of all contracts compiled for the test.
+    symbols: Arc<Vec<Symbols>>,
+    /// The deployed runtime code hashes mapped to the symbols indexes.
+    runtime_symbols: HashMap<revm::primitives::B256, usize>,
+    /// The call frames of the transaction being executed.
+    frames: Vec<Frame>,
+    /// The root frame name, identifying the test input.
+    root: String,
+    /// The remaining gas, program counter, and opcode before the current instruction.
+    current: (u64, usize, u8),
+    /// The profile collected so far.
+    profile: Profile,
+}
+
+///
+/// The profiler call frame.
+///
+#[derive(Debug, Default)]
+struct Frame {
+    /// The symbols index, if the executed code is known.
+    symbols: Option<usize>,
+    /// Whether the frame has been executed by the interpreter, that is, it is not a precompile.
+    has_interpreter: bool,
+    /// The function executed most recently.
+    function: String,
+    /// The instruction executed most recently.
+    last: Option<Step>,
+}
+
+///
+/// The recorded instruction.
+///
+#[derive(Debug)]
+struct Step {
+    /// The opcode.
+    opcode: u8,
+    /// The contract frame name.
+    contract: String,
+    /// The program counter.
+    pc: usize,
+    /// The folded call stack.
+    stack: String,
+}
+
+impl GasProfiler {
+    /// The name of code without known symbols.
+    pub const UNKNOWN_CONTRACT: &'static str = "<unknown>";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(symbols: Arc<Vec<Symbols>>) -> Self {
+        Self {
+            symbols,
+            runtime_symbols: HashMap::new(),
+            frames: Vec::new(),
+            root: String::new(),
+            current: (0, 0, 0),
+            profile: Profile::default(),
+        }
+    }
+
+    ///
+    /// Sets the root frame name for the following transactions.
+    ///
+    pub fn set_root(&mut self, root: String) {
+        self.frames.clear();
+        self.root = root;
+    }
+
+    ///
+    /// Takes the collected profile, leaving an empty one in its place.
+    ///
+    pub fn take_profile(&mut self) -> Profile {
+        std::mem::take(&mut self.profile)
+    }
+
+    ///
+    /// Returns the contract frame name.
+    ///
+    fn contract_name(&self, symbols: Option<usize>) -> String {
+        match symbols {
+            Some(index) => self.symbols[index].name(),
+            None => Self::UNKNOWN_CONTRACT.to_owned(),
+        }
+    }
+
+    ///
+    /// Pops the finished frame, and excludes the gas forwarded to it from the calling instruction.
+    ///
+    /// As precompiles are not executed by the interpreter, their gas is attributed to the
+    /// calling instruction.
+    ///
+    fn frame_end(&mut self, gas: &revm::interpreter::Gas) -> Option<Frame> {
+        let frame = self.frames.pop()?;
+
+        let forwarded = gas.limit() as i64;
+        let delta = if frame.has_interpreter {
+            -forwarded
+        } else {
+            gas.spent() as i64 - forwarded
+        };
+        if let Some(step) = self.frames.last().and_then(|parent| parent.last.as_ref()) {
+            self.profile.adjust(
+                step.opcode,
+                step.contract.as_str(),
+                step.pc,
+                step.stack.as_str(),
+                delta,
+            );
+        }
+
+        Some(frame)
+    }
+}
+
+impl<CTX> revm::Inspector<CTX, EthInterpreter> for GasProfiler {
+    fn initialize_interp(&mut self, interp: &mut Interpreter<EthInterpreter>, _context: &mut CTX) {
+        if self.frames.is_empty() {
+            self.frames.push(Frame::default());
+        }
+
+        let code = interp.bytecode.original_byte_slice();
+        let symbols = self
+            .runtime_symbols
+            .get(&revm::primitives::keccak256(code))
+            .copied()
+            .or_else(|| {
+                self.symbols.iter().position(|symbols| {
+                    symbols.is_deploy
+                        && !symbols.bytecode.is_empty()
+                        && code.starts_with(symbols.bytecode.as_slice())
+                })
+            });
+
+        let frame = self.frames.last_mut().expect("Always exists");
+        frame.symbols = symbols;
+        frame.has_interpreter = true;
+    }
+
+    fn step(&mut self, interp: &mut Interpreter<EthInterpreter>, _context: &mut CTX) {
+        self.current = (
+            interp.gas.remaining(),
+            interp.bytecode.pc(),
+            interp.bytecode.opcode(),
+        );
+    }
+
+    fn step_end(&mut self, interp: &mut Interpreter<EthInterpreter>, _context: &mut CTX) {
+        let (gas_remaining, pc, opcode) = self.current;
+        let gas = gas_remaining.saturating_sub(interp.gas.remaining());
+
+        let Some(symbols) = self.frames.last().map(|frame| frame.symbols) else {
+            return;
+        };
+        let contract = self.contract_name(symbols);
+        let function = match symbols {
+            Some(index) => self.symbols[index].function(pc).to_owned(),
+            None => Symbols::UNKNOWN_FUNCTION.to_owned(),
+        };
+        self.frames.last_mut().expect("Always exists").function = function;
+
+        let mut stack = self.root.clone();
+        for frame in self.frames.iter() {
+            if !stack.is_empty() {
+                stack.push(';');
+            }
+            stack.push_str(self.contract_name(frame.symbols).as_str());
+            stack.push_str("::");
+            stack.push_str(frame.function.as_str());
+        }
+
+        self.profile
+            .record(opcode, contract.as_str(), pc, stack.as_str(), gas);
+        self.frames.last_mut().expect("Always exists").last = Some(Step {
+            opcode,
+            contract,
+            pc,
+            stack,
+        });
+    }
+
+    fn call(&mut self, _context: &mut CTX, _inputs: &mut CallInputs) -> Option<CallOutcome> {
+        self.frames.push(Frame::default());
+        None
+    }
+
+    fn call_end(&mut self, _context: &mut CTX, _inputs: &CallInputs, outcome: &mut CallOutcome) {
+        self.frame_end(&outcome.result.gas);
+    }
+
+    fn create(&mut self, _context: &mut CTX, _inputs: &mut CreateInputs) -> Option<CreateOutcome> {
+        self.frames.push(Frame::default());
+        None
+    }
+
+    fn create_end(
+        &mut self,
+        _context: &mut CTX,
+        _inputs: &CreateInputs,
+        outcome: &mut CreateOutcome,
+    ) {
+        let Some(frame) = self.frame_end(&outcome.result.gas) else {
+            return;
+        };
+        if !outcome.result.is_ok() {
+            return;
+        }
+
+        let Some(deploy_symbols) = frame.symbols.map(|index| &self.symbols[index]) else {
+            return;
+        };
+        if let Some(index) = self
+            .symbols
+            .iter()
+            .position(|symbols| !symbols.is_deploy && symbols.contract == deploy_symbols.contract)
+        {
+            self.runtime_symbols.insert(
+                revm::primitives::keccak256(outcome.result.output.as_ref()),
+                index,
+            );
+        }
+    }
+}
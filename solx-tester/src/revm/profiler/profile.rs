@@ -0,0 +1,192 @@
+//!
+//! The gas profile.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+///
+/// The gas profile.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Profile {
+    /// The gas aggregated by opcode.
+    pub opcodes: BTreeMap<u8, Sample>,
+    /// The gas aggregated by contract and program counter, along with the opcode.
+    pub pcs: BTreeMap<(String, usize), (u8, Sample)>,
+    /// The gas aggregated by folded call stacks.
+    pub stacks: BTreeMap<String, u64>,
+}
+
+///
+/// The aggregated gas sample.
+///
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Sample {
+    /// The number of executions.
+    pub count: u64,
+    /// The gas spent.
+    pub gas: u64,
+}
+
+impl Profile {
+    /// The folded stacks file extension.
+    pub const EXTENSION_FOLDED: &'static str = "folded";
+    /// The opcode table file extension.
+    pub const EXTENSION_OPCODES: &'static str = "opcodes.txt";
+    /// The program counter table file extension.
+    pub const EXTENSION_PCS: &'static str = "pcs.txt";
+
+    ///
+    /// Records an executed instruction.
+    ///
+    pub fn record(&mut self, opcode: u8, contract: &str, pc: usize, stack: &str, gas: u64) {
+        let sample = self.opcodes.entry(opcode).or_default();
+        sample.count += 1;
+        sample.gas += gas;
+
+        let (_opcode, sample) = self
+            .pcs
+            .entry((contract.to_owned(), pc))
+            .or_insert((opcode, Sample::default()));
+        sample.count += 1;
+        sample.gas += gas;
+
+        *self.stacks.entry(stack.to_owned()).or_default() += gas;
+    }
+
+    ///
+    /// Corrects the gas of an already recorded instruction, without changing the counts.
+    ///
+    /// Used for calls and creations, whose cost includes the gas forwarded to the callee.
+    ///
+    pub fn adjust(&mut self, opcode: u8, contract: &str, pc: usize, stack: &str, delta: i64) {
+        let apply = |gas: &mut u64| *gas = gas.saturating_add_signed(delta);
+
+        if let Some(sample) = self.opcodes.get_mut(&opcode) {
+            apply(&mut sample.gas);
+        }
+        if let Some((_opcode, sample)) = self.pcs.get_mut(&(contract.to_owned(), pc)) {
+            apply(&mut sample.gas);
+        }
+        if let Some(gas) = self.stacks.get_mut(stack) {
+            apply(gas);
+        }
+    }
+
+    ///
+    /// Merges another profile into this one.
+    ///
+    pub fn extend(&mut self, other: Self) {
+        for (opcode, sample) in other.opcodes.into_iter() {
+            self.opcodes.entry(opcode).or_default().add(sample);
+        }
+        for (key, (opcode, sample)) in other.pcs.into_iter() {
+            self.pcs
+                .entry(key)
+                .or_insert((opcode, Sample::default()))
+                .1
+                .add(sample);
+        }
+        for (stack, gas) in other.stacks.into_iter() {
+            *self.stacks.entry(stack).or_default() += gas;
+        }
+    }
+
+    ///
+    /// Whether nothing has been recorded.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.opcodes.is_empty()
+    }
+
+    ///
+    /// Writes the profile files named `<name>.<extension>` to the specified directory:
+    ///
+    /// 1. `folded`: the flamegraph-compatible folded stacks.
+    /// 2. `opcodes.txt`: the gas by opcode, sorted by gas in descending order.
+    /// 3. `pcs.txt`: the gas by contract and program counter, sorted by gas in descending order.
+    ///
+    pub fn write(&self, directory: &Path, name: &str) -> anyhow::Result<()> {
+        std::fs::create_dir_all(directory)?;
+        let name = crate::utils::str_to_file_name(name);
+
+        let mut folded = String::with_capacity(self.stacks.len() * 64);
+        for (stack, gas) in self.stacks.iter().filter(|(_stack, gas)| **gas > 0) {
+            folded.push_str(format!("{stack} {gas}\n").as_str());
+        }
+        Self::write_file(directory, name.as_str(), Self::EXTENSION_FOLDED, folded)?;
+
+        let mut opcodes: Vec<(&u8, &Sample)> = self.opcodes.iter().collect();
+        opcodes.sort_by_key(|(_opcode, sample)| std::cmp::Reverse(sample.gas));
+        let mut table = format!("{:<16} {:>12} {:>16}\n", "OPCODE", "COUNT", "GAS");
+        for (opcode, sample) in opcodes.into_iter() {
+            table.push_str(
+                format!(
+                    "{:<16} {:>12} {:>16}\n",
+                    Self::opcode_name(*opcode),
+                    sample.count,
+                    sample.gas
+                )
+                .as_str(),
+            );
+        }
+        Self::write_file(directory, name.as_str(), Self::EXTENSION_OPCODES, table)?;
+
+        let mut pcs: Vec<(&(String, usize), &(u8, Sample))> = self.pcs.iter().collect();
+        pcs.sort_by_key(|(_key, (_opcode, sample))| std::cmp::Reverse(sample.gas));
+        let mut table = format!(
+            "{:>8} {:<16} {:>12} {:>16} CONTRACT\n",
+            "PC", "OPCODE", "COUNT", "GAS"
+        );
+        for ((contract, pc), (opcode, sample)) in pcs.into_iter() {
+            table.push_str(
+                format!(
+                    "{pc:>8} {:<16} {:>12} {:>16} {contract}\n",
+                    Self::opcode_name(*opcode),
+                    sample.count,
+                    sample.gas
+                )
+                .as_str(),
+            );
+        }
+        Self::write_file(directory, name.as_str(), Self::EXTENSION_PCS, table)?;
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the opcode mnemonic, or its hexadecimal value if the opcode is unknown.
+    ///
+    fn opcode_name(opcode: u8) -> String {
+        match revm::bytecode::opcode::OpCode::new(opcode) {
+            Some(opcode) => opcode.as_str().to_owned(),
+            None => format!("0x{opcode:02x}"),
+        }
+    }
+
+    ///
+    /// Writes a single profile file.
+    ///
+    fn write_file(
+        directory: &Path,
+        name: &str,
+        extension: &str,
+        contents: String,
+    ) -> anyhow::Result<()> {
+        let mut path = directory.to_path_buf();
+        path.push(format!("{name}.{extension}"));
+        std::fs::write(path.as_path(), contents)
+            .map_err(|error| anyhow::anyhow!("Gas profile {path:?} writing: {error}"))
+    }
+}
+
+impl Sample {
+    ///
+    /// Adds another sample to this one.
+    ///
+    pub fn add(&mut self, other: Self) {
+        self.count += other.count;
+        self.gas += other.gas;
+    }
+}
@@ -0,0 +1,86 @@
+//!
+//! The bytecode symbols for gas profiling.
+//!
+
+///
+/// The bytecode symbols for gas profiling.
+///
+/// Maps program counters of a contract bytecode to the LLVM functions they belong to.
+///
+#[derive(Debug, Clone)]
+pub struct Symbols {
+    /// The full contract name, e.g. `tests/solidity/simple/default.sol:Test`.
+    pub contract: String,
+    /// Whether the symbols describe the deploy code.
+    pub is_deploy: bool,
+    /// The bytecode the symbols were extracted for.
+    pub bytecode: Vec<u8>,
+    /// The mapping of bytecode offsets to LLVM functions and basic blocks.
+    pub block_map: solx_utils::BlockMap,
+}
+
+impl Symbols {
+    /// The name of code not covered by any function label.
+    pub const UNKNOWN_FUNCTION: &'static str = "<unknown>";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        contract: String,
+        is_deploy: bool,
+        bytecode: Vec<u8>,
+        block_map: solx_utils::BlockMap,
+    ) -> Self {
+        Self {
+            contract,
+            is_deploy,
+            bytecode,
+            block_map,
+        }
+    }
+
+    ///
+    /// Returns the name of the function containing `pc`.
+    ///
+    pub fn function(&self, pc: usize) -> &str {
+        self.block_map
+            .entry(pc)
+            .map(|entry| entry.function.as_str())
+            .unwrap_or(Self::UNKNOWN_FUNCTION)
+    }
+
+    ///
+    /// Returns the frame name used in profiles.
+    ///
+    pub fn name(&self) -> String {
+        if self.is_deploy {
+            format!("{} (deploy)", self.contract)
+        } else {
+            self.contract.to_owned()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Symbols;
+
+    #[test]
+    fn function() {
+        let block_map = solx_utils::BlockMap {
+            entries: vec![
+                solx_utils::BlockMapEntry::new(2, "main".to_owned(), Some("entry".to_owned())),
+                solx_utils::BlockMapEntry::new(8, "main".to_owned(), Some("if_join".to_owned())),
+                solx_utils::BlockMapEntry::new(12, "fun_f".to_owned(), Some("entry".to_owned())),
+            ],
+        };
+        let symbols = Symbols::new("default.sol:Test".to_owned(), false, vec![], block_map);
+
+        assert_eq!(symbols.function(0), Symbols::UNKNOWN_FUNCTION);
+        assert_eq!(symbols.function(2), "main");
+        assert_eq!(symbols.function(11), "main");
+        assert_eq!(symbols.function(12), "fun_f");
+        assert_eq!(symbols.function(100), "fun_f");
+    }
+}
@@ -0,0 +1,78 @@
+//!
+//! `solx` tester gas snapshot entry.
+//!
+
+use std::str::FromStr;
+
+///
+/// `solx` tester gas snapshot entry.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    /// Amount of gas used.
+    pub gas: u64,
+    /// Deploy code size, if the entry is a deploy transaction.
+    pub deploy_size: Option<u64>,
+    /// Runtime code size, if the entry is a deploy transaction.
+    pub runtime_size: Option<u64>,
+}
+
+impl Entry {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(gas: u64, deploy_size: Option<u64>, runtime_size: Option<u64>) -> Self {
+        Self {
+            gas,
+            deploy_size,
+            runtime_size,
+        }
+    }
+}
+
+impl FromStr for Entry {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let inner = string
+            .strip_prefix('(')
+            .and_then(|string| string.strip_suffix(')'))
+            .ok_or_else(|| anyhow::anyhow!("Expected a parenthesized list of values"))?;
+
+        let mut gas = None;
+        let mut deploy_size = None;
+        let mut runtime_size = None;
+        for field in inner.split(", ") {
+            let (name, value) = field
+                .split_once(": ")
+                .ok_or_else(|| anyhow::anyhow!("Invalid field `{field}`"))?;
+            let value = u64::from_str(value)
+                .map_err(|error| anyhow::anyhow!("Invalid value of field `{name}`: {error}"))?;
+            match name {
+                "gas" => gas = Some(value),
+                "deploy size" => deploy_size = Some(value),
+                "runtime size" => runtime_size = Some(value),
+                name => anyhow::bail!("Unknown field `{name}`"),
+            }
+        }
+
+        Ok(Self {
+            gas: gas.ok_or_else(|| anyhow::anyhow!("Field `gas` is missing"))?,
+            deploy_size,
+            runtime_size,
+        })
+    }
+}
+
+impl std::fmt::Display for Entry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(gas: {}", self.gas)?;
+        if let Some(deploy_size) = self.deploy_size {
+            write!(f, ", deploy size: {deploy_size}")?;
+        }
+        if let Some(runtime_size) = self.runtime_size {
+            write!(f, ", runtime size: {runtime_size}")?;
+        }
+        write!(f, ")")
+    }
+}
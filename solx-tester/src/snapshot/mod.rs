@@ -0,0 +1,288 @@
+//!
+//! `solx` tester gas snapshot.
+//!
+
+pub mod entry;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use colored::Colorize;
+
+use crate::toolchain::Toolchain;
+
+use self::entry::Entry;
+
+///
+/// `solx` tester gas snapshot.
+///
+/// Maps `<test path>::<case>[<input>] <mode>` to the gas and code sizes measured for it,
+/// in a format similar to Foundry `.gas-snapshot` files:
+///
+/// ```text
+/// tests/solidity/simple/default.sol::first[main:1] Y +M3B3 (gas: 1234)
+/// ```
+///
+/// The compiler version is not a part of the key, so bumping the compiler version does not
+/// invalidate the whole snapshot. The upstream `solc` modes are the exception, as the `solc`
+/// toolchain runs each test with several `solc` versions:
+///
+/// ```text
+/// tests/solidity/simple/default.sol::first[main:1] Y + 0.8.30 (gas: 1234)
+/// ```
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    /// The snapshot entries.
+    pub entries: BTreeMap<String, Entry>,
+}
+
+///
+/// A difference between the recorded and measured snapshot entries.
+///
+#[derive(Debug)]
+pub enum Mismatch {
+    /// The measured entry differs from the recorded one.
+    Changed {
+        /// The entry key.
+        key: String,
+        /// The recorded entry.
+        expected: Entry,
+        /// The measured entry.
+        found: Entry,
+    },
+    /// The measured entry is not recorded.
+    Unrecorded {
+        /// The entry key.
+        key: String,
+        /// The measured entry.
+        found: Entry,
+    },
+    /// The recorded entry has not been measured.
+    Stale {
+        /// The entry key.
+        key: String,
+        /// The recorded entry.
+        expected: Entry,
+    },
+}
+
+impl Snapshot {
+    /// The snapshot files directory.
+    pub const DIRECTORY: &'static str = "tests/gas-snapshots";
+
+    /// The snapshot file extension.
+    pub const EXTENSION: &'static str = "gas-snapshot";
+
+    ///
+    /// Returns the snapshot file path for the specified toolchain.
+    ///
+    pub fn path(toolchain: Toolchain) -> PathBuf {
+        let mut path = crate::utils::str_to_path_normalized(Self::DIRECTORY);
+        path.push(format!("{toolchain}.{}", Self::EXTENSION));
+        path
+    }
+
+    ///
+    /// Reads the snapshot from the file system.
+    ///
+    pub fn read(path: &Path) -> anyhow::Result<Self> {
+        let string = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("Gas snapshot {path:?} reading: {error}"))?;
+        Self::from_str(string.as_str())
+            .map_err(|error| anyhow::anyhow!("Gas snapshot {path:?} parsing: {error}"))
+    }
+
+    ///
+    /// Writes the snapshot to the file system.
+    ///
+    pub fn write(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, self.to_string())
+            .map_err(|error| anyhow::anyhow!("Gas snapshot {path:?} writing: {error}"))
+    }
+
+    ///
+    /// Inserts an entry.
+    ///
+    pub fn insert(&mut self, key: String, entry: Entry) {
+        self.entries.insert(key, entry);
+    }
+
+    ///
+    /// Overwrites the entries with the ones from `other`, keeping the rest intact.
+    ///
+    /// Used to update snapshots with results of filtered runs.
+    ///
+    pub fn extend(&mut self, other: Self) {
+        self.entries.extend(other.entries);
+    }
+
+    ///
+    /// Compares the measured snapshot with the recorded one.
+    ///
+    /// Recorded entries which were not measured are only reported if `is_complete` is set,
+    /// that is, if the run has not been filtered, and if they are not in `failed`, as failed
+    /// tests are not measured, but are already reported.
+    ///
+    pub fn compare(
+        &self,
+        found: &Self,
+        failed: &BTreeSet<String>,
+        is_complete: bool,
+    ) -> Vec<Mismatch> {
+        let mut mismatches = Vec::new();
+        for (key, found) in found.entries.iter() {
+            match self.entries.get(key) {
+                Some(expected) if expected == found => {}
+                Some(expected) => mismatches.push(Mismatch::Changed {
+                    key: key.to_owned(),
+                    expected: *expected,
+                    found: *found,
+                }),
+                None => mismatches.push(Mismatch::Unrecorded {
+                    key: key.to_owned(),
+                    found: *found,
+                }),
+            }
+        }
+        if is_complete {
+            for (key, expected) in self.entries.iter() {
+                if !found.entries.contains_key(key) && !failed.contains(key) {
+                    mismatches.push(Mismatch::Stale {
+                        key: key.to_owned(),
+                        expected: *expected,
+                    });
+                }
+            }
+        }
+        mismatches
+    }
+}
+
+impl FromStr for Snapshot {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut entries = BTreeMap::new();
+        for (index, line) in string.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let position = line
+                .rfind(" (")
+                .ok_or_else(|| anyhow::anyhow!("Line {}: entry values are missing", index + 1))?;
+            let (key, entry) = line.split_at(position);
+            let entry = Entry::from_str(entry.trim_start())
+                .map_err(|error| anyhow::anyhow!("Line {}: {error}", index + 1))?;
+            entries.insert(key.to_owned(), entry);
+        }
+        Ok(Self { entries })
+    }
+}
+
+impl std::fmt::Display for Snapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (key, entry) in self.entries.iter() {
+            writeln!(f, "{key} {entry}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Changed {
+                key,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{:>7} {key} (expected {expected}, found {found})",
+                "CHANGED".bright_red(),
+            ),
+            Self::Unrecorded { key, found } => {
+                write!(f, "{:>7} {key} (found {found})", "NEW".bright_red())
+            }
+            Self::Stale { key, expected } => {
+                write!(f, "{:>7} {key} (expected {expected})", "STALE".bright_red())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::str::FromStr;
+
+    use super::entry::Entry;
+    use super::Mismatch;
+    use super::Snapshot;
+
+    #[test]
+    fn roundtrip() {
+        let mut snapshot = Snapshot::default();
+        snapshot.insert(
+            "tests/solidity/simple/default.sol::first[#deployer:Test] Y +M3B3".to_owned(),
+            Entry::new(1000, Some(200), Some(100)),
+        );
+        snapshot.insert(
+            "tests/solidity/simple/default.sol::first[main:1] Y +M3B3".to_owned(),
+            Entry::new(42, None, None),
+        );
+
+        let parsed = Snapshot::from_str(snapshot.to_string().as_str()).expect("Always valid");
+        assert_eq!(parsed, snapshot);
+    }
+
+    #[test]
+    fn compare() {
+        let changed = "tests/yul/default.yul::first[main:1] Y +M3B3".to_owned();
+        let unrecorded = "tests/yul/default.yul::second[main:1] Y +M3B3".to_owned();
+        let stale = "tests/yul/default.yul::third[main:1] Y +M3B3".to_owned();
+
+        let mut expected = Snapshot::default();
+        expected.insert(changed.clone(), Entry::new(42, None, None));
+        expected.insert(stale.clone(), Entry::new(7, None, None));
+
+        let mut found = Snapshot::default();
+        found.insert(changed.clone(), Entry::new(43, None, None));
+        found.insert(unrecorded.clone(), Entry::new(1, None, None));
+
+        let mismatches = expected.compare(&found, &BTreeSet::new(), true);
+        assert_eq!(mismatches.len(), 3);
+        assert!(matches!(
+            &mismatches[0],
+            Mismatch::Changed { key, found, .. } if key == &changed && found.gas == 43
+        ));
+        assert!(matches!(&mismatches[1], Mismatch::Unrecorded { key, .. } if key == &unrecorded));
+        assert!(matches!(&mismatches[2], Mismatch::Stale { key, .. } if key == &stale));
+
+        let mismatches = expected.compare(&found, &BTreeSet::new(), false);
+        assert_eq!(mismatches.len(), 2);
+    }
+
+    #[test]
+    fn compare_failed() {
+        let passed = "tests/yul/default.yul::first[main:1] Y +M3B3".to_owned();
+        let failed = "tests/yul/default.yul::second[main:1] Y +M3B3".to_owned();
+
+        let mut expected = Snapshot::default();
+        expected.insert(passed.clone(), Entry::new(42, None, None));
+        expected.insert(failed.clone(), Entry::new(7, None, None));
+
+        let mut found = Snapshot::default();
+        found.insert(passed, Entry::new(42, None, None));
+
+        let mismatches = expected.compare(&found, &BTreeSet::from([failed]), true);
+        assert!(mismatches.is_empty());
+    }
+}
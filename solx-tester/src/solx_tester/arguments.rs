@@ -5,6 +5,7 @@
 use std::path::PathBuf;
 
 use clap::Parser;
+use clap::Subcommand;
 
 ///
 /// `solx` tester arguments.
@@ -12,6 +13,10 @@ use clap::Parser;
 #[derive(Debug, Parser)]
 #[command(about, long_about = None)]
 pub struct Arguments {
+    /// The subcommand to run instead of the test suite.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// The logging level.
     #[arg(short, long)]
     pub verbose: bool,
@@ -28,6 +33,17 @@ pub struct Arguments {
     #[arg(long)]
     pub trace: bool,
 
+    /// Writes a self-contained reproduction of each failed test input to the specified directory.
+    /// The reproductions can be re-executed with the `replay` subcommand.
+    #[arg(long)]
+    pub dump_failures: Option<PathBuf>,
+
+    /// Profiles the gas usage by opcode, program counter, and LLVM function, and writes the
+    /// profiles of each test to the specified directory, including flamegraph folded stacks.
+    /// Replaces the REVM trace if both are enabled.
+    #[arg(long)]
+    pub gas_profile: Option<PathBuf>,
+
     /// Runs tests only in modes that contain any string from the specified ones.
     #[arg(short, long)]
     pub mode: Vec<String>,
@@ -50,6 +66,13 @@ pub struct Arguments {
     #[structopt(long = "benchmark-format", default_value_t = solx_benchmark_converter::OutputFormat::Json)]
     pub benchmark_format: solx_benchmark_converter::OutputFormat,
 
+    /// Rewrites the gas snapshot files in `tests/gas-snapshots/` with the measured values,
+    /// instead of checking the measured values against them.
+    /// Filtered runs only overwrite the measured entries and keep the rest.
+    /// Without this flag, a missing snapshot file of the toolchain is reported as a failure.
+    #[structopt(long)]
+    pub update_snapshots: bool,
+
     /// Sets the number of threads, which execute the tests concurrently.
     #[structopt(short, long)]
     pub threads: Option<usize>,
@@ -81,3 +104,42 @@ pub struct Arguments {
     #[structopt(long)]
     pub llvm_debug_logging: bool,
 }
+
+///
+/// `solx` tester subcommands.
+///
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Re-executes reproductions written with `--dump-failures`.
+    Replay {
+        /// The reproduction files.
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+    },
+    /// Converts EIP-3155 execution traces, such as the ones printed with `--trace`, into an
+    /// execution profile for `solx --profile-use`.
+    Profile {
+        /// The `solx` standard JSON output of the traced build with the bytecode objects and
+        /// block maps requested.
+        #[arg(long)]
+        output: PathBuf,
+        /// The execution profile output path.
+        #[arg(long)]
+        profile: PathBuf,
+        /// The trace files.
+        #[arg(required = true)]
+        traces: Vec<PathBuf>,
+    },
+    /// Cross-checks the compiled Yul objects executed on REVM against the reference Yul
+    /// interpreter.
+    CrossCheck {
+        /// The Yul files or directories. Defaults to `tests/yul`.
+        paths: Vec<PathBuf>,
+        /// The number of randomly generated programs to check in addition.
+        #[arg(long, default_value_t = 64)]
+        generated: usize,
+        /// The seed of the program generator.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+}
@@ -12,6 +12,7 @@ use clap::Parser;
 use colored::Colorize;
 
 use self::arguments::Arguments;
+use self::arguments::Command;
 
 /// The rayon worker stack size.
 const RAYON_WORKER_STACK_SIZE: usize = 16 * 1024 * 1024;
@@ -37,6 +38,28 @@ fn main() {
 /// The entry point wrapper used for proper error handling.
 ///
 fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
+    match arguments.command {
+        Some(Command::Replay { paths }) => return replay(paths, arguments.trace),
+        Some(Command::Profile {
+            output,
+            profile,
+            traces,
+        }) => return convert_traces(output, profile, traces),
+        Some(Command::CrossCheck {
+            paths,
+            generated,
+            seed,
+        }) => {
+            solx_tester::LLVMOptions::initialize(
+                arguments.llvm_verify_each,
+                arguments.llvm_debug_logging,
+            )?;
+            let solx = arguments.solx.unwrap_or_else(|| PathBuf::from("solx"));
+            return solx_tester::CrossCheck::new(solx)?.run(paths, generated, seed);
+        }
+        None => {}
+    }
+
     println!(
         "    {} {} v{}",
         "Starting".bright_green().bold(),
@@ -49,9 +72,10 @@ fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
 
     let debug_config = if arguments.debug {
         std::fs::create_dir_all(solx_tester::DEBUG_DIRECTORY)?;
-        Some(solx_codegen_evm::DebugConfig::new(PathBuf::from_str(
-            solx_tester::DEBUG_DIRECTORY,
-        )?))
+        Some(solx_codegen_evm::DebugConfig::new(
+            PathBuf::from_str(solx_tester::DEBUG_DIRECTORY)?,
+            false,
+        ))
     } else {
         None
     };
@@ -104,7 +128,13 @@ fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
     );
 
     solx_tester::REVM::download(executable_download_config_paths)?;
-    compiler_tester.run_revm(toolchain, arguments.solx, arguments.trace)?;
+    compiler_tester.run_revm(
+        toolchain,
+        arguments.solx,
+        arguments.trace,
+        arguments.dump_failures,
+        arguments.gas_profile,
+    )?;
 
     let summary = solx_tester::Summary::unwrap_arc(summary);
     print!("{summary}");
@@ -126,13 +156,90 @@ fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
         output.write_to_file(path)?;
     }
 
-    if !summary.is_successful() {
+    let mut is_snapshot_successful = true;
+    if let solx_tester::Workflow::BuildAndRun = arguments.workflow {
+        let snapshot_path = solx_tester::Snapshot::path(toolchain);
+        let found = summary.snapshot();
+        let is_complete =
+            arguments.mode.is_empty() && arguments.path.is_empty() && arguments.group.is_empty();
+        if arguments.update_snapshots {
+            let snapshot = if is_complete {
+                found
+            } else {
+                let mut snapshot = if snapshot_path.exists() {
+                    solx_tester::Snapshot::read(snapshot_path.as_path())?
+                } else {
+                    solx_tester::Snapshot::default()
+                };
+                snapshot.extend(found);
+                snapshot
+            };
+            snapshot.write(snapshot_path.as_path())?;
+        } else if snapshot_path.exists() {
+            let snapshot = solx_tester::Snapshot::read(snapshot_path.as_path())?;
+            let mismatches = snapshot.compare(&found, &summary.snapshot_failed_keys(), is_complete);
+            for mismatch in mismatches.iter() {
+                println!("{mismatch}");
+            }
+            if !mismatches.is_empty() {
+                println!(
+                    "     {} {} gas snapshot mismatches. Run with `--update-snapshots` to accept the changes",
+                    "Checked".bright_red().bold(),
+                    mismatches.len(),
+                );
+                is_snapshot_successful = false;
+            }
+        } else {
+            println!(
+                "     {} gas snapshot {snapshot_path:?} is not recorded. Run with `--update-snapshots` to record it",
+                "Missing".bright_red().bold(),
+            );
+            is_snapshot_successful = false;
+        }
+    }
+
+    if !summary.is_successful() || !is_snapshot_successful {
         anyhow::bail!("");
     }
 
     Ok(())
 }
 
+///
+/// Re-executes the reproductions of failed test inputs.
+///
+fn replay(paths: Vec<PathBuf>, enable_trace: bool) -> anyhow::Result<()> {
+    let mut failed = 0;
+    for path in paths.iter() {
+        let reproduction = solx_tester::Reproduction::read(path.as_path())?;
+        if !reproduction.replay(enable_trace)? {
+            failed += 1;
+        }
+    }
+
+    if failed > 0 {
+        anyhow::bail!("{failed} out of {} reproductions failed", paths.len());
+    }
+
+    Ok(())
+}
+
+///
+/// Converts the execution traces into an execution profile.
+///
+fn convert_traces(output: PathBuf, profile: PathBuf, traces: Vec<PathBuf>) -> anyhow::Result<()> {
+    let mut converter =
+        solx_tester::TraceConverter::try_from_standard_json_output(output.as_path())?;
+    for path in traces.iter() {
+        converter.convert_file(path.as_path())?;
+    }
+
+    let profile_data = serde_json::to_vec_pretty(&converter.into_profile())?;
+    std::fs::write(profile.as_path(), profile_data)
+        .map_err(|error| anyhow::anyhow!("Execution profile {profile:?} writing: {error}"))?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
@@ -144,15 +251,19 @@ mod tests {
         std::env::set_current_dir("..").expect("Change directory failed");
 
         let arguments = Arguments {
+            command: None,
             verbose: false,
             quiet: false,
             debug: false,
             trace: false,
+            dump_failures: None,
+            gas_profile: None,
             mode: vec!["Y+M3B3 0.8.33".to_owned()],
             path: vec!["tests/solidity/simple/default.sol".to_owned()],
             group: vec![],
             benchmark: None,
             benchmark_format: solx_benchmark_converter::OutputFormat::Xlsx,
+            update_snapshots: false,
             threads: Some(1),
             solx: Some(assert_cmd::cargo::cargo_bin!("SOLX").to_path_buf()),
             toolchain: Some(solx_tester::Toolchain::IrLLVM),
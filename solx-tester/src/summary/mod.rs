@@ -5,15 +5,21 @@
 pub mod benchmark_adapters;
 pub mod element;
 
+use std::collections::BTreeSet;
 use std::sync::Arc;
 use std::sync::Mutex;
 
 use benchmark_adapters::mode::ModeInfo;
 use colored::Colorize;
 
+use crate::compilers::mode::imode::IMode;
+use crate::snapshot::entry::Entry as SnapshotEntry;
+use crate::snapshot::Snapshot;
 use crate::test::case::input::output::Output;
 use crate::test::description::TestDescription;
+use crate::test::selector::TestSelector;
 use crate::toolchain::Toolchain;
+use crate::Mode;
 
 use self::element::outcome::passed_variant::PassedVariant;
 use self::element::outcome::Outcome;
@@ -160,6 +166,88 @@ impl Summary {
         Ok(benchmark)
     }
 
+    ///
+    /// Returns the gas snapshot of the passed tests.
+    ///
+    pub fn snapshot(&self) -> Snapshot {
+        let mut snapshot = Snapshot::default();
+
+        for Element {
+            test_description: TestDescription { mode, selector, .. },
+            outcome,
+        } in self.elements.iter()
+        {
+            let entry = match outcome {
+                Outcome::Passed {
+                    variant:
+                        PassedVariant::Deploy {
+                            deploy_size,
+                            runtime_size,
+                            gas,
+                        },
+                    ..
+                } => SnapshotEntry::new(*gas, Some(*deploy_size), Some(*runtime_size)),
+                Outcome::Passed {
+                    variant: PassedVariant::Runtime { gas },
+                    ..
+                } => SnapshotEntry::new(*gas, None, None),
+                _ => continue,
+            };
+
+            snapshot.insert(Self::snapshot_key(mode.as_ref(), selector), entry);
+        }
+
+        snapshot
+    }
+
+    ///
+    /// Returns the snapshot keys of the failed and invalid tests.
+    ///
+    /// Their recorded entries are not stale, as the tests have been run and reported as failed.
+    ///
+    pub fn snapshot_failed_keys(&self) -> BTreeSet<String> {
+        self.elements
+            .iter()
+            .filter(|element| {
+                matches!(
+                    element.outcome,
+                    Outcome::Failed { .. } | Outcome::Invalid { .. }
+                )
+            })
+            .map(|element| {
+                Self::snapshot_key(
+                    element.test_description.mode.as_ref(),
+                    &element.test_description.selector,
+                )
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the snapshot key of the test.
+    ///
+    /// The compiler version is only included for the upstream `solc` modes, as the `solc`
+    /// toolchain runs each test with several `solc` versions.
+    ///
+    fn snapshot_key(mode: Option<&Mode>, selector: &TestSelector) -> String {
+        match mode {
+            Some(mode) => {
+                let version = match mode {
+                    Mode::Solc(inner) if !inner.via_mlir => mode.version(),
+                    Mode::YulUpstream(inner) if !inner.via_mlir => mode.version(),
+                    _ => None,
+                };
+                let mode = [mode.codegen(), mode.optimizations(), version]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                format!("{selector} {mode}")
+            }
+            None => selector.to_string(),
+        }
+    }
+
     ///
     /// Wraps data into a thread-safe shared reference.
     ///
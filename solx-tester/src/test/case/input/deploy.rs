@@ -9,11 +9,14 @@ use revm::context::result::ExecutionResult;
 
 use crate::summary::Summary;
 use crate::test::case::input::calldata::Calldata;
+use crate::test::case::input::environment::Environment;
 use crate::test::case::input::identifier::InputIdentifier;
 use crate::test::case::input::output::Output;
 use crate::test::description::TestDescription;
 use crate::test::InputContext;
 
+use crate::reproduction::recording::Recording;
+use crate::reproduction::step::Step;
 use crate::revm::revm_type_conversions::revm_bytes_to_vec_value;
 use crate::revm::REVM;
 
@@ -34,6 +37,8 @@ pub struct Deploy {
     caller: web3::types::Address,
     /// The value in wei.
     value: Option<u128>,
+    /// The block and transaction environment.
+    environment: Environment,
     /// The expected output.
     expected: Output,
 }
@@ -49,6 +54,7 @@ impl Deploy {
         calldata: Calldata,
         caller: web3::types::Address,
         value: Option<u128>,
+        environment: Environment,
         expected: Output,
     ) -> Self {
         Self {
@@ -58,6 +64,7 @@ impl Deploy {
             calldata,
             caller,
             value,
+            environment,
             expected,
         }
     }
@@ -67,8 +74,15 @@ impl Deploy {
     ///
     /// Runs the deploy transaction on native REVM.
     ///
-    pub fn run_revm(self, summary: Arc<Mutex<Summary>>, vm: &mut REVM, context: InputContext<'_>) {
+    pub fn run_revm(
+        self,
+        summary: Arc<Mutex<Summary>>,
+        vm: &mut REVM,
+        context: InputContext<'_>,
+        mut recording: Option<&mut Recording>,
+    ) {
         let input_index = context.selector;
+        let dump_failures = context.case_context.dump_failures;
         let test = TestDescription::from_context(
             context,
             InputIdentifier::Deployer {
@@ -81,15 +95,22 @@ impl Deploy {
         calldata.extend(self.calldata.inner);
         let calldata_cost = REVM::calldata_gas_cost(calldata.as_slice());
 
-        let tx = REVM::new_deploy_transaction(self.caller, self.value, calldata.clone());
+        let tx = REVM::new_deploy_transaction(
+            self.caller,
+            self.value,
+            calldata.clone(),
+            &self.environment,
+        );
 
         let initial_balance = (web3::types::U256::from(1) << 100)
-            + web3::types::U256::from(self.value.unwrap_or_default());
+            + web3::types::U256::from(self.value.unwrap_or_default())
+            + REVM::gas_fee_reserve(&self.environment);
         vm.set_account(&self.caller, initial_balance);
-        vm.set_block_data(
-            revm::primitives::U256::from(input_index + 1),
-            revm::primitives::U256::from(((input_index + 1) as u128) * REVM::BLOCK_TIMESTAMP_STEP),
-        );
+        vm.set_environment(&self.environment, input_index);
+
+        if let Some(recording) = recording.as_deref_mut() {
+            recording.push(Step::new(vm, &tx, &[(self.caller, initial_balance)], &[]));
+        }
 
         let result = match vm.execute_transaction(tx) {
             Ok(result) => result,
@@ -134,6 +155,14 @@ impl Deploy {
         } else if let Some(error) = halt_reason {
             Summary::invalid(summary, test, format!("{error:?}"));
         } else {
+            if let (Some(directory), Some(recording)) = (dump_failures, recording) {
+                if let Err(error) = recording.write(directory, &test, &self.expected, &output) {
+                    eprintln!(
+                        "Failed to dump the reproduction of `{}`: {error}",
+                        test.selector
+                    );
+                }
+            }
             Summary::failed(summary, test, self.expected, output, calldata);
         }
     }
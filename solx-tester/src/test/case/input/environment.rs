@@ -0,0 +1,136 @@
+//!
+//! The block and transaction environment of the test input.
+//!
+
+use std::collections::BTreeMap;
+
+use crate::directories::matter_labs::test::metadata::environment::Environment as MatterLabsTestEnvironment;
+use crate::revm::REVM;
+use crate::test::case::input::value::Value;
+use crate::test::instance::Instance;
+
+///
+/// The block and transaction environment of the test input.
+///
+/// The fields which are not set fall back to the REVM defaults.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Environment {
+    /// The block number.
+    pub block_number: Option<web3::types::U256>,
+    /// The block timestamp.
+    pub timestamp: Option<web3::types::U256>,
+    /// The block base fee.
+    pub basefee: Option<u64>,
+    /// The block prevrandao.
+    pub prevrandao: Option<web3::types::U256>,
+    /// The chain ID.
+    pub chain_id: Option<u64>,
+    /// The transaction gas price.
+    pub gas_price: Option<u128>,
+    /// The transaction origin.
+    pub origin: Option<web3::types::Address>,
+}
+
+impl Environment {
+    ///
+    /// Try convert from Matter Labs compiler test metadata environment.
+    ///
+    pub fn try_from_matter_labs(
+        environment: MatterLabsTestEnvironment,
+        instances: &BTreeMap<String, Instance>,
+    ) -> anyhow::Result<Self> {
+        let block_number = Self::parse_value("block_number", environment.block_number, instances)?;
+        let timestamp = Self::parse_value("timestamp", environment.timestamp, instances)?;
+        let basefee = Self::parse_value("basefee", environment.basefee, instances)?
+            .map(|value| u64::try_from(value).map_err(|_| anyhow::anyhow!("`basefee` exceeds u64")))
+            .transpose()?;
+        let prevrandao = Self::parse_value("prevrandao", environment.prevrandao, instances)?;
+        let chain_id = Self::parse_value("chain_id", environment.chain_id, instances)?
+            .map(|value| {
+                u64::try_from(value).map_err(|_| anyhow::anyhow!("`chain_id` exceeds u64"))
+            })
+            .transpose()?;
+        let gas_price = Self::parse_value("gas_price", environment.gas_price, instances)?
+            .map(|value| {
+                u128::try_from(value).map_err(|_| anyhow::anyhow!("`gas_price` exceeds u128"))
+            })
+            .transpose()?;
+        let origin = Self::parse_value("origin", environment.origin, instances)?
+            .map(|value| crate::utils::u256_to_address(&value));
+
+        let effective_basefee = basefee.unwrap_or(REVM::BASE_FEE) as u128;
+        let effective_gas_price = gas_price.unwrap_or(REVM::GAS_PRICE as u128);
+        if effective_gas_price < effective_basefee {
+            anyhow::bail!(
+                "`gas_price` {effective_gas_price} is below `basefee` {effective_basefee}, so the transactions would be rejected. Set `gas_price` explicitly"
+            );
+        }
+
+        Ok(Self {
+            block_number,
+            timestamp,
+            basefee,
+            prevrandao,
+            chain_id,
+            gas_price,
+            origin,
+        })
+    }
+
+    ///
+    /// Parses a known value of the field with the specified name.
+    ///
+    fn parse_value(
+        name: &str,
+        value: Option<String>,
+        instances: &BTreeMap<String, Instance>,
+    ) -> anyhow::Result<Option<web3::types::U256>> {
+        let value = match value {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        match Value::try_from_matter_labs(value.as_str(), instances)
+            .map_err(|error| anyhow::anyhow!("Invalid `{name}` value `{value}`: {error}"))?
+        {
+            Value::Known(value) => Ok(Some(value)),
+            Value::Any => anyhow::bail!("`{name}` can not be `*`"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::directories::matter_labs::test::metadata::environment::Environment as MatterLabsTestEnvironment;
+
+    use super::Environment;
+
+    #[test]
+    fn basefee_without_gas_price() {
+        let environment = MatterLabsTestEnvironment {
+            basefee: Some("7".to_owned()),
+            ..Default::default()
+        };
+
+        let error = Environment::try_from_matter_labs(environment, &BTreeMap::new())
+            .expect_err("Must be rejected");
+        assert!(error.to_string().contains("is below `basefee`"));
+    }
+
+    #[test]
+    fn basefee_with_gas_price() {
+        let environment = MatterLabsTestEnvironment {
+            basefee: Some("7".to_owned()),
+            gas_price: Some("7".to_owned()),
+            ..Default::default()
+        };
+
+        let environment =
+            Environment::try_from_matter_labs(environment, &BTreeMap::new()).expect("Always valid");
+        assert_eq!(environment.basefee, Some(7));
+        assert_eq!(environment.gas_price, Some(7));
+    }
+}
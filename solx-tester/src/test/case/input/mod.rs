@@ -5,6 +5,7 @@
 pub mod balance;
 pub mod calldata;
 pub mod deploy;
+pub mod environment;
 pub mod identifier;
 pub mod output;
 pub mod runtime;
@@ -19,6 +20,7 @@ use std::sync::Mutex;
 
 use crate::compilers::mode::Mode;
 use crate::directories::matter_labs::test::metadata::case::input::Input as MatterLabsTestInput;
+use crate::reproduction::recording::Recording;
 use crate::revm::REVM;
 use crate::summary::Summary;
 use crate::test::instance::Instance;
@@ -27,6 +29,7 @@ use crate::test::InputContext;
 use self::balance::Balance;
 use self::calldata::Calldata;
 use self::deploy::Deploy;
+use self::environment::Environment;
 use self::output::Output;
 use self::runtime::Runtime;
 use self::storage::Storage;
@@ -58,6 +61,10 @@ impl Input {
         instances: &BTreeMap<String, Instance>,
         method_identifiers: &Option<BTreeMap<String, BTreeMap<String, u32>>>,
     ) -> anyhow::Result<Self> {
+        let environment =
+            Environment::try_from_matter_labs(input.environment.unwrap_or_default(), instances)
+                .map_err(|error| anyhow::anyhow!("Invalid environment: {error}"))?;
+
         let caller = match Value::try_from_matter_labs(input.caller.as_str(), instances)
             .map_err(|error| anyhow::anyhow!("Invalid caller `{}`: {error}", input.caller))?
         {
@@ -106,6 +113,7 @@ impl Input {
                 calldata,
                 caller,
                 value,
+                environment,
                 expected,
             )),
             "#fallback" => {
@@ -123,6 +131,7 @@ impl Input {
                     caller,
                     value,
                     storage,
+                    environment,
                     expected,
                 ))
             }
@@ -170,6 +179,7 @@ impl Input {
                     caller,
                     value,
                     storage,
+                    environment,
                     expected,
                 ))
             }
@@ -224,6 +234,7 @@ impl Input {
                     calldata.clone().into(),
                     *caller,
                     value,
+                    Environment::default(),
                     expected,
                 )))
             }
@@ -255,6 +266,7 @@ impl Input {
                     Calldata::default(),
                     *caller,
                     None,
+                    Environment::default(),
                     expected,
                 )))
             }
@@ -297,6 +309,7 @@ impl Input {
                     *caller,
                     value,
                     Storage::default(),
+                    Environment::default(),
                     expected,
                 )))
             }
@@ -309,10 +322,18 @@ impl Input {
     ///
     /// Runs the input on REVM.
     ///
-    pub fn run_revm(self, summary: Arc<Mutex<Summary>>, vm: &mut REVM, context: InputContext<'_>) {
+    /// The transactions are appended to the `recording` of the test case, if it is enabled.
+    ///
+    pub fn run_revm(
+        self,
+        summary: Arc<Mutex<Summary>>,
+        vm: &mut REVM,
+        context: InputContext<'_>,
+        recording: Option<&mut Recording>,
+    ) {
         match self {
-            Self::Deploy(deploy) => deploy.run_revm(summary, vm, context),
-            Self::Runtime(runtime) => runtime.run_revm(summary, vm, context),
+            Self::Deploy(deploy) => deploy.run_revm(summary, vm, context, recording),
+            Self::Runtime(runtime) => runtime.run_revm(summary, vm, context, recording),
             Self::StorageEmpty(storage_empty) => storage_empty.run_revm(summary, vm, context),
             Self::Balance(balance_check) => balance_check.run_revm(summary, vm, context),
         }
@@ -12,7 +12,7 @@ use crate::test::case::input::value::Value;
 ///
 /// The compiler test outcome event.
 ///
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Event {
     /// The event address.
     address: Option<web3::types::Address>,
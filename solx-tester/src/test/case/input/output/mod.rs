@@ -3,6 +3,7 @@
 //!
 
 pub mod event;
+pub mod revert;
 
 use std::collections::BTreeMap;
 use std::str::FromStr;
@@ -16,11 +17,12 @@ use crate::test::case::input::value::Value;
 use crate::test::instance::Instance;
 
 use self::event::Event;
+use self::revert::Revert;
 
 ///
 /// The compiler test outcome data.
 ///
-#[derive(Debug, Default, Clone, serde::Serialize)]
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Output {
     /// The return data values.
     pub return_data: Vec<Value>,
@@ -70,11 +72,32 @@ impl Output {
             })
             .ok_or_else(|| anyhow::anyhow!("Version not covered"))?;
 
-        let (return_data, exception, events) = match variant {
-            MatterLabsTestExpectedVariant::Simple(return_data) => (return_data, false, Vec::new()),
+        let (return_data, revert, exception, events) = match variant {
+            MatterLabsTestExpectedVariant::Simple(return_data) => {
+                (return_data, None, false, Vec::new())
+            }
             MatterLabsTestExpectedVariant::Extended(expected) => {
+                let exception = expected.is_exception();
+                let revert = match (expected.error, expected.revert_string) {
+                    (Some(_), Some(_)) => {
+                        anyhow::bail!("`error` and `revert_string` are mutually exclusive")
+                    }
+                    (Some(signature), None) => Some(Revert::Error {
+                        signature,
+                        arguments: expected.arguments,
+                    }),
+                    (None, Some(reason)) => Some(Revert::String(reason)),
+                    (None, None) if !expected.arguments.is_empty() => {
+                        anyhow::bail!("`arguments` can only be specified with `error`")
+                    }
+                    (None, None) => None,
+                };
+                if revert.is_some() && !expected.return_data.is_empty() {
+                    anyhow::bail!(
+                        "`return_data` can not be specified with `error` or `revert_string`"
+                    );
+                }
                 let return_data = expected.return_data;
-                let exception = expected.exception;
                 let events = expected
                     .events
                     .into_iter()
@@ -85,11 +108,16 @@ impl Output {
                     })
                     .collect::<anyhow::Result<Vec<Event>>>()
                     .map_err(|error| anyhow::anyhow!("Invalid events: {error}"))?;
-                (return_data, exception, events)
+                (return_data, revert, exception, events)
             }
         };
-        let return_data = Value::try_from_vec_matter_labs(return_data, instances)
-            .map_err(|error| anyhow::anyhow!("Invalid return data: {error}"))?;
+        let return_data = match revert {
+            Some(revert) => revert
+                .try_into_return_data(instances)
+                .map_err(|error| anyhow::anyhow!("Invalid revert data: {error}"))?,
+            None => Value::try_from_vec_matter_labs(return_data, instances)
+                .map_err(|error| anyhow::anyhow!("Invalid return data: {error}"))?,
+        };
 
         Ok(Self {
             return_data,
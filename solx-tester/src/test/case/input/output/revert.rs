@@ -0,0 +1,159 @@
+//!
+//! The compiler test expected revert data.
+//!
+
+use std::collections::BTreeMap;
+
+use web3::ethabi;
+
+use crate::revm::revm_type_conversions::revm_bytes_to_vec_value;
+use crate::test::case::input::value::Value;
+use crate::test::instance::Instance;
+
+///
+/// The compiler test expected revert data.
+///
+#[derive(Debug, Clone)]
+pub enum Revert {
+    /// The custom error with its signature and arguments, e.g. `InsufficientBalance(uint256,uint256)`.
+    Error {
+        /// The error signature.
+        signature: String,
+        /// The error arguments.
+        arguments: Vec<String>,
+    },
+    /// The `Error(string)` revert reason.
+    String(String),
+}
+
+impl Revert {
+    /// The `Error(string)` signature used by `revert("...")` and `require(..., "...")`.
+    pub const ERROR_STRING_SIGNATURE: &'static str = "Error(string)";
+
+    ///
+    /// ABI-encodes the revert data and splits it into return data words.
+    ///
+    pub fn try_into_return_data(
+        self,
+        instances: &BTreeMap<String, Instance>,
+    ) -> anyhow::Result<Vec<Value>> {
+        let (signature, arguments) = match self {
+            Self::Error {
+                signature,
+                arguments,
+            } => (signature, arguments),
+            Self::String(reason) => (Self::ERROR_STRING_SIGNATURE.to_owned(), vec![reason]),
+        };
+
+        let parameter_types = Self::parameter_types(signature.as_str())
+            .map_err(|error| anyhow::anyhow!("Invalid error signature `{signature}`: {error}"))?;
+        if parameter_types.len() != arguments.len() {
+            anyhow::bail!(
+                "Error `{signature}` expects {} arguments, found {}",
+                parameter_types.len(),
+                arguments.len()
+            );
+        }
+
+        let tokens = parameter_types
+            .iter()
+            .zip(arguments)
+            .enumerate()
+            .map(|(index, (parameter_type, argument))| {
+                Self::tokenize(parameter_type, argument.as_str(), instances).map_err(|error| {
+                    anyhow::anyhow!("Argument #{index} `{argument}` is invalid: {error}")
+                })
+            })
+            .collect::<anyhow::Result<Vec<ethabi::Token>>>()?;
+
+        let mut data = solx_utils::Keccak256Hash::from_slice(signature.as_bytes()).as_bytes()
+            [..solx_utils::BYTE_LENGTH_X32]
+            .to_vec();
+        data.extend(ethabi::encode(tokens.as_slice()));
+        Ok(revm_bytes_to_vec_value(revm::primitives::Bytes::from(data)))
+    }
+
+    ///
+    /// Parses the parameter types from the error signature.
+    ///
+    fn parameter_types(signature: &str) -> anyhow::Result<Vec<ethabi::ParamType>> {
+        let position = signature
+            .find('(')
+            .ok_or_else(|| anyhow::anyhow!("Expected `Name(type1,type2,...)`"))?;
+        let (name, parameters) = signature.split_at(position);
+        if name.is_empty() {
+            anyhow::bail!("The error name is missing");
+        }
+        if parameters == "()" {
+            return Ok(vec![]);
+        }
+
+        match ethabi::param_type::Reader::read(parameters)
+            .map_err(|error| anyhow::anyhow!("{error}"))?
+        {
+            ethabi::ParamType::Tuple(parameter_types) => Ok(parameter_types),
+            _ => anyhow::bail!("Expected a parenthesized list of types"),
+        }
+    }
+
+    ///
+    /// Converts the argument string into an ABI token of the specified type.
+    ///
+    fn tokenize(
+        parameter_type: &ethabi::ParamType,
+        argument: &str,
+        instances: &BTreeMap<String, Instance>,
+    ) -> anyhow::Result<ethabi::Token> {
+        let token = match parameter_type {
+            ethabi::ParamType::Uint(_) => {
+                ethabi::Token::Uint(Self::known_value(argument, instances)?)
+            }
+            ethabi::ParamType::Int(_) => {
+                ethabi::Token::Int(Self::known_value(argument, instances)?)
+            }
+            ethabi::ParamType::Address => ethabi::Token::Address(crate::utils::u256_to_address(
+                &Self::known_value(argument, instances)?,
+            )),
+            ethabi::ParamType::Bool => match argument {
+                "true" => ethabi::Token::Bool(true),
+                "false" => ethabi::Token::Bool(false),
+                argument => anyhow::bail!("Expected `true` or `false`, found `{argument}`"),
+            },
+            ethabi::ParamType::String => ethabi::Token::String(argument.to_owned()),
+            ethabi::ParamType::Bytes => ethabi::Token::Bytes(Self::hex_value(argument)?),
+            ethabi::ParamType::FixedBytes(size) => {
+                let bytes = Self::hex_value(argument)?;
+                if bytes.len() != *size {
+                    anyhow::bail!("Expected {size} bytes, found {}", bytes.len());
+                }
+                ethabi::Token::FixedBytes(bytes)
+            }
+            parameter_type => anyhow::bail!("Type `{parameter_type}` is not supported"),
+        };
+        Ok(token)
+    }
+
+    ///
+    /// Parses a known value, supporting the same syntax as other metadata values.
+    ///
+    fn known_value(
+        argument: &str,
+        instances: &BTreeMap<String, Instance>,
+    ) -> anyhow::Result<web3::types::U256> {
+        match Value::try_from_matter_labs(argument, instances)? {
+            Value::Known(value) => Ok(value),
+            Value::Any => anyhow::bail!("Error arguments can not be `*`"),
+        }
+    }
+
+    ///
+    /// Parses a `0x`-prefixed hexadecimal byte string.
+    ///
+    fn hex_value(argument: &str) -> anyhow::Result<Vec<u8>> {
+        let argument = argument
+            .strip_prefix("0x")
+            .ok_or_else(|| anyhow::anyhow!("Expected a `0x`-prefixed hexadecimal string"))?;
+        hex::decode(argument)
+            .map_err(|error| anyhow::anyhow!("Invalid hexadecimal string: {error}"))
+    }
+}
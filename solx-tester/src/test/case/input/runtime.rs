@@ -8,10 +8,13 @@ use std::sync::Mutex;
 
 use revm::context::result::ExecutionResult;
 
+use crate::reproduction::recording::Recording;
+use crate::reproduction::step::Step;
 use crate::revm::revm_type_conversions::revm_bytes_to_vec_value;
 use crate::revm::REVM;
 use crate::summary::Summary;
 use crate::test::case::input::calldata::Calldata;
+use crate::test::case::input::environment::Environment;
 use crate::test::case::input::identifier::InputIdentifier;
 use crate::test::case::input::output::Output;
 use crate::test::case::input::storage::Storage;
@@ -34,6 +37,8 @@ pub struct Runtime {
     value: Option<u128>,
     /// The contracts storage to set before running.
     storage: Storage,
+    /// The block and transaction environment.
+    environment: Environment,
     /// The expected output.
     expected: Output,
 }
@@ -49,6 +54,7 @@ impl Runtime {
         caller: web3::types::Address,
         value: Option<u128>,
         storage: Storage,
+        environment: Environment,
         expected: Output,
     ) -> Self {
         Self {
@@ -58,6 +64,7 @@ impl Runtime {
             caller,
             value,
             storage,
+            environment,
             expected,
         }
     }
@@ -72,8 +79,10 @@ impl Runtime {
         summary: Arc<Mutex<Summary>>,
         vm: &mut REVM,
         context: InputContext<'_>,
+        mut recording: Option<&mut Recording>,
     ) {
         let input_index = context.selector;
+        let dump_failures = context.case_context.dump_failures;
         let test = TestDescription::from_context(
             context,
             InputIdentifier::Runtime {
@@ -91,9 +100,11 @@ impl Runtime {
             self.caller,
             self.calldata.clone(),
             self.value,
+            &self.environment,
         );
 
-        let mut initial_balance = web3::types::U256::from(self.value.unwrap_or_default());
+        let mut initial_balance = web3::types::U256::from(self.value.unwrap_or_default())
+            + REVM::gas_fee_reserve(&self.environment);
         if REVM::get_rich_addresses().contains(&self.caller) {
             initial_balance += web3::types::U256::from(1) << 100;
         }
@@ -105,11 +116,18 @@ impl Runtime {
             .get(&self.address)
             .cloned()
             .unwrap_or_default();
+        let step_storage = recording.is_some().then(|| storage.clone());
         vm.extend_account_storage(&self.address, storage);
-        vm.set_block_data(
-            revm::primitives::U256::from(input_index + 1),
-            revm::primitives::U256::from(((input_index + 1) as u128) * REVM::BLOCK_TIMESTAMP_STEP),
-        );
+        vm.set_environment(&self.environment, input_index);
+
+        if let (Some(recording), Some(storage)) = (recording.as_deref_mut(), step_storage) {
+            recording.push(Step::new(
+                vm,
+                &tx,
+                &[(self.caller, initial_balance)],
+                &[(self.address, &storage)],
+            ));
+        }
 
         let result = match vm.execute_transaction(tx) {
             Ok(result) => result,
@@ -144,6 +162,14 @@ impl Runtime {
         } else if let Some(error) = error {
             Summary::invalid(summary, test, format!("{error:?}"));
         } else {
+            if let (Some(directory), Some(recording)) = (dump_failures, recording) {
+                if let Err(error) = recording.write(directory, &test, &self.expected, &output) {
+                    eprintln!(
+                        "Failed to dump the reproduction of `{}`: {error}",
+                        test.selector
+                    );
+                }
+            }
             Summary::failed(summary, test, self.expected, output, self.calldata.inner);
         }
     }
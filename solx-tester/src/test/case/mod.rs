@@ -10,6 +10,7 @@ use std::sync::Mutex;
 
 use crate::compilers::mode::Mode;
 use crate::directories::matter_labs::test::metadata::case::Case as MatterLabsTestCase;
+use crate::reproduction::recording::Recording;
 use crate::revm::REVM;
 use crate::summary::Summary;
 use crate::test::instance::Instance;
@@ -31,6 +32,9 @@ pub struct Case {
 }
 
 impl Case {
+    /// The case name used in gas profiles if the case is unnamed.
+    pub const DEFAULT_NAME: &'static str = "default";
+
     ///
     /// A shortcut constructor.
     ///
@@ -93,14 +97,19 @@ impl Case {
     ///
     /// Runs the case on REVM.
     ///
-    pub fn run_revm(self, summary: Arc<Mutex<Summary>>, context: &CaseContext, mut vm: REVM) {
+    pub fn run_revm(self, summary: Arc<Mutex<Summary>>, context: &CaseContext, vm: &mut REVM) {
+        let mut recording = context.dump_failures.map(|_| Recording::default());
         for (index, input) in self.inputs.into_iter().enumerate() {
+            vm.set_profile_root(format!(
+                "{}[{index}]",
+                self.name.as_deref().unwrap_or(Self::DEFAULT_NAME)
+            ));
             let context = InputContext {
                 case_context: context,
                 case_name: &self.name,
                 selector: index,
             };
-            input.run_revm(summary.clone(), &mut vm, context)
+            input.run_revm(summary.clone(), vm, context, recording.as_mut())
         }
     }
 }
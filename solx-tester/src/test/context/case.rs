@@ -2,6 +2,8 @@
 //! Context used to process test cases, consisting of a number of inputs.
 //!
 
+use std::path::Path;
+
 use crate::Mode;
 
 ///
@@ -12,4 +14,6 @@ pub struct CaseContext<'a> {
     pub mode: &'a Mode,
     pub group: &'a Option<String>,
     pub name: &'a str,
+    /// The directory to dump reproductions of failed inputs to.
+    pub dump_failures: Option<&'a Path>,
 }
@@ -8,10 +8,13 @@ pub mod description;
 pub mod instance;
 pub mod selector;
 
+use std::path::Path;
 use std::sync::Arc;
 use std::sync::Mutex;
 
 use crate::compilers::mode::Mode;
+use crate::revm::profiler::profile::Profile;
+use crate::revm::profiler::symbols::Symbols;
 use crate::revm::REVM;
 use crate::summary::Summary;
 use crate::test::case::Case;
@@ -31,32 +34,71 @@ pub struct Test {
     mode: Mode,
     /// The test group.
     group: Option<String>,
+    /// The contract symbols for gas profiling.
+    symbols: Vec<Symbols>,
 }
 
 impl Test {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(name: String, cases: Vec<Case>, mode: Mode, group: Option<String>) -> Self {
+    pub fn new(
+        name: String,
+        cases: Vec<Case>,
+        mode: Mode,
+        group: Option<String>,
+        symbols: Vec<Symbols>,
+    ) -> Self {
         Self {
             name,
             cases,
             mode,
             group,
+            symbols,
         }
     }
 
     ///
     /// Runs the test on REVM.
     ///
-    pub fn run_revm(self, summary: Arc<Mutex<Summary>>, enable_trace: bool) {
+    /// If `gas_profile` is set, the tracer is replaced with the gas profiler, and the profile
+    /// of all test cases is written to the specified directory.
+    ///
+    pub fn run_revm(
+        self,
+        summary: Arc<Mutex<Summary>>,
+        enable_trace: bool,
+        dump_failures: Option<&Path>,
+        gas_profile: Option<&Path>,
+    ) {
+        let symbols = Arc::new(self.symbols);
+        let mut profile = Profile::default();
+
         for case in self.cases {
             let context = CaseContext {
                 name: &self.name,
                 mode: &self.mode,
                 group: &self.group,
+                dump_failures,
+            };
+            let mut vm = match gas_profile {
+                Some(_) => REVM::new_profiling(symbols.clone()),
+                None => REVM::new(enable_trace),
             };
-            case.run_revm(summary.clone(), &context, REVM::new(enable_trace));
+            case.run_revm(summary.clone(), &context, &mut vm);
+            if let Some(case_profile) = vm.take_profile() {
+                profile.extend(case_profile);
+            }
+        }
+
+        if let Some(directory) = gas_profile {
+            if profile.is_empty() {
+                return;
+            }
+            let name = format!("{} {}", self.name, self.mode);
+            if let Err(error) = profile.write(directory, name.as_str()) {
+                eprintln!("Gas profile of `{name}` writing error: {error}");
+            }
         }
     }
 }
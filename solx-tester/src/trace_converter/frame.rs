@@ -0,0 +1,28 @@
+//!
+//! The trace converter call frame.
+//!
+
+use std::collections::HashMap;
+
+///
+/// The trace converter call frame.
+///
+#[derive(Debug)]
+pub struct Frame {
+    /// The indexes of code segments whose bytecode matches all instructions executed so far.
+    pub candidates: Vec<usize>,
+    /// The execution counts by program counter.
+    pub counts: HashMap<usize, u64>,
+}
+
+impl Frame {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(segments_number: usize) -> Self {
+        Self {
+            candidates: (0..segments_number).collect(),
+            counts: HashMap::new(),
+        }
+    }
+}
@@ -0,0 +1,259 @@
+//!
+//! The converter of execution traces into execution profiles.
+//!
+
+pub mod frame;
+pub mod segment;
+
+use std::io::BufRead;
+use std::path::Path;
+
+use self::frame::Frame;
+use self::segment::Segment;
+
+///
+/// The converter of execution traces into execution profiles.
+///
+/// Reads EIP-3155 traces, such as the ones printed with `--trace`, and counts the executions of
+/// LLVM basic blocks with the help of the block maps of the traced build. As the traces do not
+/// contain code addresses, each call frame is attributed to the first code segment whose
+/// bytecode matches all instructions executed in the frame.
+///
+#[derive(Debug)]
+pub struct TraceConverter {
+    /// The code segments of the traced build.
+    segments: Vec<Segment>,
+    /// The call frames of the transaction being converted.
+    frames: Vec<Frame>,
+    /// The profile collected so far.
+    profile: solx_utils::ExecutionProfile,
+}
+
+impl TraceConverter {
+    ///
+    /// Reads the code segments of the traced build from the `solx` standard JSON output.
+    ///
+    /// The output must contain the bytecode objects and block maps, that is, the
+    /// `evm.bytecode.object`, `evm.bytecode.blockMap`, `evm.deployedBytecode.object`, and
+    /// `evm.deployedBytecode.blockMap` selectors must be requested.
+    ///
+    pub fn try_from_standard_json_output(path: &Path) -> anyhow::Result<Self> {
+        let data = std::fs::read(path)
+            .map_err(|error| anyhow::anyhow!("Standard JSON output {path:?} reading: {error}"))?;
+        let output: solx_standard_json::Output =
+            solx_utils::deserialize_from_slice(data.as_slice()).map_err(|error| {
+                anyhow::anyhow!("Standard JSON output {path:?} parsing: {error}")
+            })?;
+
+        let mut segments = Vec::new();
+        for (file, contracts) in output.contracts.into_iter() {
+            for (name, contract) in contracts.into_iter() {
+                let Some(evm) = contract.evm else {
+                    continue;
+                };
+                for (bytecode, code_segment) in [
+                    (evm.bytecode, solx_utils::CodeSegment::Deploy),
+                    (evm.deployed_bytecode, solx_utils::CodeSegment::Runtime),
+                ] {
+                    let Some((object, block_map)) =
+                        bytecode.and_then(|bytecode| Some((bytecode.object?, bytecode.block_map?)))
+                    else {
+                        continue;
+                    };
+                    // Unlinked bytecode cannot be executed, so it is skipped.
+                    let Ok(bytecode) = hex::decode(object.as_str()) else {
+                        continue;
+                    };
+                    segments.push(Segment::new(
+                        format!("{file}:{name}"),
+                        code_segment,
+                        bytecode,
+                        block_map,
+                    ));
+                }
+            }
+        }
+        if segments.is_empty() {
+            anyhow::bail!("Standard JSON output {path:?} contains no bytecode with block maps");
+        }
+
+        Ok(Self {
+            segments,
+            frames: Vec::new(),
+            profile: solx_utils::ExecutionProfile::default(),
+        })
+    }
+
+    ///
+    /// Converts the trace file, skipping the lines that are not EIP-3155 steps or summaries.
+    ///
+    pub fn convert_file(&mut self, path: &Path) -> anyhow::Result<()> {
+        let file = std::fs::File::open(path)
+            .map_err(|error| anyhow::anyhow!("Trace file {path:?} opening: {error}"))?;
+        for line in std::io::BufReader::new(file).lines() {
+            let line =
+                line.map_err(|error| anyhow::anyhow!("Trace file {path:?} reading: {error}"))?;
+            let Ok(value) = serde_json::from_str::<serde_json::Value>(line.as_str()) else {
+                continue;
+            };
+
+            let field = |name: &str| value.get(name).and_then(serde_json::Value::as_u64);
+            match (field("pc"), field("op"), field("depth")) {
+                (Some(pc), Some(opcode), Some(depth)) => {
+                    self.step(depth as usize, pc as usize, opcode as u8)
+                }
+                _ if value.get("gasUsed").is_some() => self.finish_transaction(),
+                _ => {}
+            }
+        }
+        self.finish_transaction();
+        Ok(())
+    }
+
+    ///
+    /// Records an executed instruction.
+    ///
+    /// The call depth starts from 1 for the transaction frame.
+    ///
+    pub fn step(&mut self, depth: usize, pc: usize, opcode: u8) {
+        let depth = depth.max(1);
+        while self.frames.len() > depth {
+            self.finish_frame();
+        }
+        while self.frames.len() < depth {
+            self.frames.push(Frame::new(self.segments.len()));
+        }
+
+        let segments = &self.segments;
+        let frame = self.frames.last_mut().expect("Always exists");
+        if frame.candidates.is_empty() {
+            return;
+        }
+        frame
+            .candidates
+            .retain(|index| segments[*index].bytecode.get(pc) == Some(&opcode));
+        *frame.counts.entry(pc).or_default() += 1;
+    }
+
+    ///
+    /// Finishes all call frames of the current transaction.
+    ///
+    pub fn finish_transaction(&mut self) {
+        while !self.frames.is_empty() {
+            self.finish_frame();
+        }
+    }
+
+    ///
+    /// Returns the collected profile.
+    ///
+    /// All blocks of the executed code segments are included, so the blocks which have never
+    /// been executed can be told apart from the ones missing in the block maps.
+    ///
+    pub fn into_profile(mut self) -> solx_utils::ExecutionProfile {
+        self.finish_transaction();
+        for segment in self.segments.iter() {
+            if self
+                .profile
+                .unit(segment.contract.as_str(), segment.code_segment)
+                .is_none()
+            {
+                continue;
+            }
+            for entry in segment.block_map.entries.iter() {
+                let Some(ref block) = entry.block else {
+                    continue;
+                };
+                self.profile.record(
+                    segment.contract.as_str(),
+                    segment.code_segment,
+                    entry.function.as_str(),
+                    block.as_str(),
+                    0,
+                );
+            }
+        }
+        self.profile
+    }
+
+    ///
+    /// Pops the finished frame, and records the executions of the blocks started in it.
+    ///
+    fn finish_frame(&mut self) {
+        let Some(frame) = self.frames.pop() else {
+            return;
+        };
+        let Some(segment) = frame.candidates.first().map(|index| &self.segments[*index]) else {
+            return;
+        };
+
+        for (pc, count) in frame.counts.into_iter() {
+            let Some(entry) = segment.block_map.entry_at(pc) else {
+                continue;
+            };
+            let Some(ref block) = entry.block else {
+                continue;
+            };
+            self.profile.record(
+                segment.contract.as_str(),
+                segment.code_segment,
+                entry.function.as_str(),
+                block.as_str(),
+                count,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::segment::Segment;
+    use super::TraceConverter;
+
+    #[test]
+    fn step() {
+        let block_map = solx_utils::BlockMap {
+            entries: vec![
+                solx_utils::BlockMapEntry::new(0, "main".to_owned(), Some("entry".to_owned())),
+                solx_utils::BlockMapEntry::new(3, "main".to_owned(), Some("for_body".to_owned())),
+                solx_utils::BlockMapEntry::new(5, "main".to_owned(), Some("for_join".to_owned())),
+            ],
+        };
+        let mut converter = TraceConverter {
+            segments: vec![
+                Segment::new(
+                    "default.sol:Other".to_owned(),
+                    solx_utils::CodeSegment::Runtime,
+                    vec![0x5b, 0x60, 0x00, 0x00],
+                    block_map.clone(),
+                ),
+                Segment::new(
+                    "default.sol:Test".to_owned(),
+                    solx_utils::CodeSegment::Runtime,
+                    vec![0x5b, 0x60, 0x03, 0x5b, 0x56],
+                    block_map,
+                ),
+            ],
+            frames: vec![],
+            profile: solx_utils::ExecutionProfile::default(),
+        };
+
+        converter.step(1, 0, 0x5b);
+        converter.step(1, 1, 0x60);
+        for _ in 0..3 {
+            converter.step(1, 3, 0x5b);
+            converter.step(1, 4, 0x56);
+        }
+        let profile = converter.into_profile();
+
+        let unit = profile
+            .unit("default.sol:Test", solx_utils::CodeSegment::Runtime)
+            .expect("Always exists");
+        assert_eq!(unit.count("main", "entry"), Some(1));
+        assert_eq!(unit.count("main", "for_body"), Some(3));
+        assert_eq!(unit.count("main", "for_join"), Some(0));
+        assert!(profile
+            .unit("default.sol:Other", solx_utils::CodeSegment::Runtime)
+            .is_none());
+    }
+}
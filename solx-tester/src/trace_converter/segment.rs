@@ -0,0 +1,37 @@
+//!
+//! The trace converter code segment.
+//!
+
+///
+/// The trace converter code segment.
+///
+#[derive(Debug)]
+pub struct Segment {
+    /// The full contract name, e.g. `tests/solidity/simple/default.sol:Test`.
+    pub contract: String,
+    /// The code segment.
+    pub code_segment: solx_utils::CodeSegment,
+    /// The bytecode.
+    pub bytecode: Vec<u8>,
+    /// The mapping of bytecode offsets to LLVM basic blocks.
+    pub block_map: solx_utils::BlockMap,
+}
+
+impl Segment {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        contract: String,
+        code_segment: solx_utils::CodeSegment,
+        bytecode: Vec<u8>,
+        block_map: solx_utils::BlockMap,
+    ) -> Self {
+        Self {
+            contract,
+            code_segment,
+            bytecode,
+            block_map,
+        }
+    }
+}
@@ -61,3 +61,20 @@ pub fn str_to_path_normalized(path: &str) -> PathBuf {
 pub fn str_to_string_normalized(path: &str) -> String {
     path.replace(std::path::MAIN_SEPARATOR_STR, "/")
 }
+
+///
+/// Replaces all characters which are not ASCII alphanumeric with underscores, so the string
+/// can be used as a file name.
+///
+pub fn str_to_file_name(string: &str) -> String {
+    string
+        .chars()
+        .map(|char| {
+            if char.is_ascii_alphanumeric() {
+                char
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
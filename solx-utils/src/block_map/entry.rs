@@ -0,0 +1,32 @@
+//!
+//! The block map entry.
+//!
+
+///
+/// The block map entry.
+///
+/// Describes an LLVM basic block starting at the specified bytecode offset.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Entry {
+    /// The bytecode offset of the first instruction of the block.
+    pub offset: usize,
+    /// The LLVM function the block belongs to.
+    pub function: String,
+    /// The LLVM IR basic block name, if the block has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block: Option<String>,
+}
+
+impl Entry {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(offset: usize, function: String, block: Option<String>) -> Self {
+        Self {
+            offset,
+            function,
+            block,
+        }
+    }
+}
@@ -0,0 +1,205 @@
+//!
+//! The mapping of bytecode offsets to LLVM basic blocks.
+//!
+
+pub mod entry;
+
+use self::entry::Entry;
+
+///
+/// The mapping of bytecode offsets to LLVM basic blocks.
+///
+/// Is extracted from the verbose LLVM EVM assembly, so it does not require any instrumentation
+/// of the bytecode. The basic blocks are identified by their LLVM IR names, which are printed
+/// in the assembly comments, e.g. `.BB0_2: ; %if_join` or `; %bb.0: ; %entry`.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct BlockMap {
+    /// The basic blocks sorted by offset.
+    pub entries: Vec<Entry>,
+}
+
+impl BlockMap {
+    /// The verbose assembly comment prefix of blocks that do not need a label.
+    pub const UNLABELED_BLOCK_PREFIX: &'static str = "%bb.";
+
+    ///
+    /// Extracts the basic block offsets from the LLVM EVM assembly.
+    ///
+    /// Offsets are computed by summing up instruction sizes, where `PUSH<N>` takes `N + 1` bytes
+    /// and the rest take one byte. Labels starting with `.` and `; %bb.<N>:` comments start
+    /// basic blocks, and the other labels start functions. If several blocks start at the same
+    /// offset, only the last one is kept.
+    ///
+    pub fn from_llvm_assembly(assembly: &str) -> Self {
+        let mut block_map = Self::default();
+        let mut function = String::new();
+        let mut offset = 0;
+
+        for line in assembly.lines() {
+            let (code, comment) = match line.split_once(';') {
+                Some((code, comment)) => (code.trim(), Some(comment)),
+                None => (line.trim(), None),
+            };
+
+            if code.is_empty() {
+                if let Some(comment) = comment.filter(|comment| {
+                    comment
+                        .trim_start()
+                        .starts_with(Self::UNLABELED_BLOCK_PREFIX)
+                }) {
+                    block_map.push(Entry::new(
+                        offset,
+                        function.clone(),
+                        Self::ir_block_name(comment),
+                    ));
+                }
+                continue;
+            }
+            if let Some(label) = code.strip_suffix(':') {
+                if label.starts_with('.') {
+                    block_map.push(Entry::new(
+                        offset,
+                        function.clone(),
+                        comment.and_then(Self::ir_block_name),
+                    ));
+                } else if !label.contains(char::is_whitespace) {
+                    function = label.trim_matches('"').to_owned();
+                    block_map.push(Entry::new(offset, function.clone(), None));
+                }
+                continue;
+            }
+            if code.starts_with('.') {
+                continue;
+            }
+
+            let mnemonic = code
+                .split_whitespace()
+                .next()
+                .expect("Always exists")
+                .to_uppercase();
+            offset += match mnemonic
+                .strip_prefix("PUSH")
+                .and_then(|size| size.parse::<usize>().ok())
+            {
+                Some(size) => size + 1,
+                None => 1,
+            };
+        }
+
+        block_map
+    }
+
+    ///
+    /// Returns the basic block containing `offset`.
+    ///
+    pub fn entry(&self, offset: usize) -> Option<&Entry> {
+        let index = self.entries.partition_point(|entry| entry.offset <= offset);
+        self.entries.get(index.checked_sub(1)?)
+    }
+
+    ///
+    /// Returns the basic block starting exactly at `offset`.
+    ///
+    pub fn entry_at(&self, offset: usize) -> Option<&Entry> {
+        self.entry(offset).filter(|entry| entry.offset == offset)
+    }
+
+    ///
+    /// Appends a block, replacing the last one if it starts at the same offset.
+    ///
+    fn push(&mut self, entry: Entry) {
+        if let Some(last) = self.entries.last_mut() {
+            if last.offset == entry.offset {
+                *last = entry;
+                return;
+            }
+        }
+        self.entries.push(entry);
+    }
+
+    ///
+    /// Extracts the LLVM IR basic block name from a verbose assembly comment.
+    ///
+    /// The name is the last comment part starting with `%`, e.g. `if_join` in `%bb.3: ; %if_join`.
+    ///
+    fn ir_block_name(comment: &str) -> Option<String> {
+        let name = comment.rsplit(';').next()?.trim().strip_prefix('%')?;
+        if name.is_empty() || (name.starts_with("bb.") && name.ends_with(':')) {
+            return None;
+        }
+        Some(name.trim_matches('"').to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::entry::Entry;
+    use super::BlockMap;
+
+    #[test]
+    fn from_llvm_assembly() {
+        let assembly = r#"
+	.text
+	.file	"default.sol:Test"
+	.globl	main
+	.type	main,@function
+main:                                   ; @main
+.func_begin0:
+; %bb.0:                                ; %entry
+	JUMPDEST
+	PUSH1 128
+	PUSH1 64
+	MSTORE
+	PUSH4 @.BB0_2
+	JUMPI
+; %bb.1:
+	STOP
+.BB0_2:                                 ; %if_join
+                                        ; =>This Inner Loop Header: Depth=1
+	JUMPDEST
+	PUSH0
+	STOP
+.Lfunc_end0:
+fun_f:                                  ; @fun_f
+; %bb.0:                                ; %entry
+	JUMPDEST
+	STOP
+"#;
+        let block_map = BlockMap::from_llvm_assembly(assembly);
+
+        assert_eq!(
+            block_map.entries,
+            vec![
+                Entry::new(0, "main".to_owned(), Some("entry".to_owned())),
+                Entry::new(12, "main".to_owned(), None),
+                Entry::new(13, "main".to_owned(), Some("if_join".to_owned())),
+                Entry::new(16, "fun_f".to_owned(), Some("entry".to_owned())),
+            ]
+        );
+    }
+
+    #[test]
+    fn entry() {
+        let block_map = BlockMap {
+            entries: vec![
+                Entry::new(0, "main".to_owned(), Some("entry".to_owned())),
+                Entry::new(13, "main".to_owned(), Some("if_join".to_owned())),
+            ],
+        };
+
+        assert_eq!(
+            block_map.entry(12).and_then(|entry| entry.block.as_deref()),
+            Some("entry")
+        );
+        assert_eq!(
+            block_map
+                .entry(100)
+                .and_then(|entry| entry.block.as_deref()),
+            Some("if_join")
+        );
+        assert!(block_map.entry_at(12).is_none());
+        assert!(block_map.entry_at(13).is_some());
+    }
+}
@@ -5,7 +5,7 @@
 ///
 /// CBOR payload.
 ///
-/// Used for encoding IPFS contract metadata hash.
+/// Used for encoding IPFS and Swarm contract metadata hashes.
 ///
 #[derive(Debug, Clone, PartialEq)]
 pub struct CBOR<'a, S>
@@ -50,8 +50,9 @@ where
         cbor.push(0xA0_u8 + (field_count as u8));
 
         if let Some((r#type, hash)) = self.hash.as_ref() {
-            cbor.push(0x64_u8);
-            cbor.extend(r#type.to_string().as_bytes());
+            let r#type = r#type.to_string();
+            cbor.push(0x60_u8 + (r#type.len() as u8));
+            cbor.extend(r#type.as_bytes());
             cbor.push(0x58_u8);
             cbor.push(hash.len() as u8);
             cbor.extend_from_slice(hash);
@@ -78,6 +79,7 @@ where
 mod tests {
     use crate::IPFSHash;
     use crate::MetadataHashType;
+    use crate::SwarmHash;
 
     #[test]
     #[should_panic(expected = "Version data cannot be empty")]
@@ -156,4 +158,21 @@ mod tests {
             "a264697066735822122060d9c5c201b8c2ed8ac8de1e21afc4ef115ad9f47863e21ffd29f272544b512564736f6c637821736f6c783a302e312e303b736f6c633a302e382e32393b6c6c766d3a312e302e320052"
         );
     }
+
+    #[test]
+    fn swarm_hash_and_solx_version_data() {
+        let hash_type = MetadataHashType::Swarm.to_string();
+        let swarm = SwarmHash::from_slice("LLVM is the Endgame".as_bytes());
+
+        let cbor = super::CBOR::new(
+            Some((hash_type.as_str(), swarm.as_bytes())),
+            "solc".to_owned(),
+            vec![("solx".to_owned(), semver::Version::new(0, 8, 29))],
+        );
+
+        assert_eq!(
+            hex::encode(cbor.to_vec()),
+            "a265627a7a723158201c4d41c298b19378f8e80df08c171d1aab3841dbe04accf3f8ac28f6f6d90c4264736f6c63780b736f6c783a302e382e3239003b"
+        );
+    }
 }
@@ -0,0 +1,75 @@
+//!
+//! The execution profile for profile-guided optimization.
+//!
+
+pub mod unit;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::code_segment::CodeSegment;
+
+use self::unit::Unit;
+
+///
+/// The execution profile for profile-guided optimization.
+///
+/// Contains the basic block execution counts of each contract and code segment, collected from
+/// execution traces of a previous build with the help of its block maps.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ExecutionProfile {
+    /// The translation unit profiles by full contract path and code segment.
+    pub contracts: BTreeMap<String, BTreeMap<CodeSegment, Unit>>,
+}
+
+impl ExecutionProfile {
+    ///
+    /// Reads the profile from a JSON file.
+    ///
+    pub fn try_from_path(path: &Path) -> anyhow::Result<Self> {
+        let data = std::fs::read(path)
+            .map_err(|error| anyhow::anyhow!("Execution profile {path:?} reading: {error}"))?;
+        crate::deserialize_from_slice(data.as_slice())
+            .map_err(|error| anyhow::anyhow!("Execution profile {path:?} parsing: {error}"))
+    }
+
+    ///
+    /// Returns the profile of the specified contract code segment.
+    ///
+    pub fn unit(&self, contract: &str, code_segment: CodeSegment) -> Option<&Unit> {
+        self.contracts.get(contract)?.get(&code_segment)
+    }
+
+    ///
+    /// Adds `count` executions of the basic block.
+    ///
+    pub fn record(
+        &mut self,
+        contract: &str,
+        code_segment: CodeSegment,
+        function: &str,
+        block: &str,
+        count: u64,
+    ) {
+        let count_total = self
+            .contracts
+            .entry(contract.to_owned())
+            .or_default()
+            .entry(code_segment)
+            .or_default()
+            .functions
+            .entry(function.to_owned())
+            .or_default()
+            .entry(block.to_owned())
+            .or_default();
+        *count_total = count_total.saturating_add(count);
+    }
+
+    ///
+    /// Whether the profile contains no counts.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.contracts.is_empty()
+    }
+}
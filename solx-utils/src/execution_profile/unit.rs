@@ -0,0 +1,102 @@
+//!
+//! The execution profile of a translation unit.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The execution profile of a translation unit.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Unit {
+    /// The basic block execution counts by LLVM function and LLVM IR basic block name.
+    pub functions: BTreeMap<String, BTreeMap<String, u64>>,
+}
+
+impl Unit {
+    /// The profile summary cutoffs in millionths of the total count, as used by LLVM.
+    pub const SUMMARY_CUTOFFS: [u32; 16] = [
+        10000, 100000, 200000, 300000, 400000, 500000, 600000, 700000, 800000, 900000, 950000,
+        990000, 999000, 999900, 999990, 999999,
+    ];
+
+    ///
+    /// Returns the execution count of the basic block.
+    ///
+    pub fn count(&self, function: &str, block: &str) -> Option<u64> {
+        self.functions.get(function)?.get(block).copied()
+    }
+
+    ///
+    /// Returns the execution counts of all basic blocks.
+    ///
+    pub fn counts(&self) -> impl Iterator<Item = u64> + '_ {
+        self.functions
+            .values()
+            .flat_map(|blocks| blocks.values().copied())
+    }
+
+    ///
+    /// Returns the detailed profile summary as `(cutoff, minimal count, number of counts)` tuples.
+    ///
+    /// For each cutoff, the minimal count is the smallest one among the hottest blocks that
+    /// together make up the cutoff share of the total count.
+    ///
+    pub fn detailed_summary(&self) -> Vec<(u32, u64, u32)> {
+        let mut counts = self
+            .counts()
+            .filter(|count| *count > 0)
+            .collect::<Vec<u64>>();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        let total = counts.iter().map(|count| *count as u128).sum::<u128>();
+        if total == 0 {
+            return vec![];
+        }
+
+        let mut summary = Vec::with_capacity(Self::SUMMARY_CUTOFFS.len());
+        let mut accumulated = 0;
+        let mut index = 0;
+        for cutoff in Self::SUMMARY_CUTOFFS.into_iter() {
+            let desired = (total * cutoff as u128).div_ceil(1_000_000);
+            while accumulated < desired && index < counts.len() {
+                accumulated += counts[index] as u128;
+                index += 1;
+            }
+            summary.push((cutoff, counts[index - 1], index as u32));
+        }
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::Unit;
+
+    #[test]
+    fn detailed_summary() {
+        let unit = Unit {
+            functions: BTreeMap::from([(
+                "main".to_owned(),
+                BTreeMap::from([
+                    ("entry".to_owned(), 1),
+                    ("for_body".to_owned(), 98),
+                    ("if_join".to_owned(), 1),
+                ]),
+            )]),
+        };
+
+        let summary = unit.detailed_summary();
+        assert_eq!(summary.first(), Some(&(10000, 98, 1)));
+        assert_eq!(summary[8], (800000, 98, 1));
+        assert_eq!(summary[10], (950000, 98, 1));
+        assert_eq!(summary[11], (990000, 1, 2));
+        assert_eq!(summary.last(), Some(&(999999, 1, 3)));
+    }
+
+    #[test]
+    fn detailed_summary_empty() {
+        assert!(Unit::default().detailed_summary().is_empty());
+    }
+}
@@ -20,6 +20,9 @@ pub static EXTENSION_EVMLA: &str = "evmla";
 /// The Ethereal IR file extension.
 pub static EXTENSION_ETHIR: &str = "ethir";
 
+/// The Graphviz DOT file extension.
+pub static EXTENSION_DOT: &str = "dot";
+
 /// The EVM file extension.
 pub static EXTENSION_EVM: &str = "evm";
 
@@ -0,0 +1,157 @@
+//!
+//! Swarm `bzzr1` hash utilities.
+//!
+
+use sha3::Digest;
+
+///
+/// Swarm `bzzr1` hash utilities.
+///
+/// Implements the binary Merkle tree chunk hash used by `solc` for the `bzzr1` metadata hash.
+///
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Swarm {
+    /// Binary representation.
+    bytes: [u8; crate::BYTE_LENGTH_FIELD],
+    /// Hexadecimal string representation.
+    string: String,
+}
+
+impl Swarm {
+    /// The Swarm chunk size in bytes.
+    pub const CHUNK_SIZE: usize = 4096;
+
+    /// The number of hashes that fit into a single chunk.
+    pub const CHUNK_BRANCHES: usize = Self::CHUNK_SIZE / crate::BYTE_LENGTH_FIELD;
+
+    /// The size of a binary Merkle tree segment pair, which is hashed directly.
+    pub const SEGMENT_PAIR_SIZE: usize = 2 * crate::BYTE_LENGTH_FIELD;
+
+    ///
+    /// Computes the Swarm `bzzr1` hash for `preimage`.
+    ///
+    pub fn from_slice(preimage: &[u8]) -> Self {
+        let bytes = Self::chunk_hash(preimage, false);
+        let string = hex::encode(bytes);
+        Self { bytes, string }
+    }
+
+    ///
+    /// Returns a reference to the 32-byte Swarm hash.
+    ///
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    ///
+    /// Returns a reference to the hexadecimal string representation of the Swarm hash.
+    ///
+    pub fn as_str(&self) -> &str {
+        self.string.as_str()
+    }
+
+    ///
+    /// Extracts the binary representation.
+    ///
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.bytes.to_vec()
+    }
+
+    ///
+    /// Computes the hash of a chunk, which is either the data itself, or the concatenated
+    /// hashes of its subtrees if the data does not fit into a single chunk.
+    ///
+    /// The chunk is padded with zeros, hashed with the binary Merkle tree hash, and prefixed
+    /// with the little-endian 64-bit size of the data it represents.
+    ///
+    fn chunk_hash(data: &[u8], force_higher_level: bool) -> [u8; crate::BYTE_LENGTH_FIELD] {
+        let mut chunk = if data.len() < Self::CHUNK_SIZE
+            || (data.len() == Self::CHUNK_SIZE && !force_higher_level)
+        {
+            data.to_vec()
+        } else {
+            let mut max_represented_size = Self::CHUNK_SIZE;
+            while max_represented_size * Self::CHUNK_BRANCHES < data.len() {
+                max_represented_size *= Self::CHUNK_BRANCHES;
+            }
+            let force_higher_level = max_represented_size > Self::CHUNK_SIZE;
+
+            let mut hashes = Vec::with_capacity(Self::CHUNK_SIZE);
+            for subtree in data.chunks(max_represented_size) {
+                hashes.extend(Self::chunk_hash(subtree, force_higher_level));
+            }
+            hashes
+        };
+        chunk.resize(Self::CHUNK_SIZE, 0);
+
+        let mut hasher = sha3::Keccak256::new();
+        hasher.update((data.len() as u64).to_le_bytes());
+        hasher.update(Self::bmt_hash(chunk.as_slice()));
+        hasher.finalize().into()
+    }
+
+    ///
+    /// Computes the binary Merkle tree hash of a padded chunk.
+    ///
+    fn bmt_hash(data: &[u8]) -> [u8; crate::BYTE_LENGTH_FIELD] {
+        if data.len() <= Self::SEGMENT_PAIR_SIZE {
+            return sha3::Keccak256::digest(data).into();
+        }
+
+        let (left, right) = data.split_at(data.len() / 2);
+        let mut hasher = sha3::Keccak256::new();
+        hasher.update(Self::bmt_hash(left));
+        hasher.update(Self::bmt_hash(right));
+        hasher.finalize().into()
+    }
+}
+
+impl std::fmt::Display for Swarm {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn empty() {
+        assert_eq!(
+            super::Swarm::from_slice(&[]).as_str(),
+            "b34ca8c22b9e982354f9c7f50b470d66db428d880c8a904d5fe4ec9713171526"
+        );
+    }
+
+    #[test]
+    fn short() {
+        assert_eq!(
+            super::Swarm::from_slice("LLVM is the Endgame".as_bytes()).as_str(),
+            "1c4d41c298b19378f8e80df08c171d1aab3841dbe04accf3f8ac28f6f6d90c42"
+        );
+    }
+
+    #[test]
+    fn single_chunk() {
+        assert_eq!(
+            super::Swarm::from_slice(vec![0; super::Swarm::CHUNK_SIZE].as_slice()).as_str(),
+            "09ae927d0f3aaa37324df178928d3826820f3dd3388ce4aaebfc3af410bde23a"
+        );
+    }
+
+    #[test]
+    fn multiple_chunks() {
+        let preimage = (0..=u8::MAX).cycle().take(5120).collect::<Vec<u8>>();
+        assert_eq!(
+            super::Swarm::from_slice(preimage.as_slice()).as_str(),
+            "2c0d62d4be20e8d6ceb7e01bef18186e704fe149c48f45c1baa589eb72f8e803"
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            super::Swarm::from_slice("LLVM is the Endgame".as_bytes()).to_string(),
+            "1c4d41c298b19378f8e80df08c171d1aab3841dbe04accf3f8ac28f6f6d90c42"
+        );
+    }
+}
@@ -4,6 +4,7 @@
 
 pub(crate) mod base;
 pub(crate) mod bit_length;
+pub(crate) mod block_map;
 pub(crate) mod byte_length;
 pub(crate) mod cbor;
 pub(crate) mod code_segment;
@@ -11,17 +12,23 @@ pub(crate) mod contract_name;
 pub(crate) mod deserializer;
 pub(crate) mod error;
 pub(crate) mod evm_version;
+pub(crate) mod execution_profile;
 pub(crate) mod exit_code;
 pub(crate) mod extension;
 pub(crate) mod hash;
 pub(crate) mod libraries;
 pub(crate) mod llvm_ir;
+pub(crate) mod llvm_remark;
 pub(crate) mod logging;
 pub(crate) mod metadata_hash_type;
+pub(crate) mod optimization_objective;
+pub(crate) mod static_gas;
 pub(crate) mod target;
 
 pub use self::base::*;
 pub use self::bit_length::*;
+pub use self::block_map::entry::Entry as BlockMapEntry;
+pub use self::block_map::BlockMap;
 pub use self::byte_length::*;
 pub use self::cbor::CBOR;
 pub use self::code_segment::CodeSegment;
@@ -29,13 +36,21 @@ pub use self::contract_name::ContractName;
 pub use self::deserializer::*;
 pub use self::error::*;
 pub use self::evm_version::EVMVersion;
+pub use self::execution_profile::unit::Unit as ExecutionProfileUnit;
+pub use self::execution_profile::ExecutionProfile;
 pub use self::exit_code::*;
 pub use self::extension::*;
 pub use self::hash::ipfs::IPFS as IPFSHash;
 pub use self::hash::keccak256::Keccak256 as Keccak256Hash;
+pub use self::hash::swarm::Swarm as SwarmHash;
 pub use self::hash::Hash;
 pub use self::libraries::Libraries;
 pub use self::llvm_ir::*;
+pub use self::llvm_remark::kind::Kind as LLVMRemarkKind;
+pub use self::llvm_remark::location::Location as LLVMRemarkLocation;
+pub use self::llvm_remark::LLVMRemark;
 pub use self::logging::*;
 pub use self::metadata_hash_type::MetadataHashType;
+pub use self::optimization_objective::OptimizationObjective;
+pub use self::static_gas::*;
 pub use self::target::Target;
@@ -0,0 +1,55 @@
+//!
+//! The LLVM optimization remark kind.
+//!
+
+use std::str::FromStr;
+
+///
+/// The LLVM optimization remark kind.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Kind {
+    /// The transformation has been applied.
+    Passed,
+    /// The transformation has been rejected.
+    Missed,
+    /// The analysis result explaining a decision.
+    Analysis,
+}
+
+impl Kind {
+    ///
+    /// Returns the LLVM option enabling the remarks of this kind for the passes matching the regex.
+    ///
+    pub fn llvm_option(&self, regex: &str) -> String {
+        match self {
+            Self::Passed => format!("-pass-remarks={regex}"),
+            Self::Missed => format!("-pass-remarks-missed={regex}"),
+            Self::Analysis => format!("-pass-remarks-analysis={regex}"),
+        }
+    }
+}
+
+impl FromStr for Kind {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "passed" => Ok(Self::Passed),
+            "missed" => Ok(Self::Missed),
+            "analysis" => Ok(Self::Analysis),
+            string => anyhow::bail!("unknown LLVM remark kind: `{string}`"),
+        }
+    }
+}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Passed => write!(f, "passed"),
+            Self::Missed => write!(f, "missed"),
+            Self::Analysis => write!(f, "analysis"),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//!
+//! The LLVM optimization remark source code location.
+//!
+
+///
+/// The LLVM optimization remark source code location.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Location {
+    /// The source file path.
+    pub file: String,
+    /// The line number, starting from 1.
+    pub line: u32,
+    /// The column number, starting from 1.
+    pub column: u32,
+}
+
+impl Location {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(file: String, line: u32, column: u32) -> Self {
+        Self { file, line, column }
+    }
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
@@ -0,0 +1,66 @@
+//!
+//! The LLVM optimization remark.
+//!
+
+pub mod kind;
+pub mod location;
+
+use self::kind::Kind;
+use self::location::Location;
+
+///
+/// The LLVM optimization remark.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LLVMRemark {
+    /// The remark kind.
+    pub kind: Kind,
+    /// The name of the pass that has emitted the remark, e.g. `inline`.
+    pub pass: String,
+    /// The remark identifier within the pass, e.g. `NotInlined`.
+    pub name: String,
+    /// The LLVM function the remark refers to.
+    pub function: String,
+    /// The remark message.
+    pub message: String,
+    /// The source code location, if the debug information is available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+}
+
+impl LLVMRemark {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        kind: Kind,
+        pass: String,
+        name: String,
+        function: String,
+        message: String,
+        location: Option<Location>,
+    ) -> Self {
+        Self {
+            kind,
+            pass,
+            name,
+            function,
+            message,
+            location,
+        }
+    }
+}
+
+impl std::fmt::Display for LLVMRemark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(ref location) = self.location {
+            write!(f, "{location}: ")?;
+        }
+        write!(
+            f,
+            "[{}] {} in `{}`: {}",
+            self.kind, self.pass, self.function, self.message
+        )
+    }
+}
@@ -0,0 +1,44 @@
+//!
+//! Objective of the optimization mode auto-tuning.
+//!
+
+use std::str::FromStr;
+
+///
+/// Objective of the optimization mode auto-tuning.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum OptimizationObjective {
+    /// The minimal runtime code size.
+    #[serde(rename = "runtime-size")]
+    RuntimeSize,
+    /// The minimal deploy code size, including the runtime code.
+    #[serde(rename = "deploy-size")]
+    DeploySize,
+    /// The minimal static gas estimate of the runtime code.
+    #[serde(rename = "gas")]
+    Gas,
+}
+
+impl FromStr for OptimizationObjective {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "runtime-size" => Ok(Self::RuntimeSize),
+            "deploy-size" => Ok(Self::DeploySize),
+            "gas" => Ok(Self::Gas),
+            string => anyhow::bail!("unknown optimization objective: `{string}`"),
+        }
+    }
+}
+
+impl std::fmt::Display for OptimizationObjective {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::RuntimeSize => write!(f, "runtime-size"),
+            Self::DeploySize => write!(f, "deploy-size"),
+            Self::Gas => write!(f, "gas"),
+        }
+    }
+}
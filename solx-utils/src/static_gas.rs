@@ -0,0 +1,196 @@
+//!
+//! Static gas estimate of EVM bytecode.
+//!
+
+/// The weight of an instruction per enclosing loop, which is the assumed number of iterations.
+const LOOP_WEIGHT: u64 = 10;
+
+/// The `JUMP` instruction.
+const OPCODE_JUMP: u8 = 0x56;
+
+/// The `JUMPI` instruction.
+const OPCODE_JUMPI: u8 = 0x57;
+
+/// The `JUMPDEST` instruction.
+const OPCODE_JUMPDEST: u8 = 0x5b;
+
+///
+/// Returns the static gas estimate of `bytecode`.
+///
+/// Every instruction is weighted by its base gas cost, with state access instructions assumed
+/// to be warm, and by `LOOP_WEIGHT` for each loop it is enclosed in. Dynamic costs such as memory
+/// expansion are not taken into account.
+///
+pub fn static_gas(bytecode: &[u8]) -> u64 {
+    let instructions = instructions(bytecode);
+    let loops = loops(bytecode, instructions.as_slice());
+
+    instructions
+        .iter()
+        .map(|(offset, opcode, _)| {
+            let depth = loops
+                .iter()
+                .filter(|(start, end)| (start..=end).contains(&offset))
+                .count() as u32;
+            opcode_base_gas(*opcode).saturating_mul(LOOP_WEIGHT.saturating_pow(depth))
+        })
+        .fold(0, u64::saturating_add)
+}
+
+///
+/// Returns the offsets, opcodes, and the immediate values of `PUSH` instructions of `bytecode`.
+///
+/// The immediate values which do not fit into `usize` are omitted, as they cannot be jump targets.
+///
+fn instructions(bytecode: &[u8]) -> Vec<(usize, u8, Option<usize>)> {
+    let mut instructions = Vec::new();
+    let mut offset = 0;
+    while let Some(opcode) = bytecode.get(offset).copied() {
+        let size = if (0x60..=0x7f).contains(&opcode) {
+            (opcode - 0x5f) as usize
+        } else {
+            0
+        };
+        let bytes = bytecode
+            .get(offset + 1..offset + 1 + size)
+            .unwrap_or(&bytecode[offset + 1..]);
+        let immediate = (size > 0 && size <= std::mem::size_of::<usize>()).then(|| {
+            bytes
+                .iter()
+                .fold(0, |value, byte| (value << 8) | *byte as usize)
+        });
+        instructions.push((offset, opcode, immediate));
+        offset += 1 + size;
+    }
+    instructions
+}
+
+///
+/// Returns the offset ranges of loops, which are detected by backward jumps to constant targets.
+///
+/// Unconditional jumps whose basic block pushes the address right after the jump are function
+/// calls, so they are not considered loops.
+///
+fn loops(bytecode: &[u8], instructions: &[(usize, u8, Option<usize>)]) -> Vec<(usize, usize)> {
+    let mut loops = Vec::new();
+    let mut block_immediates = Vec::new();
+    let mut previous_immediate = None;
+    for (offset, opcode, immediate) in instructions.iter().copied() {
+        match opcode {
+            OPCODE_JUMPDEST => block_immediates.clear(),
+            OPCODE_JUMP | OPCODE_JUMPI => {
+                if let Some(target) = previous_immediate {
+                    let is_call = opcode == OPCODE_JUMP && block_immediates.contains(&(offset + 1));
+                    if target < offset
+                        && bytecode.get(target).copied() == Some(OPCODE_JUMPDEST)
+                        && !is_call
+                    {
+                        loops.push((target, offset));
+                    }
+                }
+            }
+            _ => {}
+        }
+        if let Some(immediate) = immediate {
+            block_immediates.push(immediate);
+        }
+        previous_immediate = immediate;
+    }
+    loops
+}
+
+///
+/// Returns the base gas cost of the EVM instruction `opcode`.
+///
+/// Undefined instructions are not charged.
+///
+fn opcode_base_gas(opcode: u8) -> u64 {
+    match opcode {
+        // STOP, RETURN, REVERT, INVALID
+        0x00 | 0xf3 | 0xfd | 0xfe => 0,
+        // JUMPDEST
+        0x5b => 1,
+        // ADDRESS, ORIGIN, CALLER, CALLVALUE, CALLDATASIZE, CODESIZE, GASPRICE, RETURNDATASIZE
+        0x30 | 0x32..=0x34 | 0x36 | 0x38 | 0x3a | 0x3d => 2,
+        // Block information except BLOCKHASH, SELFBALANCE, and BLOBHASH
+        0x41..=0x46 | 0x48 | 0x4a => 2,
+        // POP, PC, MSIZE, GAS, PUSH0
+        0x50 | 0x58..=0x5a | 0x5f => 2,
+        // ADD, SUB, comparison and bitwise operations
+        0x01 | 0x03 | 0x10..=0x1d => 3,
+        // CALLDATALOAD, CALLDATACOPY, CODECOPY, RETURNDATACOPY, BLOBHASH
+        0x35 | 0x37 | 0x39 | 0x3e | 0x49 => 3,
+        // MLOAD, MSTORE, MSTORE8, MCOPY
+        0x51..=0x53 | 0x5e => 3,
+        // PUSH1..PUSH32, DUP1..DUP16, SWAP1..SWAP16
+        0x60..=0x9f => 3,
+        // MUL, DIV, SDIV, MOD, SMOD, SIGNEXTEND, SELFBALANCE
+        0x02 | 0x04..=0x07 | 0x0b | 0x47 => 5,
+        // ADDMOD, MULMOD, JUMP
+        0x08 | 0x09 | 0x56 => 8,
+        // EXP, JUMPI
+        0x0a | 0x57 => 10,
+        // BLOCKHASH
+        0x40 => 20,
+        // KECCAK256
+        0x20 => 30,
+        // BALANCE, EXTCODESIZE, EXTCODECOPY, EXTCODEHASH, SLOAD, SSTORE, TLOAD, TSTORE
+        0x31 | 0x3b | 0x3c | 0x3f | 0x54 | 0x55 | 0x5c | 0x5d => 100,
+        // CALL, CALLCODE, DELEGATECALL, STATICCALL
+        0xf1 | 0xf2 | 0xf4 | 0xfa => 100,
+        // LOG0..LOG4
+        0xa0..=0xa4 => 375 * (1 + (opcode - 0xa0) as u64),
+        // SELFDESTRUCT
+        0xff => 5000,
+        // CREATE, CREATE2
+        0xf0 | 0xf5 => 32000,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn empty() {
+        assert_eq!(super::static_gas(&[]), 0);
+    }
+
+    #[test]
+    fn arithmetic() {
+        // PUSH1 0x01, PUSH1 0x02, ADD, PUSH1 0x03, MUL, STOP
+        let bytecode = [0x60, 0x01, 0x60, 0x02, 0x01, 0x60, 0x03, 0x02, 0x00];
+        assert_eq!(super::static_gas(&bytecode), 3 + 3 + 3 + 3 + 5);
+    }
+
+    #[test]
+    fn push_immediates_skipped() {
+        // PUSH2 0x5b5b, PUSH32 with SLOAD opcodes as immediates
+        let mut bytecode = vec![0x61, 0x5b, 0x5b, 0x7f];
+        bytecode.extend([0x54; 32]);
+        assert_eq!(super::static_gas(bytecode.as_slice()), 3 + 3);
+    }
+
+    #[test]
+    fn storage_and_logs() {
+        // SLOAD, SSTORE, LOG2
+        let bytecode = [0x54, 0x55, 0xa2];
+        assert_eq!(super::static_gas(&bytecode), 100 + 100 + 1125);
+    }
+
+    #[test]
+    fn loop_weighted() {
+        // JUMPDEST, PUSH1 0x01, PUSH1 0x00, JUMPI, STOP
+        let bytecode = [0x5b, 0x60, 0x01, 0x60, 0x00, 0x57, 0x00];
+        assert_eq!(
+            super::static_gas(&bytecode),
+            (1 + 3 + 3 + 10) * super::LOOP_WEIGHT
+        );
+    }
+
+    #[test]
+    fn call_not_weighted() {
+        // JUMPDEST, JUMP, PUSH1 0x07, PUSH1 0x00, JUMP, JUMPDEST, STOP
+        let bytecode = [0x5b, 0x56, 0x60, 0x07, 0x60, 0x00, 0x56, 0x5b, 0x00];
+        assert_eq!(super::static_gas(&bytecode), 1 + 8 + 3 + 3 + 8 + 1);
+    }
+}
@@ -0,0 +1,156 @@
+//!
+//! The Yul IR semantic analysis error.
+//!
+
+use crate::yul::lexer::token::location::Location;
+
+///
+/// The Yul IR semantic analysis error.
+///
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum Error {
+    /// A builtin function name cannot be used as an identifier.
+    #[error("{location} The identifier `{identifier}` is reserved for a builtin function")]
+    ReservedIdentifier {
+        /// The invalid identifier location.
+        location: Location,
+        /// The invalid identifier.
+        identifier: String,
+    },
+    /// Identifiers cannot be redeclared or shadowed.
+    #[error(
+        "{location} The identifier `{identifier}` is already declared in this or an enclosing scope"
+    )]
+    AlreadyDeclared {
+        /// The invalid identifier location.
+        location: Location,
+        /// The invalid identifier.
+        identifier: String,
+    },
+    /// The variable is not declared.
+    #[error("{location} Variable `{identifier}` is not declared")]
+    UndeclaredVariable {
+        /// The invalid identifier location.
+        location: Location,
+        /// The invalid identifier.
+        identifier: String,
+    },
+    /// The variable is declared outside of the current function.
+    #[error("{location} Variable `{identifier}` is declared outside of the current function")]
+    InaccessibleVariable {
+        /// The invalid identifier location.
+        location: Location,
+        /// The invalid identifier.
+        identifier: String,
+    },
+    /// The function is not defined.
+    #[error("{location} Function `{identifier}` is not defined")]
+    UndefinedFunction {
+        /// The invalid identifier location.
+        location: Location,
+        /// The invalid identifier.
+        identifier: String,
+    },
+    /// A function is referenced as a variable.
+    #[error("{location} Function `{identifier}` cannot be used as a variable")]
+    FunctionAsVariable {
+        /// The invalid identifier location.
+        location: Location,
+        /// The invalid identifier.
+        identifier: String,
+    },
+    /// A variable is called as a function.
+    #[error("{location} Variable `{identifier}` cannot be called")]
+    VariableAsFunction {
+        /// The invalid identifier location.
+        location: Location,
+        /// The invalid identifier.
+        identifier: String,
+    },
+    /// Invalid number of function arguments.
+    #[error("{location} Function `{identifier}` must have {expected} arguments, found {found}")]
+    InvalidNumberOfArguments {
+        /// The invalid function call location.
+        location: Location,
+        /// The invalid function name.
+        identifier: String,
+        /// The expected number of arguments.
+        expected: usize,
+        /// The actual number of arguments.
+        found: usize,
+    },
+    /// Invalid number of values produced by an expression.
+    #[error("{location} Expected {expected} values, found {found}")]
+    InvalidNumberOfValues {
+        /// The invalid expression location.
+        location: Location,
+        /// The expected number of values.
+        expected: usize,
+        /// The actual number of values.
+        found: usize,
+    },
+    /// The builtin function argument must be a string literal.
+    #[error("{location} Argument #{index} of function `{identifier}` must be a string literal")]
+    LiteralArgumentExpected {
+        /// The invalid function call location.
+        location: Location,
+        /// The function name.
+        identifier: String,
+        /// The argument index, starting from 1.
+        index: usize,
+    },
+    /// The object or data section referenced by `dataoffset` or `datasize` is not found.
+    #[error("{location} Object or data `{identifier}` is not found")]
+    UnknownObject {
+        /// The invalid function call location.
+        location: Location,
+        /// The object or data identifier.
+        identifier: String,
+    },
+    /// The literal does not fit into a 256-bit word.
+    #[error("{location} Literal `{literal}` does not fit into 32 bytes")]
+    LiteralTooLarge {
+        /// The invalid literal location.
+        location: Location,
+        /// The invalid literal.
+        literal: String,
+    },
+    /// `break` or `continue` outside of a `for` loop body.
+    #[error("{location} `{keyword}` is only allowed in a `for` loop body")]
+    OutsideOfLoop {
+        /// The invalid statement location.
+        location: Location,
+        /// The statement keyword.
+        keyword: &'static str,
+    },
+    /// `leave` outside of a function body.
+    #[error("{location} `leave` is only allowed in a function body")]
+    OutsideOfFunction {
+        /// The invalid statement location.
+        location: Location,
+    },
+}
+
+impl Error {
+    ///
+    /// Returns the error location.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::ReservedIdentifier { location, .. } => *location,
+            Self::AlreadyDeclared { location, .. } => *location,
+            Self::UndeclaredVariable { location, .. } => *location,
+            Self::InaccessibleVariable { location, .. } => *location,
+            Self::UndefinedFunction { location, .. } => *location,
+            Self::FunctionAsVariable { location, .. } => *location,
+            Self::VariableAsFunction { location, .. } => *location,
+            Self::InvalidNumberOfArguments { location, .. } => *location,
+            Self::InvalidNumberOfValues { location, .. } => *location,
+            Self::LiteralArgumentExpected { location, .. } => *location,
+            Self::UnknownObject { location, .. } => *location,
+            Self::LiteralTooLarge { location, .. } => *location,
+            Self::OutsideOfLoop { location, .. } => *location,
+            Self::OutsideOfFunction { location, .. } => *location,
+        }
+    }
+}
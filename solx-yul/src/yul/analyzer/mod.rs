@@ -0,0 +1,524 @@
+//!
+//! The Yul IR semantic analyzer.
+//!
+
+pub mod error;
+pub mod scope;
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::BTreeSet;
+
+use crate::yul::lexer::token::lexeme::literal::integer::Integer as IntegerLiteral;
+use crate::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::dialect::Dialect;
+use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::if_conditional::IfConditional;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::switch::Switch;
+use crate::yul::parser::statement::variable_declaration::VariableDeclaration;
+use crate::yul::parser::statement::Statement;
+use crate::yul::visitor::Visitor;
+
+use self::error::Error;
+use self::scope::Scope;
+
+///
+/// The Yul IR semantic analyzer.
+///
+/// Checks the scoping rules, function and builtin arities, the placement of `break`, `continue`,
+/// and `leave`, literal sizes, and object names passed to `dataoffset` and `datasize`.
+/// Unlike the parser, does not stop at the first error.
+///
+#[derive(Debug, Default)]
+pub struct Analyzer {
+    /// The errors found so far.
+    errors: Vec<Error>,
+    /// The scope stack.
+    scopes: Vec<Scope>,
+    /// The object and data names accessible from the current object.
+    objects: BTreeSet<String>,
+    /// The object and data names of the whole object tree, used to resolve dotted paths.
+    objects_all: BTreeSet<String>,
+    /// The factory dependencies of the parent object, which have been moved there by the parser.
+    objects_inherited: BTreeSet<String>,
+    /// Whether the current statement is inside a `for` loop body.
+    is_in_loop_body: bool,
+    /// Whether the current statement is inside a function body.
+    is_in_function: bool,
+    /// The number of values produced by the last expression, or `None` if it is unknown.
+    values: Option<usize>,
+}
+
+impl Analyzer {
+    /// The maximum literal size in bytes.
+    pub const LITERAL_SIZE_LIMIT: usize = solx_utils::BYTE_LENGTH_FIELD;
+
+    /// The maximum decimal literal value, which is `2^256 - 1`.
+    pub const DECIMAL_LITERAL_MAX: &'static str =
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+
+    ///
+    /// Analyzes the object with its inner objects, and returns all errors found.
+    ///
+    pub fn analyze<P>(object: &Object<P>) -> Result<(), Vec<Error>>
+    where
+        P: Dialect,
+    {
+        let mut analyzer = Self::default();
+        analyzer.collect_object_names(object);
+        analyzer.visit_object(object);
+
+        if analyzer.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(analyzer.errors)
+        }
+    }
+
+    ///
+    /// Collects the object and data names of the whole object tree.
+    ///
+    fn collect_object_names<P>(&mut self, object: &Object<P>)
+    where
+        P: Dialect,
+    {
+        self.objects_all.insert(object.identifier.to_owned());
+        self.objects_all
+            .extend(object.factory_dependencies.iter().cloned());
+        self.objects_all.extend(object.data.iter().cloned());
+        if let Some(inner_object) = object.inner_object.as_ref() {
+            self.collect_object_names(inner_object);
+        }
+    }
+
+    ///
+    /// Analyzes the statements in the current scope, hoisting the function definitions.
+    ///
+    fn visit_statements<P>(&mut self, statements: &[Statement<P>])
+    where
+        P: Dialect,
+    {
+        for statement in statements.iter() {
+            if let Statement::FunctionDefinition(function_definition) = statement {
+                if self.check_declarable(
+                    function_definition.location,
+                    function_definition.identifier.as_str(),
+                ) {
+                    self.scopes
+                        .last_mut()
+                        .expect("Always exists")
+                        .functions
+                        .insert(
+                            function_definition.identifier.to_owned(),
+                            (
+                                function_definition.arguments.len(),
+                                function_definition.result.len(),
+                            ),
+                        );
+                }
+            }
+        }
+        for statement in statements.iter() {
+            self.visit_statement(statement);
+        }
+    }
+
+    ///
+    /// Declares the variable in the current scope.
+    ///
+    fn declare_variable(&mut self, identifier: &Identifier) {
+        if self.check_declarable(identifier.location, identifier.inner.as_str()) {
+            self.scopes
+                .last_mut()
+                .expect("Always exists")
+                .variables
+                .insert(identifier.inner.to_owned());
+        }
+    }
+
+    ///
+    /// Checks whether the identifier can be declared in the current scope.
+    ///
+    /// Yul forbids shadowing, so the identifiers of all enclosing scopes are checked, including
+    /// the ones outside of the current function.
+    ///
+    fn check_declarable(&mut self, location: Location, identifier: &str) -> bool {
+        if Name::from(identifier).arity().is_some() || Self::verbatim_arity(identifier).is_some() {
+            self.errors.push(Error::ReservedIdentifier {
+                location,
+                identifier: identifier.to_owned(),
+            });
+            return false;
+        }
+        if self.scopes.iter().any(|scope| scope.contains(identifier)) {
+            self.errors.push(Error::AlreadyDeclared {
+                location,
+                identifier: identifier.to_owned(),
+            });
+            return false;
+        }
+        true
+    }
+
+    ///
+    /// Checks that the variable is declared and accessible.
+    ///
+    fn check_variable(&mut self, location: Location, identifier: &str) {
+        let mut is_accessible = true;
+        for scope in self.scopes.iter().rev() {
+            if scope.variables.contains(identifier) {
+                if !is_accessible {
+                    self.errors.push(Error::InaccessibleVariable {
+                        location,
+                        identifier: identifier.to_owned(),
+                    });
+                }
+                return;
+            }
+            if scope.functions.contains_key(identifier) {
+                self.errors.push(Error::FunctionAsVariable {
+                    location,
+                    identifier: identifier.to_owned(),
+                });
+                return;
+            }
+            if scope.is_function {
+                is_accessible = false;
+            }
+        }
+        self.errors.push(Error::UndeclaredVariable {
+            location,
+            identifier: identifier.to_owned(),
+        });
+    }
+
+    ///
+    /// Returns the numbers of arguments and results of the user-defined function.
+    ///
+    fn find_function(&mut self, location: Location, identifier: &str) -> Option<(usize, usize)> {
+        if let Some(arity) = self
+            .scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.functions.get(identifier))
+        {
+            return Some(*arity);
+        }
+
+        if self
+            .scopes
+            .iter()
+            .any(|scope| scope.variables.contains(identifier))
+        {
+            self.errors.push(Error::VariableAsFunction {
+                location,
+                identifier: identifier.to_owned(),
+            });
+        } else {
+            self.errors.push(Error::UndefinedFunction {
+                location,
+                identifier: identifier.to_owned(),
+            });
+        }
+        None
+    }
+
+    ///
+    /// Checks that the object or data section referenced by `dataoffset` or `datasize` exists.
+    ///
+    fn check_object(&mut self, location: Location, path: &str) {
+        let is_found = self.objects.contains(path)
+            || (path.contains('.')
+                && path
+                    .split('.')
+                    .all(|identifier| self.objects_all.contains(identifier)));
+        if !is_found {
+            self.errors.push(Error::UnknownObject {
+                location,
+                identifier: path.to_owned(),
+            });
+        }
+    }
+
+    ///
+    /// Checks that the literal value fits into a 256-bit word.
+    ///
+    fn check_literal(&mut self, literal: &Literal) {
+        let is_too_large = match literal.inner {
+            LexicalLiteral::Boolean(_) => false,
+            LexicalLiteral::Integer(IntegerLiteral::Decimal { ref inner }) => {
+                let digits = inner.trim_start_matches('0');
+                digits.len() > Self::DECIMAL_LITERAL_MAX.len()
+                    || (digits.len() == Self::DECIMAL_LITERAL_MAX.len()
+                        && digits > Self::DECIMAL_LITERAL_MAX)
+            }
+            LexicalLiteral::Integer(IntegerLiteral::Hexadecimal { ref inner }) => {
+                let digits = inner.trim_start_matches("0x").trim_start_matches('0');
+                digits.len() > Self::LITERAL_SIZE_LIMIT * 2
+            }
+            LexicalLiteral::String(ref string) => {
+                string.to_bytes().len() > Self::LITERAL_SIZE_LIMIT
+            }
+        };
+        if is_too_large {
+            self.errors.push(Error::LiteralTooLarge {
+                location: literal.location,
+                literal: literal.inner.to_string(),
+            });
+        }
+    }
+
+    ///
+    /// Checks the number of values produced by the last expression.
+    ///
+    fn check_values(&mut self, location: Location, expected: usize) {
+        if let Some(found) = self.values {
+            if found != expected {
+                self.errors.push(Error::InvalidNumberOfValues {
+                    location,
+                    expected,
+                    found,
+                });
+            }
+        }
+    }
+
+    ///
+    /// Returns the numbers of arguments and results of a `verbatim_<N>i_<M>o` builtin.
+    ///
+    /// The first argument is the bytecode literal.
+    ///
+    fn verbatim_arity(identifier: &str) -> Option<(usize, usize)> {
+        let (input_size, output_size) = identifier
+            .strip_prefix("verbatim_")?
+            .strip_suffix('o')?
+            .split_once("i_")?;
+        Some((
+            input_size.parse::<usize>().ok()? + 1,
+            output_size.parse::<usize>().ok()?,
+        ))
+    }
+}
+
+impl<P> Visitor<P> for Analyzer
+where
+    P: Dialect,
+{
+    fn visit_object(&mut self, object: &Object<P>) {
+        let mut objects = std::mem::take(&mut self.objects_inherited);
+        objects.insert(object.identifier.to_owned());
+        objects.extend(object.factory_dependencies.iter().cloned());
+        objects.extend(object.data.iter().cloned());
+        if let Some(inner_object) = object.inner_object.as_ref() {
+            objects.insert(inner_object.identifier.to_owned());
+        }
+        self.objects = objects;
+
+        self.visit_code(&object.code);
+
+        if let Some(inner_object) = object.inner_object.as_ref() {
+            self.objects_inherited = object.factory_dependencies.iter().cloned().collect();
+            self.visit_object(inner_object);
+        }
+    }
+
+    fn visit_code(&mut self, code: &Code<P>) {
+        self.visit_block(&code.block);
+    }
+
+    fn visit_block(&mut self, block: &Block<P>) {
+        self.scopes.push(Scope::default());
+        self.visit_statements(block.statements.as_slice());
+        self.scopes.pop();
+    }
+
+    fn visit_statement(&mut self, statement: &Statement<P>) {
+        match statement {
+            Statement::Object(_) | Statement::Code(_) => {}
+            Statement::Block(block) => self.visit_block(block),
+            Statement::Expression(expression) => {
+                <Self as Visitor<P>>::visit_expression(self, expression);
+                self.check_values(expression.location(), 0);
+            }
+            Statement::FunctionDefinition(function_definition) => {
+                self.visit_function_definition(function_definition)
+            }
+            Statement::VariableDeclaration(variable_declaration) => {
+                <Self as Visitor<P>>::visit_variable_declaration(self, variable_declaration)
+            }
+            Statement::Assignment(assignment) => {
+                <Self as Visitor<P>>::visit_assignment(self, assignment)
+            }
+            Statement::IfConditional(if_conditional) => self.visit_if_conditional(if_conditional),
+            Statement::Switch(switch) => self.visit_switch(switch),
+            Statement::ForLoop(for_loop) => self.visit_for_loop(for_loop),
+            Statement::Continue(location) if !self.is_in_loop_body => {
+                self.errors.push(Error::OutsideOfLoop {
+                    location: *location,
+                    keyword: "continue",
+                })
+            }
+            Statement::Break(location) if !self.is_in_loop_body => {
+                self.errors.push(Error::OutsideOfLoop {
+                    location: *location,
+                    keyword: "break",
+                })
+            }
+            Statement::Leave(location) if !self.is_in_function => {
+                self.errors.push(Error::OutsideOfFunction {
+                    location: *location,
+                })
+            }
+            Statement::Continue(_) | Statement::Break(_) | Statement::Leave(_) => {}
+        }
+    }
+
+    fn visit_function_definition(&mut self, function_definition: &FunctionDefinition<P>) {
+        let is_in_loop_body = std::mem::replace(&mut self.is_in_loop_body, false);
+        let is_in_function = std::mem::replace(&mut self.is_in_function, true);
+
+        self.scopes.push(Scope::new_function());
+        for identifier in function_definition
+            .arguments
+            .iter()
+            .chain(function_definition.result.iter())
+        {
+            self.declare_variable(identifier);
+        }
+        self.visit_block(&function_definition.body);
+        self.scopes.pop();
+
+        self.is_in_loop_body = is_in_loop_body;
+        self.is_in_function = is_in_function;
+    }
+
+    fn visit_variable_declaration(&mut self, variable_declaration: &VariableDeclaration) {
+        if let Some(expression) = variable_declaration.expression.as_ref() {
+            <Self as Visitor<P>>::visit_expression(self, expression);
+            self.check_values(expression.location(), variable_declaration.bindings.len());
+        }
+        for identifier in variable_declaration.bindings.iter() {
+            self.declare_variable(identifier);
+        }
+    }
+
+    fn visit_assignment(&mut self, assignment: &Assignment) {
+        <Self as Visitor<P>>::visit_expression(self, &assignment.initializer);
+        self.check_values(assignment.initializer.location(), assignment.bindings.len());
+        for identifier in assignment.bindings.iter() {
+            self.check_variable(identifier.location, identifier.inner.as_str());
+        }
+    }
+
+    fn visit_if_conditional(&mut self, if_conditional: &IfConditional<P>) {
+        <Self as Visitor<P>>::visit_expression(self, &if_conditional.condition);
+        self.check_values(if_conditional.condition.location(), 1);
+        self.visit_block(&if_conditional.block);
+    }
+
+    fn visit_switch(&mut self, switch: &Switch<P>) {
+        <Self as Visitor<P>>::visit_expression(self, &switch.expression);
+        self.check_values(switch.expression.location(), 1);
+        for case in switch.cases.iter() {
+            self.check_literal(&case.literal);
+            self.visit_block(&case.block);
+        }
+        if let Some(block) = switch.default.as_ref() {
+            self.visit_block(block);
+        }
+    }
+
+    fn visit_for_loop(&mut self, for_loop: &ForLoop<P>) {
+        let is_in_loop_body = std::mem::replace(&mut self.is_in_loop_body, false);
+
+        self.scopes.push(Scope::default());
+        self.visit_statements(for_loop.initializer.statements.as_slice());
+        <Self as Visitor<P>>::visit_expression(self, &for_loop.condition);
+        self.check_values(for_loop.condition.location(), 1);
+        self.visit_block(&for_loop.finalizer);
+        self.is_in_loop_body = true;
+        self.visit_block(&for_loop.body);
+        self.scopes.pop();
+
+        self.is_in_loop_body = is_in_loop_body;
+    }
+
+    fn visit_expression(&mut self, expression: &Expression) {
+        match expression {
+            Expression::FunctionCall(call) => <Self as Visitor<P>>::visit_function_call(self, call),
+            Expression::Identifier(identifier) => {
+                self.check_variable(identifier.location, identifier.inner.as_str());
+                self.values = Some(1);
+            }
+            Expression::Literal(literal) => <Self as Visitor<P>>::visit_literal(self, literal),
+        }
+    }
+
+    fn visit_function_call(&mut self, call: &FunctionCall) {
+        let identifier = call.name.to_string();
+        let (arity, literal_arguments): (Option<(usize, usize)>, &[usize]) = match call.name {
+            Name::UserDefined(ref name) => match Self::verbatim_arity(name) {
+                Some(arity) => (Some(arity), &[0]),
+                None => (self.find_function(call.location, name), &[]),
+            },
+            ref name => (name.arity(), name.literal_arguments()),
+        };
+
+        for (index, argument) in call.arguments.iter().enumerate() {
+            if !literal_arguments.contains(&index) {
+                <Self as Visitor<P>>::visit_expression(self, argument);
+                self.check_values(argument.location(), 1);
+                continue;
+            }
+
+            match argument {
+                Expression::Literal(Literal {
+                    inner: LexicalLiteral::String(string),
+                    location,
+                    ..
+                }) => {
+                    if let Name::DataOffset | Name::DataSize = call.name {
+                        self.check_object(*location, string.inner.as_str());
+                    }
+                }
+                argument => self.errors.push(Error::LiteralArgumentExpected {
+                    location: argument.location(),
+                    identifier: identifier.clone(),
+                    index: index + 1,
+                }),
+            }
+        }
+
+        match arity {
+            Some((input_size, output_size)) => {
+                if call.arguments.len() != input_size {
+                    self.errors.push(Error::InvalidNumberOfArguments {
+                        location: call.location,
+                        identifier,
+                        expected: input_size,
+                        found: call.arguments.len(),
+                    });
+                }
+                self.values = Some(output_size);
+            }
+            None => self.values = None,
+        }
+    }
+
+    fn visit_literal(&mut self, literal: &Literal) {
+        self.check_literal(literal);
+        self.values = Some(1);
+    }
+}
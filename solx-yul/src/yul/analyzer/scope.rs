@@ -0,0 +1,42 @@
+//!
+//! The Yul IR semantic analysis scope.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+///
+/// The Yul IR semantic analysis scope.
+///
+/// Corresponds to a block, or to the arguments and results of a function.
+///
+#[derive(Debug, Default)]
+pub struct Scope {
+    /// The variables declared so far.
+    pub variables: BTreeSet<String>,
+    /// The functions with their numbers of arguments and results.
+    /// Are hoisted, so they are visible in the whole block.
+    pub functions: BTreeMap<String, (usize, usize)>,
+    /// Whether the scope contains function arguments and results.
+    /// The variables of enclosing scopes are not accessible from inside the function.
+    pub is_function: bool,
+}
+
+impl Scope {
+    ///
+    /// A shortcut constructor for function scopes.
+    ///
+    pub fn new_function() -> Self {
+        Self {
+            is_function: true,
+            ..Self::default()
+        }
+    }
+
+    ///
+    /// Whether the identifier is declared in the scope.
+    ///
+    pub fn contains(&self, identifier: &str) -> bool {
+        self.variables.contains(identifier) || self.functions.contains_key(identifier)
+    }
+}
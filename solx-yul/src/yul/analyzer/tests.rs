@@ -0,0 +1,249 @@
+//!
+//! The Yul IR semantic analyzer tests.
+//!
+
+use crate::yul::analyzer::error::Error;
+use crate::yul::analyzer::Analyzer;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::Lexer;
+use crate::yul::parser::dialect::DefaultDialect;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::Statement;
+
+fn analyze(input: &str) -> Result<(), Vec<Error>> {
+    let mut lexer = Lexer::new(input);
+    let object = Object::<DefaultDialect>::parse(&mut lexer, None, solx_utils::CodeSegment::Deploy)
+        .expect("Always valid");
+    Analyzer::analyze(&object)
+}
+
+#[test]
+fn default() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            let size := datasize("Test_deployed")
+            codecopy(0, dataoffset("Test_deployed"), size)
+            return(0, size)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                let a, b := pair(calldataload(0))
+                for { let i := 0 } lt(i, a) { i := add(i, 1) } {
+                    if eq(i, b) { break }
+                    if gt(i, 10) { continue }
+                    sstore(i, mload(datasize(".metadata")))
+                }
+                switch a
+                case 0 { revert(0, 0) }
+                default { mstore(0, "string literal of 32 bytes long") }
+                mstore(32, "\x12\u220Eabcdefghijklmnopqrstuvwxyz1")
+                return(0, 64)
+            }
+            function pair(x) -> y, z {
+                y := x
+                z := twice(x)
+                if iszero(x) { leave }
+            }
+            function twice(x) -> y {
+                y := mul(x, 0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff)
+            }
+        }
+        data ".metadata" hex"1234"
+    }
+}
+    "#;
+
+    assert_eq!(analyze(input), Ok(()));
+}
+
+#[test]
+fn errors() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            let x := add(1)
+            y := x
+            break
+            leave
+            pop(datasize("Unknown"))
+            let x := 1
+            mstore(0, 0x10000000000000000000000000000000000000000000000000000000000000000)
+            function f(a) -> b { b := x }
+            let c := f()
+            f(1)
+        }
+    }
+}
+    "#;
+
+    assert_eq!(
+        analyze(input),
+        Err(vec![
+            Error::InvalidNumberOfArguments {
+                location: Location::new(5, 22),
+                identifier: "add".to_owned(),
+                expected: 2,
+                found: 1,
+            },
+            Error::UndeclaredVariable {
+                location: Location::new(6, 13),
+                identifier: "y".to_owned(),
+            },
+            Error::OutsideOfLoop {
+                location: Location::new(7, 13),
+                keyword: "break",
+            },
+            Error::OutsideOfFunction {
+                location: Location::new(8, 13),
+            },
+            Error::UnknownObject {
+                location: Location::new(9, 26),
+                identifier: "Unknown".to_owned(),
+            },
+            Error::AlreadyDeclared {
+                location: Location::new(10, 17),
+                identifier: "x".to_owned(),
+            },
+            Error::LiteralTooLarge {
+                location: Location::new(11, 23),
+                literal: "0x10000000000000000000000000000000000000000000000000000000000000000"
+                    .to_owned(),
+            },
+            Error::InaccessibleVariable {
+                location: Location::new(12, 39),
+                identifier: "x".to_owned(),
+            },
+            Error::InvalidNumberOfArguments {
+                location: Location::new(13, 22),
+                identifier: "f".to_owned(),
+                expected: 1,
+                found: 0,
+            },
+            Error::InvalidNumberOfValues {
+                location: Location::new(14, 13),
+                expected: 0,
+                found: 1,
+            },
+        ])
+    );
+}
+
+#[test]
+fn error_reserved_identifier() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            function f(mload) {}
+        }
+    }
+}
+    "#;
+
+    assert_eq!(
+        analyze(input),
+        Err(vec![Error::ReservedIdentifier {
+            location: Location::new(5, 24),
+            identifier: "mload".to_owned(),
+        }])
+    );
+}
+
+#[test]
+fn error_reserved_identifier_variable() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            let x := 1
+        }
+    }
+}
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    let mut object =
+        Object::<DefaultDialect>::parse(&mut lexer, None, solx_utils::CodeSegment::Deploy)
+            .expect("Always valid");
+
+    // The parser rejects reserved variable names, so the name is replaced as if the object
+    // was deserialized from the Yul AST JSON.
+    let Statement::Block(block) = &mut object.code.block.statements[0] else {
+        panic!("Expected a block");
+    };
+    let Statement::VariableDeclaration(declaration) = &mut block.statements[0] else {
+        panic!("Expected a variable declaration");
+    };
+    declaration.bindings[0].inner = "mload".to_owned();
+
+    assert_eq!(
+        Analyzer::analyze(&object),
+        Err(vec![Error::ReservedIdentifier {
+            location: Location::new(5, 17),
+            identifier: "mload".to_owned(),
+        }])
+    );
+}
+
+#[test]
+fn error_undefined_function() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            let x := 1
+            mdelete(x)
+            x(1)
+        }
+    }
+}
+    "#;
+
+    assert_eq!(
+        analyze(input),
+        Err(vec![
+            Error::UndefinedFunction {
+                location: Location::new(6, 13),
+                identifier: "mdelete".to_owned(),
+            },
+            Error::VariableAsFunction {
+                location: Location::new(7, 13),
+                identifier: "x".to_owned(),
+            },
+        ])
+    );
+}
+
+#[test]
+fn error_loop_finalizer() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            for { } 1 { break } {
+                function f() { continue }
+            }
+        }
+    }
+}
+    "#;
+
+    assert_eq!(
+        analyze(input),
+        Err(vec![
+            Error::OutsideOfLoop {
+                location: Location::new(5, 25),
+                keyword: "break",
+            },
+            Error::OutsideOfLoop {
+                location: Location::new(6, 32),
+                keyword: "continue",
+            },
+        ])
+    );
+}
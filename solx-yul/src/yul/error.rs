@@ -2,7 +2,10 @@
 //! The Yul IR error.
 //!
 
+use crate::yul::analyzer::error::Error as AnalyzerError;
 use crate::yul::lexer::error::Error as LexerError;
+use crate::yul::lexer::token::lexeme::Lexeme;
+use crate::yul::lexer::token::location::Location;
 use crate::yul::parser::error::Error as ParserError;
 
 ///
@@ -16,4 +19,65 @@ pub enum Error {
     /// The parser error.
     #[error("Syntax: {0}")]
     Parser(#[from] ParserError),
+    /// The semantic analyzer error.
+    #[error("Semantic: {0}")]
+    Analyzer(#[from] AnalyzerError),
+    /// The errors collected by the parser while recovering from syntax errors, or by the analyzer.
+    #[error("{}", .0.iter().map(|error| error.to_string()).collect::<Vec<String>>().join("\n"))]
+    Multiple(Vec<Self>),
+}
+
+impl Error {
+    ///
+    /// Creates an error from the non-empty list, flattening the nested lists.
+    ///
+    /// A single error is returned as is.
+    ///
+    pub fn from_errors(errors: Vec<Self>) -> Self {
+        let mut errors: Vec<Self> = errors.into_iter().flat_map(Self::into_errors).collect();
+        if errors.len() == 1 {
+            errors.pop().expect("Always exists")
+        } else {
+            Self::Multiple(errors)
+        }
+    }
+
+    ///
+    /// Returns the list of errors, flattening the nested lists.
+    ///
+    pub fn into_errors(self) -> Vec<Self> {
+        match self {
+            Self::Multiple(errors) => errors.into_iter().flat_map(Self::into_errors).collect(),
+            error => vec![error],
+        }
+    }
+
+    ///
+    /// Returns the error location, unless it is a list of errors.
+    ///
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Self::Lexer(error) => Some(error.location()),
+            Self::Parser(error) => Some(error.location()),
+            Self::Analyzer(error) => Some(error.location()),
+            Self::Multiple(_) => None,
+        }
+    }
+
+    ///
+    /// Whether the parser cannot recover from the error, since the input cannot be tokenized
+    /// any further or has ended.
+    ///
+    /// A list of errors is fatal if its last error is.
+    ///
+    pub fn is_fatal(&self) -> bool {
+        match self {
+            Self::Lexer(_) => true,
+            Self::Parser(ParserError::InvalidToken { found, .. }) => {
+                found == &Lexeme::EndOfFile.to_string()
+            }
+            Self::Parser(_) | Self::Analyzer(_) => false,
+            Self::Multiple(errors) => errors.last().is_some_and(Self::is_fatal),
+        }
+    }
 }
@@ -0,0 +1,411 @@
+//!
+//! Implementation of a folding transformation for Yul syntax tree.
+//!
+
+use crate::yul::parser::dialect::Dialect;
+use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::if_conditional::IfConditional;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::switch::case::Case;
+use crate::yul::parser::statement::switch::Switch;
+use crate::yul::parser::statement::variable_declaration::VariableDeclaration;
+use crate::yul::parser::statement::Statement;
+
+///
+/// Folding transformation of Yul syntax tree.
+///
+/// Every method takes a node by value and returns its replacement. By default, the node is
+/// rebuilt from its folded children, so a transformation only overrides the methods of the nodes
+/// it rewrites. An overriding method may call the eponymous `walk_*` function to fold the
+/// children first.
+///
+pub trait Fold<P>
+where
+    P: Dialect,
+{
+    ///
+    /// Fold Yul object in Yul syntax tree.
+    ///
+    fn fold_object(&mut self, object: Object<P>) -> Object<P> {
+        walk_object(self, object)
+    }
+
+    ///
+    /// Fold a `code` block of an object in Yul syntax tree.
+    ///
+    fn fold_code(&mut self, code: Code<P>) -> Code<P> {
+        walk_code(self, code)
+    }
+
+    ///
+    /// Fold a block of statements in Yul syntax tree.
+    ///
+    fn fold_block(&mut self, block: Block<P>) -> Block<P> {
+        walk_block(self, block)
+    }
+
+    ///
+    /// Fold the statements of a block, which allows removing or inserting statements.
+    ///
+    fn fold_statements(&mut self, statements: Vec<Statement<P>>) -> Vec<Statement<P>> {
+        walk_statements(self, statements)
+    }
+
+    ///
+    /// Fold an arbitrary statement in Yul syntax tree.
+    ///
+    fn fold_statement(&mut self, statement: Statement<P>) -> Statement<P> {
+        walk_statement(self, statement)
+    }
+
+    ///
+    /// Fold a function definition in Yul syntax tree.
+    ///
+    fn fold_function_definition(
+        &mut self,
+        function_definition: FunctionDefinition<P>,
+    ) -> FunctionDefinition<P> {
+        walk_function_definition(self, function_definition)
+    }
+
+    ///
+    /// Fold a variable declaration in Yul syntax tree.
+    ///
+    fn fold_variable_declaration(
+        &mut self,
+        variable_declaration: VariableDeclaration,
+    ) -> VariableDeclaration {
+        walk_variable_declaration::<P, Self>(self, variable_declaration)
+    }
+
+    ///
+    /// Fold an assignment in Yul syntax tree.
+    ///
+    fn fold_assignment(&mut self, assignment: Assignment) -> Assignment {
+        walk_assignment::<P, Self>(self, assignment)
+    }
+
+    ///
+    /// Fold an `if` statement in Yul syntax tree.
+    ///
+    fn fold_if_conditional(&mut self, if_conditional: IfConditional<P>) -> IfConditional<P> {
+        walk_if_conditional(self, if_conditional)
+    }
+
+    ///
+    /// Fold `switch` statement in Yul syntax tree.
+    ///
+    fn fold_switch(&mut self, switch: Switch<P>) -> Switch<P> {
+        walk_switch(self, switch)
+    }
+
+    ///
+    /// Fold `for` statement in Yul syntax tree.
+    ///
+    fn fold_for_loop(&mut self, for_loop: ForLoop<P>) -> ForLoop<P> {
+        walk_for_loop(self, for_loop)
+    }
+
+    ///
+    /// Fold an arbitrary Yul expression in Yul syntax tree.
+    ///
+    fn fold_expression(&mut self, expression: Expression) -> Expression {
+        walk_expression::<P, Self>(self, expression)
+    }
+
+    ///
+    /// Fold a function call in Yul syntax tree.
+    ///
+    fn fold_function_call(&mut self, call: FunctionCall) -> FunctionCall {
+        walk_function_call::<P, Self>(self, call)
+    }
+
+    ///
+    /// Fold a function name in Yul syntax tree: a user defined one, or one of the predefined set like `lt`.
+    ///
+    fn fold_name(&mut self, name: Name) -> Name {
+        name
+    }
+
+    ///
+    /// Fold a variable identifier in Yul syntax tree.
+    ///
+    fn fold_identifier(&mut self, identifier: Identifier) -> Identifier {
+        identifier
+    }
+
+    ///
+    /// Fold a literal (e.g. integer) in Yul syntax tree.
+    ///
+    fn fold_literal(&mut self, literal: Literal) -> Literal {
+        literal
+    }
+}
+
+///
+/// Folds the code and the inner object.
+///
+pub fn walk_object<P, F>(folder: &mut F, object: Object<P>) -> Object<P>
+where
+    P: Dialect,
+    F: Fold<P> + ?Sized,
+{
+    Object {
+        code: folder.fold_code(object.code),
+        inner_object: object
+            .inner_object
+            .map(|inner_object| Box::new(folder.fold_object(*inner_object))),
+        ..object
+    }
+}
+
+///
+/// Folds the code block.
+///
+pub fn walk_code<P, F>(folder: &mut F, code: Code<P>) -> Code<P>
+where
+    P: Dialect,
+    F: Fold<P> + ?Sized,
+{
+    Code {
+        location: code.location,
+        block: folder.fold_block(code.block),
+    }
+}
+
+///
+/// Folds the block statements.
+///
+pub fn walk_block<P, F>(folder: &mut F, block: Block<P>) -> Block<P>
+where
+    P: Dialect,
+    F: Fold<P> + ?Sized,
+{
+    Block {
+        location: block.location,
+        statements: folder.fold_statements(block.statements),
+    }
+}
+
+///
+/// Folds each statement.
+///
+pub fn walk_statements<P, F>(folder: &mut F, statements: Vec<Statement<P>>) -> Vec<Statement<P>>
+where
+    P: Dialect,
+    F: Fold<P> + ?Sized,
+{
+    statements
+        .into_iter()
+        .map(|statement| folder.fold_statement(statement))
+        .collect()
+}
+
+///
+/// Dispatches the statement to the folder method of its kind.
+///
+pub fn walk_statement<P, F>(folder: &mut F, statement: Statement<P>) -> Statement<P>
+where
+    P: Dialect,
+    F: Fold<P> + ?Sized,
+{
+    match statement {
+        Statement::Object(object) => Statement::Object(folder.fold_object(object)),
+        Statement::Code(code) => Statement::Code(folder.fold_code(code)),
+        Statement::Block(block) => Statement::Block(folder.fold_block(block)),
+        Statement::Expression(expression) => {
+            Statement::Expression(folder.fold_expression(expression))
+        }
+        Statement::FunctionDefinition(function_definition) => {
+            Statement::FunctionDefinition(folder.fold_function_definition(function_definition))
+        }
+        Statement::VariableDeclaration(variable_declaration) => {
+            Statement::VariableDeclaration(folder.fold_variable_declaration(variable_declaration))
+        }
+        Statement::Assignment(assignment) => {
+            Statement::Assignment(folder.fold_assignment(assignment))
+        }
+        Statement::IfConditional(if_conditional) => {
+            Statement::IfConditional(folder.fold_if_conditional(if_conditional))
+        }
+        Statement::Switch(switch) => Statement::Switch(folder.fold_switch(switch)),
+        Statement::ForLoop(for_loop) => Statement::ForLoop(folder.fold_for_loop(for_loop)),
+        statement @ (Statement::Continue(_) | Statement::Break(_) | Statement::Leave(_)) => {
+            statement
+        }
+    }
+}
+
+///
+/// Folds the arguments, results, and body of the function.
+///
+pub fn walk_function_definition<P, F>(
+    folder: &mut F,
+    function_definition: FunctionDefinition<P>,
+) -> FunctionDefinition<P>
+where
+    P: Dialect,
+    F: Fold<P> + ?Sized,
+{
+    FunctionDefinition {
+        arguments: function_definition
+            .arguments
+            .into_iter()
+            .map(|identifier| folder.fold_identifier(identifier))
+            .collect(),
+        result: function_definition
+            .result
+            .into_iter()
+            .map(|identifier| folder.fold_identifier(identifier))
+            .collect(),
+        body: folder.fold_block(function_definition.body),
+        ..function_definition
+    }
+}
+
+///
+/// Folds the bindings and the initializer of the variable declaration.
+///
+pub fn walk_variable_declaration<P, F>(
+    folder: &mut F,
+    variable_declaration: VariableDeclaration,
+) -> VariableDeclaration
+where
+    P: Dialect,
+    F: Fold<P> + ?Sized,
+{
+    VariableDeclaration {
+        location: variable_declaration.location,
+        bindings: variable_declaration
+            .bindings
+            .into_iter()
+            .map(|binding| folder.fold_identifier(binding))
+            .collect(),
+        expression: variable_declaration
+            .expression
+            .map(|expression| folder.fold_expression(expression)),
+    }
+}
+
+///
+/// Folds the bindings and the initializer of the assignment.
+///
+pub fn walk_assignment<P, F>(folder: &mut F, assignment: Assignment) -> Assignment
+where
+    P: Dialect,
+    F: Fold<P> + ?Sized,
+{
+    Assignment {
+        location: assignment.location,
+        bindings: assignment
+            .bindings
+            .into_iter()
+            .map(|binding| folder.fold_identifier(binding))
+            .collect(),
+        initializer: folder.fold_expression(assignment.initializer),
+    }
+}
+
+///
+/// Folds the condition and the body of the `if` statement.
+///
+pub fn walk_if_conditional<P, F>(
+    folder: &mut F,
+    if_conditional: IfConditional<P>,
+) -> IfConditional<P>
+where
+    P: Dialect,
+    F: Fold<P> + ?Sized,
+{
+    IfConditional {
+        location: if_conditional.location,
+        condition: folder.fold_expression(if_conditional.condition),
+        block: folder.fold_block(if_conditional.block),
+    }
+}
+
+///
+/// Folds the scrutinee, the cases, and the default block of the `switch` statement.
+///
+pub fn walk_switch<P, F>(folder: &mut F, switch: Switch<P>) -> Switch<P>
+where
+    P: Dialect,
+    F: Fold<P> + ?Sized,
+{
+    Switch {
+        location: switch.location,
+        expression: folder.fold_expression(switch.expression),
+        cases: switch
+            .cases
+            .into_iter()
+            .map(|case| Case {
+                location: case.location,
+                literal: folder.fold_literal(case.literal),
+                block: folder.fold_block(case.block),
+            })
+            .collect(),
+        default: switch.default.map(|default| folder.fold_block(default)),
+    }
+}
+
+///
+/// Folds the initializer, condition, finalizer, and body of the `for` statement.
+///
+pub fn walk_for_loop<P, F>(folder: &mut F, for_loop: ForLoop<P>) -> ForLoop<P>
+where
+    P: Dialect,
+    F: Fold<P> + ?Sized,
+{
+    ForLoop {
+        location: for_loop.location,
+        initializer: folder.fold_block(for_loop.initializer),
+        condition: folder.fold_expression(for_loop.condition),
+        finalizer: folder.fold_block(for_loop.finalizer),
+        body: folder.fold_block(for_loop.body),
+    }
+}
+
+///
+/// Dispatches the expression to the folder method of its kind.
+///
+pub fn walk_expression<P, F>(folder: &mut F, expression: Expression) -> Expression
+where
+    P: Dialect,
+    F: Fold<P> + ?Sized,
+{
+    match expression {
+        Expression::FunctionCall(call) => Expression::FunctionCall(folder.fold_function_call(call)),
+        Expression::Identifier(identifier) => {
+            Expression::Identifier(folder.fold_identifier(identifier))
+        }
+        Expression::Literal(literal) => Expression::Literal(folder.fold_literal(literal)),
+    }
+}
+
+///
+/// Folds the name and the arguments of the function call.
+///
+pub fn walk_function_call<P, F>(folder: &mut F, call: FunctionCall) -> FunctionCall
+where
+    P: Dialect,
+    F: Fold<P> + ?Sized,
+{
+    FunctionCall {
+        location: call.location,
+        name: folder.fold_name(call.name),
+        arguments: call
+            .arguments
+            .into_iter()
+            .map(|argument| folder.fold_expression(argument))
+            .collect(),
+    }
+}
@@ -0,0 +1,561 @@
+//!
+//! The Yul interpreter builtin functions.
+//!
+
+use num::One;
+use num::Signed;
+use num::ToPrimitive;
+use num::Zero;
+
+use crate::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::dialect::Dialect;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::Expression;
+
+use super::call_kind::CallKind;
+use super::error::Error;
+use super::host::Host;
+use super::log::Log;
+use super::memory::Memory;
+use super::status::Status;
+use super::Exit;
+use super::Interpreter;
+
+impl<'a, P, H> Interpreter<'a, P, H>
+where
+    P: Dialect,
+    H: Host,
+{
+    ///
+    /// Calls the builtin function.
+    ///
+    pub(super) fn call_builtin(
+        &mut self,
+        call: &'a FunctionCall,
+    ) -> Result<Vec<num::BigUint>, Exit> {
+        let location = call.location;
+        let (input_size, _) = call.name.arity().expect("Always exists");
+        if call.arguments.len() != input_size {
+            return Err(Error::InvalidNumberOfArguments {
+                location,
+                identifier: call.name.to_string(),
+                expected: input_size,
+                found: call.arguments.len(),
+            }
+            .into());
+        }
+
+        match call.name {
+            Name::DataSize | Name::DataOffset => {
+                Self::literal_argument(call, 0)?;
+                return Ok(vec![num::BigUint::zero()]);
+            }
+            Name::LoadImmutable => {
+                let identifier = Self::literal_argument(call, 0)?;
+                let value = self
+                    .immutables
+                    .get(identifier.as_str())
+                    .cloned()
+                    .unwrap_or_default();
+                return Ok(vec![value]);
+            }
+            Name::SetImmutable => {
+                let identifier = Self::literal_argument(call, 1)?;
+                let value = self.evaluate_single(&call.arguments[2])?;
+                self.evaluate_single(&call.arguments[0])?;
+                self.immutables.insert(identifier, value);
+                return Ok(vec![]);
+            }
+            Name::LinkerSymbol | Name::Verbatim { .. } | Name::Pc | Name::UnsafeAsm => {
+                return Err(Error::Unsupported {
+                    location,
+                    identifier: call.name.to_string(),
+                }
+                .into());
+            }
+            _ => {}
+        }
+
+        let arguments = self.evaluate_arguments(call.arguments.as_slice())?;
+        let value = match call.name {
+            Name::Add => Some(Self::wrap(&arguments[0] + &arguments[1])),
+            Name::Sub => Some(Self::wrap(Self::modulus() + &arguments[0] - &arguments[1])),
+            Name::Mul => Some(Self::wrap(&arguments[0] * &arguments[1])),
+            Name::Div => Some(if arguments[1].is_zero() {
+                num::BigUint::zero()
+            } else {
+                &arguments[0] / &arguments[1]
+            }),
+            Name::Mod => Some(if arguments[1].is_zero() {
+                num::BigUint::zero()
+            } else {
+                &arguments[0] % &arguments[1]
+            }),
+            Name::Sdiv => Some(if arguments[1].is_zero() {
+                num::BigUint::zero()
+            } else {
+                Self::from_signed(Self::to_signed(&arguments[0]) / Self::to_signed(&arguments[1]))
+            }),
+            Name::Smod => Some(if arguments[1].is_zero() {
+                num::BigUint::zero()
+            } else {
+                Self::from_signed(Self::to_signed(&arguments[0]) % Self::to_signed(&arguments[1]))
+            }),
+
+            Name::Lt => Some(Self::from_bool(arguments[0] < arguments[1])),
+            Name::Gt => Some(Self::from_bool(arguments[0] > arguments[1])),
+            Name::Eq => Some(Self::from_bool(arguments[0] == arguments[1])),
+            Name::IsZero => Some(Self::from_bool(arguments[0].is_zero())),
+            Name::Slt => Some(Self::from_bool(
+                Self::to_signed(&arguments[0]) < Self::to_signed(&arguments[1]),
+            )),
+            Name::Sgt => Some(Self::from_bool(
+                Self::to_signed(&arguments[0]) > Self::to_signed(&arguments[1]),
+            )),
+            Name::Clz => Some(num::BigUint::from(
+                solx_utils::BIT_LENGTH_FIELD as u64 - arguments[0].bits(),
+            )),
+
+            Name::Or => Some(&arguments[0] | &arguments[1]),
+            Name::Xor => Some(&arguments[0] ^ &arguments[1]),
+            Name::Not => Some(Self::mask() ^ &arguments[0]),
+            Name::And => Some(&arguments[0] & &arguments[1]),
+            Name::Shl => Some(match Self::shift(&arguments[0]) {
+                Some(shift) => Self::wrap(&arguments[1] << shift),
+                None => num::BigUint::zero(),
+            }),
+            Name::Shr => Some(match Self::shift(&arguments[0]) {
+                Some(shift) => &arguments[1] >> shift,
+                None => num::BigUint::zero(),
+            }),
+            Name::Sar => {
+                let value = Self::to_signed(&arguments[1]);
+                Some(match Self::shift(&arguments[0]) {
+                    Some(shift) => Self::from_signed(value >> shift),
+                    None if value.is_negative() => Self::mask(),
+                    None => num::BigUint::zero(),
+                })
+            }
+            Name::Byte => Some(match arguments[0].to_usize() {
+                Some(index) if index < solx_utils::BYTE_LENGTH_FIELD => {
+                    num::BigUint::from(Self::to_word(&arguments[1])[index])
+                }
+                _ => num::BigUint::zero(),
+            }),
+            Name::Pop => None,
+
+            Name::AddMod => Some(if arguments[2].is_zero() {
+                num::BigUint::zero()
+            } else {
+                (&arguments[0] + &arguments[1]) % &arguments[2]
+            }),
+            Name::MulMod => Some(if arguments[2].is_zero() {
+                num::BigUint::zero()
+            } else {
+                (&arguments[0] * &arguments[1]) % &arguments[2]
+            }),
+            Name::Exp => Some(arguments[0].modpow(&arguments[1], &Self::modulus())),
+            Name::SignExtend => Some(match arguments[0].to_usize() {
+                Some(index) if index < solx_utils::BYTE_LENGTH_FIELD - 1 => {
+                    let bit = (index as u64 + 1) * solx_utils::BIT_LENGTH_BYTE as u64 - 1;
+                    let low = (num::BigUint::one() << (bit + 1)) - num::BigUint::one();
+                    if arguments[1].bit(bit) {
+                        &arguments[1] | (Self::mask() ^ low)
+                    } else {
+                        &arguments[1] & low
+                    }
+                }
+                _ => arguments[1].clone(),
+            }),
+
+            Name::Keccak256 => {
+                let (offset, size) = Self::memory_range(location, &arguments[0], &arguments[1])?;
+                let preimage = self.memory.read(offset, size);
+                let hash = solx_utils::Keccak256Hash::from_slice(preimage.as_slice());
+                Some(num::BigUint::from_bytes_be(hash.as_bytes()))
+            }
+
+            Name::MLoad => {
+                let (offset, size) = Self::memory_range(
+                    location,
+                    &arguments[0],
+                    &num::BigUint::from(solx_utils::BYTE_LENGTH_FIELD),
+                )?;
+                Some(num::BigUint::from_bytes_be(
+                    self.memory.read(offset, size).as_slice(),
+                ))
+            }
+            Name::MStore => {
+                let (offset, _) = Self::memory_range(
+                    location,
+                    &arguments[0],
+                    &num::BigUint::from(solx_utils::BYTE_LENGTH_FIELD),
+                )?;
+                self.memory
+                    .write(offset, Self::to_word(&arguments[1]).as_slice());
+                None
+            }
+            Name::MStore8 => {
+                let (offset, _) =
+                    Self::memory_range(location, &arguments[0], &num::BigUint::one())?;
+                let word = Self::to_word(&arguments[1]);
+                self.memory
+                    .write(offset, &word[solx_utils::BYTE_LENGTH_FIELD - 1..]);
+                None
+            }
+            Name::MCopy => {
+                let (source, size) = Self::memory_range(location, &arguments[1], &arguments[2])?;
+                let (destination, _) = Self::memory_range(location, &arguments[0], &arguments[2])?;
+                let bytes = self.memory.read(source, size);
+                self.memory.write(destination, bytes.as_slice());
+                None
+            }
+
+            Name::SLoad => Some(self.storage.get(&arguments[0]).cloned().unwrap_or_default()),
+            Name::SStore => {
+                self.storage
+                    .insert(arguments[0].clone(), arguments[1].clone());
+                None
+            }
+            Name::TLoad => Some(
+                self.transient_storage
+                    .get(&arguments[0])
+                    .cloned()
+                    .unwrap_or_default(),
+            ),
+            Name::TStore => {
+                self.transient_storage
+                    .insert(arguments[0].clone(), arguments[1].clone());
+                None
+            }
+
+            Name::CallDataLoad => Some(num::BigUint::from_bytes_be(
+                Self::copy_padded(
+                    self.environment.calldata.as_slice(),
+                    &arguments[0],
+                    solx_utils::BYTE_LENGTH_FIELD,
+                )
+                .as_slice(),
+            )),
+            Name::CallDataSize => Some(num::BigUint::from(self.environment.calldata.len())),
+            Name::CallDataCopy => {
+                let (offset, size) = Self::memory_range(location, &arguments[0], &arguments[2])?;
+                let bytes =
+                    Self::copy_padded(self.environment.calldata.as_slice(), &arguments[1], size);
+                self.memory.write(offset, bytes.as_slice());
+                None
+            }
+            Name::CodeSize => Some(num::BigUint::zero()),
+            Name::CodeCopy | Name::DataCopy => {
+                let (offset, size) = Self::memory_range(location, &arguments[0], &arguments[2])?;
+                self.memory.write(offset, vec![0; size].as_slice());
+                None
+            }
+            Name::ExtCodeSize => {
+                let code = self.host.code(&Self::to_address(&arguments[0]));
+                Some(num::BigUint::from(code.len()))
+            }
+            Name::ExtCodeHash => Some(self.host.code_hash(&Self::to_address(&arguments[0]))),
+            Name::ExtCodeCopy => {
+                let (offset, size) = Self::memory_range(location, &arguments[1], &arguments[3])?;
+                let code = self.host.code(&Self::to_address(&arguments[0]));
+                let bytes = Self::copy_padded(code.as_slice(), &arguments[2], size);
+                self.memory.write(offset, bytes.as_slice());
+                None
+            }
+            Name::ReturnDataSize => Some(num::BigUint::from(self.return_data.len())),
+            Name::ReturnDataCopy => {
+                let (offset, size) = Self::memory_range(location, &arguments[0], &arguments[2])?;
+                let end = &arguments[1] + &arguments[2];
+                if end > num::BigUint::from(self.return_data.len()) {
+                    return Err(Exit::Halt(Status::Invalid, vec![]));
+                }
+                let source = arguments[1].to_usize().expect("Always valid");
+                let bytes = self.return_data[source..source + size].to_vec();
+                self.memory.write(offset, bytes.as_slice());
+                None
+            }
+
+            Name::Return | Name::Revert => {
+                let (offset, size) = Self::memory_range(location, &arguments[0], &arguments[1])?;
+                let return_data = self.memory.read(offset, size);
+                let status = if let Name::Return = call.name {
+                    Status::Return
+                } else {
+                    Status::Revert
+                };
+                return Err(Exit::Halt(status, return_data));
+            }
+            Name::Stop | Name::SelfDestruct => return Err(Exit::Halt(Status::Stop, vec![])),
+            Name::Invalid => return Err(Exit::Halt(Status::Invalid, vec![])),
+
+            Name::Log0 | Name::Log1 | Name::Log2 | Name::Log3 | Name::Log4 => {
+                let (offset, size) = Self::memory_range(location, &arguments[0], &arguments[1])?;
+                let data = self.memory.read(offset, size);
+                self.logs.push(Log {
+                    address: self.environment.address.clone(),
+                    topics: arguments[2..].to_vec(),
+                    data,
+                });
+                None
+            }
+
+            Name::Call | Name::CallCode => {
+                let kind = if let Name::Call = call.name {
+                    CallKind::Call
+                } else {
+                    CallKind::CallCode
+                };
+                Some(self.external_call(
+                    location,
+                    kind,
+                    &arguments[0],
+                    &arguments[1],
+                    &arguments[2],
+                    &arguments[3..],
+                )?)
+            }
+            Name::DelegateCall | Name::StaticCall => {
+                let kind = if let Name::DelegateCall = call.name {
+                    CallKind::DelegateCall
+                } else {
+                    CallKind::StaticCall
+                };
+                Some(self.external_call(
+                    location,
+                    kind,
+                    &arguments[0],
+                    &arguments[1],
+                    &num::BigUint::zero(),
+                    &arguments[2..],
+                )?)
+            }
+            Name::Create | Name::Create2 => {
+                let (offset, size) = Self::memory_range(location, &arguments[1], &arguments[2])?;
+                let code = self.memory.read(offset, size);
+                let (address, return_data) =
+                    self.host
+                        .create(&arguments[0], code.as_slice(), arguments.get(3));
+                self.return_data = if address.is_some() {
+                    vec![]
+                } else {
+                    return_data
+                };
+                Some(address.unwrap_or_default())
+            }
+
+            Name::MemoryGuard => Some(arguments[0].clone()),
+
+            Name::Address => Some(self.environment.address.clone()),
+            Name::Caller => Some(self.environment.caller.clone()),
+            Name::CallValue => Some(self.environment.call_value.clone()),
+            Name::Gas => Some(self.environment.gas.clone()),
+            Name::Balance => Some(self.host.balance(&Self::to_address(&arguments[0]))),
+            Name::SelfBalance => {
+                let address = self.environment.address.clone();
+                Some(self.host.balance(&address))
+            }
+
+            Name::GasLimit => Some(self.environment.gas_limit.clone()),
+            Name::GasPrice => Some(self.environment.gas_price.clone()),
+            Name::Origin => Some(self.environment.origin.clone()),
+            Name::ChainId => Some(self.environment.chain_id.clone()),
+            Name::Number => Some(self.environment.number.clone()),
+            Name::Timestamp => Some(self.environment.timestamp.clone()),
+            Name::BlockHash => Some(self.host.block_hash(&arguments[0])),
+            Name::BlobHash => Some(self.host.blob_hash(&arguments[0])),
+            Name::Difficulty | Name::Prevrandao => Some(self.environment.prevrandao.clone()),
+            Name::CoinBase => Some(self.environment.coinbase.clone()),
+            Name::BaseFee => Some(self.environment.base_fee.clone()),
+            Name::BlobBaseFee => Some(self.environment.blob_base_fee.clone()),
+            Name::MSize => Some(num::BigUint::from(self.memory.size())),
+
+            Name::UserDefined(_)
+            | Name::DataSize
+            | Name::DataOffset
+            | Name::LoadImmutable
+            | Name::SetImmutable
+            | Name::LinkerSymbol
+            | Name::Verbatim { .. }
+            | Name::Pc
+            | Name::UnsafeAsm => unreachable!(),
+        };
+
+        Ok(value.into_iter().collect())
+    }
+
+    ///
+    /// Performs an external call through the host.
+    ///
+    /// The `memory` arguments are the input offset and size, and the output offset and size.
+    ///
+    fn external_call(
+        &mut self,
+        location: Location,
+        kind: CallKind,
+        gas: &num::BigUint,
+        address: &num::BigUint,
+        value: &num::BigUint,
+        memory: &[num::BigUint],
+    ) -> Result<num::BigUint, Exit> {
+        let (input_offset, input_size) = Self::memory_range(location, &memory[0], &memory[1])?;
+        let (output_offset, output_size) = Self::memory_range(location, &memory[2], &memory[3])?;
+        let input = self.memory.read(input_offset, input_size);
+        self.memory.expand(output_offset, output_size);
+
+        let (is_successful, return_data) = self.host.call(
+            kind,
+            gas,
+            &Self::to_address(address),
+            value,
+            input.as_slice(),
+        );
+        let output_size = std::cmp::min(output_size, return_data.len());
+        self.memory
+            .write(output_offset, &return_data[..output_size]);
+        self.return_data = return_data;
+
+        Ok(Self::from_bool(is_successful))
+    }
+
+    ///
+    /// Returns the decoded string literal passed as the argument at `index`.
+    ///
+    fn literal_argument(call: &FunctionCall, index: usize) -> Result<String, Error> {
+        match call.arguments.get(index) {
+            Some(Expression::Literal(literal)) => match literal.inner {
+                LexicalLiteral::String(ref string) => {
+                    Ok(String::from_utf8_lossy(string.to_bytes().as_slice()).into_owned())
+                }
+                _ => Err(Error::LiteralArgumentExpected {
+                    location: call.location,
+                    identifier: call.name.to_string(),
+                    index,
+                }),
+            },
+            _ => Err(Error::LiteralArgumentExpected {
+                location: call.location,
+                identifier: call.name.to_string(),
+                index,
+            }),
+        }
+    }
+
+    ///
+    /// Converts the memory offset and size into a range supported by the interpreter.
+    ///
+    /// Empty ranges do not access the memory, so their offset is ignored.
+    ///
+    fn memory_range(
+        location: Location,
+        offset: &num::BigUint,
+        size: &num::BigUint,
+    ) -> Result<(usize, usize), Error> {
+        if size.is_zero() {
+            return Ok((0, 0));
+        }
+
+        match (offset.to_usize(), size.to_usize()) {
+            (Some(offset), Some(size))
+                if offset
+                    .checked_add(size)
+                    .is_some_and(|end| end <= Memory::LIMIT) =>
+            {
+                Ok((offset, size))
+            }
+            _ => Err(Error::MemoryLimit {
+                location,
+                limit: Memory::LIMIT,
+            }),
+        }
+    }
+
+    ///
+    /// Copies `size` bytes of `source` at `offset`, padding with zeros past its end.
+    ///
+    fn copy_padded(source: &[u8], offset: &num::BigUint, size: usize) -> Vec<u8> {
+        let mut bytes = vec![0; size];
+        if let Some(offset) = offset.to_usize().filter(|offset| *offset < source.len()) {
+            let length = std::cmp::min(size, source.len() - offset);
+            bytes[..length].copy_from_slice(&source[offset..offset + length]);
+        }
+        bytes
+    }
+
+    ///
+    /// Returns the shift amount if it is less than the word size.
+    ///
+    fn shift(value: &num::BigUint) -> Option<usize> {
+        value
+            .to_usize()
+            .filter(|shift| *shift < solx_utils::BIT_LENGTH_FIELD)
+    }
+
+    ///
+    /// Returns `2^256`.
+    ///
+    fn modulus() -> num::BigUint {
+        num::BigUint::one() << solx_utils::BIT_LENGTH_FIELD
+    }
+
+    ///
+    /// Returns `2^256 - 1`.
+    ///
+    fn mask() -> num::BigUint {
+        Self::modulus() - num::BigUint::one()
+    }
+
+    ///
+    /// Truncates the value to 256 bits.
+    ///
+    fn wrap(value: num::BigUint) -> num::BigUint {
+        value & Self::mask()
+    }
+
+    ///
+    /// Truncates the value to 160 bits.
+    ///
+    fn to_address(value: &num::BigUint) -> num::BigUint {
+        value & ((num::BigUint::one() << solx_utils::BIT_LENGTH_ETH_ADDRESS) - num::BigUint::one())
+    }
+
+    ///
+    /// Converts the boolean into a word.
+    ///
+    fn from_bool(value: bool) -> num::BigUint {
+        num::BigUint::from(value as u8)
+    }
+
+    ///
+    /// Interprets the word as a two's complement signed integer.
+    ///
+    fn to_signed(value: &num::BigUint) -> num::BigInt {
+        if value.bit(solx_utils::BIT_LENGTH_FIELD as u64 - 1) {
+            num::BigInt::from(value.clone()) - num::BigInt::from(Self::modulus())
+        } else {
+            num::BigInt::from(value.clone())
+        }
+    }
+
+    ///
+    /// Converts the signed integer into a two's complement word.
+    ///
+    fn from_signed(value: num::BigInt) -> num::BigUint {
+        let modulus = num::BigInt::from(Self::modulus());
+        (((value % &modulus) + &modulus) % modulus)
+            .to_biguint()
+            .expect("Always valid")
+    }
+
+    ///
+    /// Converts the value into a big-endian 32-byte word.
+    ///
+    fn to_word(value: &num::BigUint) -> [u8; solx_utils::BYTE_LENGTH_FIELD] {
+        let bytes = value.to_bytes_be();
+        let mut word = [0; solx_utils::BYTE_LENGTH_FIELD];
+        word[solx_utils::BYTE_LENGTH_FIELD - bytes.len()..].copy_from_slice(bytes.as_slice());
+        word
+    }
+}
@@ -0,0 +1,18 @@
+//!
+//! The Yul interpreter external call kind.
+//!
+
+///
+/// The Yul interpreter external call kind.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallKind {
+    /// The `call` builtin.
+    Call,
+    /// The `callcode` builtin.
+    CallCode,
+    /// The `delegatecall` builtin.
+    DelegateCall,
+    /// The `staticcall` builtin.
+    StaticCall,
+}
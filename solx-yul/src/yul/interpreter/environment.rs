@@ -0,0 +1,42 @@
+//!
+//! The Yul interpreter execution environment.
+//!
+
+///
+/// The Yul interpreter execution environment.
+///
+/// Contains the transaction and block parameters that are constant during an execution.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Environment {
+    /// The address of the executing contract.
+    pub address: num::BigUint,
+    /// The caller address.
+    pub caller: num::BigUint,
+    /// The value sent with the call in wei.
+    pub call_value: num::BigUint,
+    /// The call data.
+    pub calldata: Vec<u8>,
+    /// The transaction origin address.
+    pub origin: num::BigUint,
+    /// The transaction gas price.
+    pub gas_price: num::BigUint,
+    /// The value returned by `gas()`, as the interpreter does not meter gas.
+    pub gas: num::BigUint,
+    /// The block gas limit.
+    pub gas_limit: num::BigUint,
+    /// The chain ID.
+    pub chain_id: num::BigUint,
+    /// The block number.
+    pub number: num::BigUint,
+    /// The block timestamp.
+    pub timestamp: num::BigUint,
+    /// The block beneficiary address.
+    pub coinbase: num::BigUint,
+    /// The block base fee.
+    pub base_fee: num::BigUint,
+    /// The block blob base fee.
+    pub blob_base_fee: num::BigUint,
+    /// The block `prevrandao` value, also returned by `difficulty()`.
+    pub prevrandao: num::BigUint,
+}
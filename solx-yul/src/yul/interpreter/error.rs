@@ -0,0 +1,101 @@
+//!
+//! The Yul interpreter error.
+//!
+
+use crate::yul::lexer::token::location::Location;
+
+///
+/// The Yul interpreter error.
+///
+/// Most of the errors are also reported by the semantic analyzer, which is expected to be run
+/// before the interpreter.
+///
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum Error {
+    /// The variable is not declared.
+    #[error("{location} Variable `{identifier}` is not declared")]
+    UndeclaredVariable {
+        /// The variable location.
+        location: Location,
+        /// The variable identifier.
+        identifier: String,
+    },
+    /// The function is not defined.
+    #[error("{location} Function `{identifier}` is not defined")]
+    UndefinedFunction {
+        /// The function call location.
+        location: Location,
+        /// The function identifier.
+        identifier: String,
+    },
+    /// The function is called with an invalid number of arguments.
+    #[error("{location} Function `{identifier}` must have {expected} arguments, found {found}")]
+    InvalidNumberOfArguments {
+        /// The function call location.
+        location: Location,
+        /// The function identifier.
+        identifier: String,
+        /// The expected number of arguments.
+        expected: usize,
+        /// The actual number of arguments.
+        found: usize,
+    },
+    /// The expression returns an invalid number of values.
+    #[error("{location} Expected {expected} values, found {found}")]
+    InvalidNumberOfValues {
+        /// The expression location.
+        location: Location,
+        /// The expected number of values.
+        expected: usize,
+        /// The actual number of values.
+        found: usize,
+    },
+    /// The argument of the builtin function must be a string literal.
+    #[error("{location} Argument #{index} of function `{identifier}` must be a string literal")]
+    LiteralArgumentExpected {
+        /// The function call location.
+        location: Location,
+        /// The function identifier.
+        identifier: String,
+        /// The argument index.
+        index: usize,
+    },
+    /// The literal value does not fit into a 256-bit word.
+    #[error("{location} Literal `{literal}` does not fit into 32 bytes")]
+    LiteralTooLarge {
+        /// The literal location.
+        location: Location,
+        /// The literal.
+        literal: String,
+    },
+    /// The statement cannot appear in a block.
+    #[error("{location} Unexpected statement")]
+    UnexpectedStatement {
+        /// The statement location.
+        location: Location,
+    },
+    /// The builtin function cannot be interpreted without the bytecode.
+    #[error("{location} Function `{identifier}` is not supported by the interpreter")]
+    Unsupported {
+        /// The function call location.
+        location: Location,
+        /// The function identifier.
+        identifier: String,
+    },
+    /// The memory access is out of the interpreter limit.
+    #[error("{location} Memory access exceeds the interpreter limit of {limit} bytes")]
+    MemoryLimit {
+        /// The memory access location.
+        location: Location,
+        /// The memory size limit.
+        limit: usize,
+    },
+    /// The execution is too long.
+    #[error("{location} Execution exceeds the interpreter limit of {limit} steps")]
+    StepLimit {
+        /// The location of the step that exceeds the limit.
+        location: Location,
+        /// The step limit.
+        limit: usize,
+    },
+}
@@ -0,0 +1,80 @@
+//!
+//! The Yul interpreter host.
+//!
+
+use num::Zero;
+
+use crate::yul::interpreter::call_kind::CallKind;
+
+///
+/// The Yul interpreter host.
+///
+/// Provides the state of the world outside of the executing contract. The default
+/// implementations describe an empty world where every account is empty and every call fails.
+///
+pub trait Host {
+    ///
+    /// Returns the balance of the account at `address`.
+    ///
+    fn balance(&mut self, _address: &num::BigUint) -> num::BigUint {
+        num::BigUint::zero()
+    }
+
+    ///
+    /// Returns the code of the account at `address`.
+    ///
+    fn code(&mut self, _address: &num::BigUint) -> Vec<u8> {
+        vec![]
+    }
+
+    ///
+    /// Returns the code hash of the account at `address`.
+    ///
+    fn code_hash(&mut self, _address: &num::BigUint) -> num::BigUint {
+        num::BigUint::zero()
+    }
+
+    ///
+    /// Returns the hash of the block with the specified `number`.
+    ///
+    fn block_hash(&mut self, _number: &num::BigUint) -> num::BigUint {
+        num::BigUint::zero()
+    }
+
+    ///
+    /// Returns the versioned hash of the transaction blob at `index`.
+    ///
+    fn blob_hash(&mut self, _index: &num::BigUint) -> num::BigUint {
+        num::BigUint::zero()
+    }
+
+    ///
+    /// Performs an external call.
+    ///
+    /// Returns the success flag and the return data.
+    ///
+    fn call(
+        &mut self,
+        _kind: CallKind,
+        _gas: &num::BigUint,
+        _address: &num::BigUint,
+        _value: &num::BigUint,
+        _input: &[u8],
+    ) -> (bool, Vec<u8>) {
+        (false, vec![])
+    }
+
+    ///
+    /// Deploys a contract with the `code` constructor, using `salt` for `create2`.
+    ///
+    /// Returns the address of the new contract on success, and the return data.
+    ///
+    fn create(
+        &mut self,
+        _value: &num::BigUint,
+        _code: &[u8],
+        _salt: Option<&num::BigUint>,
+    ) -> (Option<num::BigUint>, Vec<u8>) {
+        (None, vec![])
+    }
+}
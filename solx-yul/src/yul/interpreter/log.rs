@@ -0,0 +1,16 @@
+//!
+//! The Yul interpreter event log.
+//!
+
+///
+/// The Yul interpreter event log.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Log {
+    /// The emitting contract address.
+    pub address: num::BigUint,
+    /// The log topics.
+    pub topics: Vec<num::BigUint>,
+    /// The log data.
+    pub data: Vec<u8>,
+}
@@ -0,0 +1,64 @@
+//!
+//! The Yul interpreter memory.
+//!
+
+///
+/// The Yul interpreter memory.
+///
+/// Grows in 32-byte words, like the EVM memory.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Memory {
+    /// The memory contents.
+    bytes: Vec<u8>,
+}
+
+impl Memory {
+    /// The maximum memory size supported by the interpreter.
+    pub const LIMIT: usize = 1 << 25;
+
+    ///
+    /// Returns the memory size in bytes, which is always a multiple of the word size.
+    ///
+    pub fn size(&self) -> usize {
+        self.bytes.len()
+    }
+
+    ///
+    /// Reads `size` bytes at `offset`, expanding the memory if necessary.
+    ///
+    pub fn read(&mut self, offset: usize, size: usize) -> Vec<u8> {
+        if size == 0 {
+            return vec![];
+        }
+        self.expand(offset, size);
+        self.bytes[offset..offset + size].to_vec()
+    }
+
+    ///
+    /// Writes `bytes` at `offset`, expanding the memory if necessary.
+    ///
+    pub fn write(&mut self, offset: usize, bytes: &[u8]) {
+        if bytes.is_empty() {
+            return;
+        }
+        self.expand(offset, bytes.len());
+        self.bytes[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    ///
+    /// Expands the memory to fit `size` bytes at `offset`, rounded up to the word size.
+    ///
+    /// Empty ranges do not expand the memory.
+    ///
+    pub fn expand(&mut self, offset: usize, size: usize) {
+        if size == 0 {
+            return;
+        }
+        let size =
+            (offset + size).div_ceil(solx_utils::BYTE_LENGTH_FIELD) * solx_utils::BYTE_LENGTH_FIELD;
+        if size > self.bytes.len() {
+            self.bytes.resize(size, 0);
+        }
+    }
+}
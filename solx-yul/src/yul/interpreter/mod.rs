@@ -0,0 +1,537 @@
+//!
+//! The Yul IR reference interpreter.
+//!
+
+mod builtin;
+pub mod call_kind;
+pub mod environment;
+pub mod error;
+pub mod host;
+pub mod log;
+pub mod memory;
+pub mod outcome;
+pub mod status;
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::BTreeMap;
+
+use num::Num;
+use num::One;
+use num::Zero;
+
+use crate::yul::lexer::token::lexeme::literal::boolean::Boolean as BooleanLiteral;
+use crate::yul::lexer::token::lexeme::literal::integer::Integer as IntegerLiteral;
+use crate::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::dialect::Dialect;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::Statement;
+
+use self::environment::Environment;
+use self::error::Error;
+use self::host::Host;
+use self::log::Log;
+use self::memory::Memory;
+use self::outcome::Outcome;
+use self::status::Status;
+
+///
+/// The Yul IR reference interpreter.
+///
+/// Executes Yul objects with the EVM semantics, so it can be used as an oracle for the code
+/// generated from Yul. The code of objects is never materialized, so `datasize`, `dataoffset`,
+/// and `codesize` evaluate to zero, while `codecopy` and `datacopy` copy zeros.
+/// Gas is not metered, and the interactions with other accounts are delegated to the [`Host`].
+///
+/// The storage and immutables are preserved between executions, so a deploy object and its
+/// runtime object can be executed one after another.
+///
+#[derive(Debug)]
+pub struct Interpreter<'a, P, H>
+where
+    P: Dialect,
+    H: Host,
+{
+    /// The execution environment.
+    pub environment: Environment,
+    /// The host providing the other accounts.
+    pub host: H,
+    /// The contract storage.
+    pub storage: BTreeMap<num::BigUint, num::BigUint>,
+    /// The immutable values set by the deploy code.
+    pub immutables: BTreeMap<String, num::BigUint>,
+
+    /// The transient storage, which is cleared before each execution.
+    transient_storage: BTreeMap<num::BigUint, num::BigUint>,
+    /// The execution memory.
+    memory: Memory,
+    /// The data returned by the last external call.
+    return_data: Vec<u8>,
+    /// The logs emitted by the current execution.
+    logs: Vec<Log>,
+    /// The variable scopes of the current function.
+    variables: Vec<BTreeMap<String, num::BigUint>>,
+    /// The function scopes visible from the current function.
+    functions: Vec<BTreeMap<&'a str, &'a FunctionDefinition<P>>>,
+    /// The number of steps performed by the current execution.
+    steps: usize,
+}
+
+///
+/// The statement execution control flow.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Flow {
+    /// Continue with the next statement.
+    Normal,
+    /// Exit the innermost loop.
+    Break,
+    /// Go to the next iteration of the innermost loop.
+    Continue,
+    /// Exit the current function.
+    Leave,
+}
+
+///
+/// The reason the execution cannot continue.
+///
+#[derive(Debug)]
+enum Exit {
+    /// The execution has halted with the return data.
+    Halt(Status, Vec<u8>),
+    /// The program cannot be interpreted.
+    Error(Error),
+}
+
+impl From<Error> for Exit {
+    fn from(error: Error) -> Self {
+        Self::Error(error)
+    }
+}
+
+impl<'a, P, H> Interpreter<'a, P, H>
+where
+    P: Dialect,
+    H: Host,
+{
+    /// The maximum number of statements and function calls in an execution.
+    pub const STEP_LIMIT: usize = 100_000_000;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(environment: Environment, host: H) -> Self {
+        Self {
+            environment,
+            host,
+            storage: BTreeMap::new(),
+            immutables: BTreeMap::new(),
+
+            transient_storage: BTreeMap::new(),
+            memory: Memory::default(),
+            return_data: vec![],
+            logs: vec![],
+            variables: vec![],
+            functions: vec![],
+            steps: 0,
+        }
+    }
+
+    ///
+    /// Executes the code of `object` as a separate transaction.
+    ///
+    /// Inner objects are not executed. To execute the runtime code, pass the inner object.
+    ///
+    pub fn execute(&mut self, object: &'a Object<P>) -> Result<Outcome, Error> {
+        let storage = self.storage.clone();
+        let immutables = self.immutables.clone();
+
+        self.transient_storage.clear();
+        self.memory = Memory::default();
+        self.return_data.clear();
+        self.logs.clear();
+        self.variables.clear();
+        self.functions.clear();
+        self.steps = 0;
+
+        let (status, return_data) = match self.execute_block(&object.code.block) {
+            Ok(_) => (Status::Stop, vec![]),
+            Err(Exit::Halt(status, return_data)) => (status, return_data),
+            Err(Exit::Error(error)) => {
+                self.storage = storage;
+                self.immutables = immutables;
+                return Err(error);
+            }
+        };
+
+        if status.is_reverted() {
+            self.storage = storage;
+            self.immutables = immutables;
+            self.logs.clear();
+        }
+
+        Ok(Outcome {
+            status,
+            return_data,
+            logs: std::mem::take(&mut self.logs),
+        })
+    }
+
+    ///
+    /// Executes the block in a new scope.
+    ///
+    fn execute_block(&mut self, block: &'a Block<P>) -> Result<Flow, Exit> {
+        self.enter_scope(block.statements.as_slice());
+        let flow = self.execute_statements(block.statements.as_slice())?;
+        self.leave_scope();
+        Ok(flow)
+    }
+
+    ///
+    /// Executes the statements in the current scope.
+    ///
+    fn execute_statements(&mut self, statements: &'a [Statement<P>]) -> Result<Flow, Exit> {
+        for statement in statements.iter() {
+            let flow = self.execute_statement(statement)?;
+            if flow != Flow::Normal {
+                return Ok(flow);
+            }
+        }
+        Ok(Flow::Normal)
+    }
+
+    ///
+    /// Executes the statement.
+    ///
+    fn execute_statement(&mut self, statement: &'a Statement<P>) -> Result<Flow, Exit> {
+        match statement {
+            Statement::Object(object) => Err(Error::UnexpectedStatement {
+                location: object.location,
+            }
+            .into()),
+            Statement::Code(code) => Err(Error::UnexpectedStatement {
+                location: code.location,
+            }
+            .into()),
+            Statement::Block(block) => {
+                self.step(block.location)?;
+                self.execute_block(block)
+            }
+            Statement::Expression(expression) => {
+                let location = expression.location();
+                self.step(location)?;
+                let values = self.evaluate(expression)?;
+                Self::check_values(location, 0, values.len())?;
+                Ok(Flow::Normal)
+            }
+            Statement::FunctionDefinition(_) => Ok(Flow::Normal),
+            Statement::VariableDeclaration(declaration) => {
+                self.step(declaration.location)?;
+                let values = match declaration.expression {
+                    Some(ref expression) => self.evaluate(expression)?,
+                    None => vec![num::BigUint::zero(); declaration.bindings.len()],
+                };
+                Self::check_values(
+                    declaration.location,
+                    declaration.bindings.len(),
+                    values.len(),
+                )?;
+                let scope = self.variables.last_mut().expect("Always exists");
+                for (binding, value) in declaration.bindings.iter().zip(values) {
+                    scope.insert(binding.inner.clone(), value);
+                }
+                Ok(Flow::Normal)
+            }
+            Statement::Assignment(assignment) => {
+                self.step(assignment.location)?;
+                let values = self.evaluate(&assignment.initializer)?;
+                Self::check_values(assignment.location, assignment.bindings.len(), values.len())?;
+                for (binding, value) in assignment.bindings.iter().zip(values) {
+                    *self.variable_mut(binding.location, binding.inner.as_str())? = value;
+                }
+                Ok(Flow::Normal)
+            }
+            Statement::IfConditional(conditional) => {
+                self.step(conditional.location)?;
+                if self.evaluate_single(&conditional.condition)?.is_zero() {
+                    return Ok(Flow::Normal);
+                }
+                self.execute_block(&conditional.block)
+            }
+            Statement::Switch(switch) => {
+                self.step(switch.location)?;
+                let value = self.evaluate_single(&switch.expression)?;
+                for case in switch.cases.iter() {
+                    if Self::literal_value(&case.literal)? == value {
+                        return self.execute_block(&case.block);
+                    }
+                }
+                match switch.default {
+                    Some(ref block) => self.execute_block(block),
+                    None => Ok(Flow::Normal),
+                }
+            }
+            Statement::ForLoop(for_loop) => {
+                self.step(for_loop.location)?;
+                self.execute_for_loop(for_loop)
+            }
+            Statement::Continue(_) => Ok(Flow::Continue),
+            Statement::Break(_) => Ok(Flow::Break),
+            Statement::Leave(_) => Ok(Flow::Leave),
+        }
+    }
+
+    ///
+    /// Executes the `for` loop.
+    ///
+    /// The initializer scope encloses the condition, body, and finalizer.
+    ///
+    fn execute_for_loop(&mut self, for_loop: &'a ForLoop<P>) -> Result<Flow, Exit> {
+        self.enter_scope(for_loop.initializer.statements.as_slice());
+        if self.execute_statements(for_loop.initializer.statements.as_slice())? == Flow::Leave {
+            self.leave_scope();
+            return Ok(Flow::Leave);
+        }
+
+        loop {
+            self.step(for_loop.location)?;
+            if self.evaluate_single(&for_loop.condition)?.is_zero() {
+                break;
+            }
+
+            match self.execute_block(&for_loop.body)? {
+                Flow::Break => break,
+                Flow::Leave => {
+                    self.leave_scope();
+                    return Ok(Flow::Leave);
+                }
+                Flow::Normal | Flow::Continue => {}
+            }
+
+            if self.execute_block(&for_loop.finalizer)? == Flow::Leave {
+                self.leave_scope();
+                return Ok(Flow::Leave);
+            }
+        }
+
+        self.leave_scope();
+        Ok(Flow::Normal)
+    }
+
+    ///
+    /// Evaluates the expression.
+    ///
+    fn evaluate(&mut self, expression: &'a Expression) -> Result<Vec<num::BigUint>, Exit> {
+        match expression {
+            Expression::FunctionCall(call) => self.call(call),
+            Expression::Identifier(identifier) => Ok(vec![self
+                .variable_mut(identifier.location, identifier.inner.as_str())?
+                .clone()]),
+            Expression::Literal(literal) => Ok(vec![Self::literal_value(literal)?]),
+        }
+    }
+
+    ///
+    /// Evaluates the expression that must return exactly one value.
+    ///
+    fn evaluate_single(&mut self, expression: &'a Expression) -> Result<num::BigUint, Exit> {
+        let mut values = self.evaluate(expression)?;
+        Self::check_values(expression.location(), 1, values.len())?;
+        Ok(values.pop().expect("Always exists"))
+    }
+
+    ///
+    /// Evaluates the function call arguments from right to left, as specified by Yul.
+    ///
+    fn evaluate_arguments(
+        &mut self,
+        arguments: &'a [Expression],
+    ) -> Result<Vec<num::BigUint>, Exit> {
+        let mut values = Vec::with_capacity(arguments.len());
+        for argument in arguments.iter().rev() {
+            values.push(self.evaluate_single(argument)?);
+        }
+        values.reverse();
+        Ok(values)
+    }
+
+    ///
+    /// Calls the user-defined or builtin function.
+    ///
+    fn call(&mut self, call: &'a FunctionCall) -> Result<Vec<num::BigUint>, Exit> {
+        self.step(call.location)?;
+
+        let identifier = match call.name {
+            Name::UserDefined(ref identifier) => identifier.as_str(),
+            _ => return self.call_builtin(call),
+        };
+
+        let depth = self
+            .functions
+            .iter()
+            .rposition(|scope| scope.contains_key(identifier))
+            .ok_or_else(|| {
+                if identifier.starts_with("verbatim_") {
+                    Error::Unsupported {
+                        location: call.location,
+                        identifier: identifier.to_owned(),
+                    }
+                } else {
+                    Error::UndefinedFunction {
+                        location: call.location,
+                        identifier: identifier.to_owned(),
+                    }
+                }
+            })?;
+        let function = self.functions[depth][identifier];
+        if function.arguments.len() != call.arguments.len() {
+            return Err(Error::InvalidNumberOfArguments {
+                location: call.location,
+                identifier: identifier.to_owned(),
+                expected: function.arguments.len(),
+                found: call.arguments.len(),
+            }
+            .into());
+        }
+
+        let arguments = self.evaluate_arguments(call.arguments.as_slice())?;
+        let mut frame = BTreeMap::new();
+        for (argument, value) in function.arguments.iter().zip(arguments) {
+            frame.insert(argument.inner.clone(), value);
+        }
+        for result in function.result.iter() {
+            frame.insert(result.inner.clone(), num::BigUint::zero());
+        }
+
+        let caller_variables = std::mem::replace(&mut self.variables, vec![frame]);
+        let caller_functions = self.functions.split_off(depth + 1);
+        self.execute_block(&function.body)?;
+        self.functions.extend(caller_functions);
+        let mut frame = std::mem::replace(&mut self.variables, caller_variables)
+            .pop()
+            .expect("Always exists");
+
+        Ok(function
+            .result
+            .iter()
+            .map(|result| frame.remove(result.inner.as_str()).expect("Always exists"))
+            .collect())
+    }
+
+    ///
+    /// Opens a new scope with the functions defined in `statements`.
+    ///
+    /// Functions are visible in the whole block they are defined in.
+    ///
+    fn enter_scope(&mut self, statements: &'a [Statement<P>]) {
+        let functions = statements
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::FunctionDefinition(function) => {
+                    Some((function.identifier.as_str(), function))
+                }
+                _ => None,
+            })
+            .collect();
+        self.variables.push(BTreeMap::new());
+        self.functions.push(functions);
+    }
+
+    ///
+    /// Closes the innermost scope.
+    ///
+    fn leave_scope(&mut self) {
+        self.variables.pop();
+        self.functions.pop();
+    }
+
+    ///
+    /// Returns a mutable reference to the variable visible from the current scope.
+    ///
+    fn variable_mut(
+        &mut self,
+        location: Location,
+        identifier: &str,
+    ) -> Result<&mut num::BigUint, Error> {
+        self.variables
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.get_mut(identifier))
+            .ok_or_else(|| Error::UndeclaredVariable {
+                location,
+                identifier: identifier.to_owned(),
+            })
+    }
+
+    ///
+    /// Counts an execution step, failing if the limit is exceeded.
+    ///
+    fn step(&mut self, location: Location) -> Result<(), Error> {
+        self.steps += 1;
+        if self.steps > Self::STEP_LIMIT {
+            return Err(Error::StepLimit {
+                location,
+                limit: Self::STEP_LIMIT,
+            });
+        }
+        Ok(())
+    }
+
+    ///
+    /// Checks the number of values produced by an expression.
+    ///
+    fn check_values(location: Location, expected: usize, found: usize) -> Result<(), Error> {
+        if expected != found {
+            return Err(Error::InvalidNumberOfValues {
+                location,
+                expected,
+                found,
+            });
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the 256-bit value of the literal.
+    ///
+    /// String literals are left-aligned in the word.
+    ///
+    fn literal_value(literal: &Literal) -> Result<num::BigUint, Error> {
+        let value = match literal.inner {
+            LexicalLiteral::Boolean(BooleanLiteral::False) => Some(num::BigUint::zero()),
+            LexicalLiteral::Boolean(BooleanLiteral::True) => Some(num::BigUint::one()),
+            LexicalLiteral::Integer(IntegerLiteral::Decimal { ref inner }) => {
+                num::BigUint::from_str_radix(inner.as_str(), solx_utils::BASE_DECIMAL).ok()
+            }
+            LexicalLiteral::Integer(IntegerLiteral::Hexadecimal { ref inner }) => {
+                num::BigUint::from_str_radix(&inner["0x".len()..], solx_utils::BASE_HEXADECIMAL)
+                    .ok()
+            }
+            LexicalLiteral::String(ref string) => {
+                let mut bytes = string.to_bytes();
+                if bytes.len() <= solx_utils::BYTE_LENGTH_FIELD {
+                    bytes.resize(solx_utils::BYTE_LENGTH_FIELD, 0);
+                    Some(num::BigUint::from_bytes_be(bytes.as_slice()))
+                } else {
+                    None
+                }
+            }
+        };
+
+        value
+            .filter(|value| value.bits() <= solx_utils::BIT_LENGTH_FIELD as u64)
+            .ok_or_else(|| Error::LiteralTooLarge {
+                location: literal.location,
+                literal: literal.inner.to_string(),
+            })
+    }
+}
@@ -0,0 +1,19 @@
+//!
+//! The Yul interpreter execution outcome.
+//!
+
+use crate::yul::interpreter::log::Log;
+use crate::yul::interpreter::status::Status;
+
+///
+/// The Yul interpreter execution outcome.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    /// The execution status.
+    pub status: Status,
+    /// The data passed to `return` or `revert`.
+    pub return_data: Vec<u8>,
+    /// The emitted logs, which are empty if the execution is reverted.
+    pub logs: Vec<Log>,
+}
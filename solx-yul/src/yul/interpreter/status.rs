@@ -0,0 +1,27 @@
+//!
+//! The Yul interpreter execution status.
+//!
+
+///
+/// The Yul interpreter execution status.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The execution ended with `return`.
+    Return,
+    /// The execution ended with `stop`, `selfdestruct`, or the end of code.
+    Stop,
+    /// The execution ended with `revert`.
+    Revert,
+    /// The execution ended with an exceptional halt, such as `invalid`.
+    Invalid,
+}
+
+impl Status {
+    ///
+    /// Whether the state changes of the execution are discarded.
+    ///
+    pub fn is_reverted(&self) -> bool {
+        matches!(self, Self::Revert | Self::Invalid)
+    }
+}
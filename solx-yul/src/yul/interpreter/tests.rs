@@ -0,0 +1,272 @@
+//!
+//! The Yul IR reference interpreter tests.
+//!
+
+use num::Num;
+
+use crate::yul::interpreter::call_kind::CallKind;
+use crate::yul::interpreter::environment::Environment;
+use crate::yul::interpreter::error::Error;
+use crate::yul::interpreter::host::Host;
+use crate::yul::interpreter::status::Status;
+use crate::yul::interpreter::Interpreter;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::Lexer;
+use crate::yul::parser::dialect::DefaultDialect;
+use crate::yul::parser::statement::object::Object;
+
+struct EmptyHost;
+
+impl Host for EmptyHost {}
+
+struct EchoHost;
+
+impl Host for EchoHost {
+    fn call(
+        &mut self,
+        kind: CallKind,
+        _gas: &num::BigUint,
+        _address: &num::BigUint,
+        _value: &num::BigUint,
+        input: &[u8],
+    ) -> (bool, Vec<u8>) {
+        (kind == CallKind::StaticCall, input.to_vec())
+    }
+}
+
+fn parse(input: &str) -> Object<DefaultDialect> {
+    let mut lexer = Lexer::new(input);
+    Object::<DefaultDialect>::parse(&mut lexer, None, solx_utils::CodeSegment::Deploy)
+        .expect("Always valid")
+}
+
+fn words(values: &[&str]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|value| {
+            let value = num::BigUint::from_str_radix(value, solx_utils::BASE_HEXADECIMAL)
+                .expect("Always valid");
+            let bytes = value.to_bytes_be();
+            let mut word = vec![0; solx_utils::BYTE_LENGTH_FIELD - bytes.len()];
+            word.extend(bytes);
+            word
+        })
+        .collect()
+}
+
+#[test]
+fn arithmetic() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            mstore(0, sub(0, 1))
+            mstore(32, sdiv(sub(0, 8), 3))
+            mstore(64, smod(sub(0, 8), 3))
+            mstore(96, sar(1, sub(0, 3)))
+            mstore(128, signextend(0, 0xff))
+            mstore(160, byte(30, 0x1234))
+            mstore(192, clz(1))
+            mstore(224, exp(2, 256))
+            mstore(256, mulmod(sub(0, 1), sub(0, 1), 7))
+            mstore(288, slt(sub(0, 1), 0))
+            mstore(320, shl(255, 3))
+            mstore(352, "abc")
+            return(0, 384)
+        }
+    }
+}
+    "#;
+    let object = parse(input);
+
+    let mut interpreter = Interpreter::new(Environment::default(), EmptyHost);
+    let outcome = interpreter.execute(&object).expect("Always valid");
+
+    assert_eq!(outcome.status, Status::Return);
+    assert_eq!(
+        outcome.return_data,
+        words(&[
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe",
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe",
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe",
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "12",
+            "ff",
+            "0",
+            "1",
+            "1",
+            "8000000000000000000000000000000000000000000000000000000000000000",
+            "6162630000000000000000000000000000000000000000000000000000000000",
+        ])
+    );
+}
+
+#[test]
+fn control_flow() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            let sum := 0
+            for { let i := 0 } 1 { i := add(i, 1) } {
+                if eq(i, 10) { break }
+                if mod(i, 2) { continue }
+                sum := add(sum, i)
+            }
+            let result
+            switch sum
+            case 20 { result := factorial(5) }
+            default { result := 0 }
+            mstore(0, result)
+            mstore(32, first(7))
+            return(0, 64)
+
+            function factorial(n) -> f {
+                f := 1
+                if n { f := mul(n, factorial(sub(n, 1))) }
+            }
+            function first(n) -> r {
+                for { } 1 { } {
+                    r := n
+                    leave
+                }
+                r := 0
+            }
+        }
+    }
+}
+    "#;
+    let object = parse(input);
+
+    let mut interpreter = Interpreter::new(Environment::default(), EmptyHost);
+    let outcome = interpreter.execute(&object).expect("Always valid");
+
+    assert_eq!(outcome.status, Status::Return);
+    assert_eq!(outcome.return_data, words(&["78", "7"]));
+}
+
+#[test]
+fn deploy_and_runtime() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            sstore(0, 42)
+            setimmutable(0, "value", callvalue())
+            codecopy(0, dataoffset("Test_deployed"), datasize("Test_deployed"))
+            return(0, datasize("Test_deployed"))
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                sstore(1, add(sload(0), calldataload(0)))
+                log1(0, 0, loadimmutable("value"))
+                if calldataload(32) { revert(0, 0) }
+                tstore(0, 1)
+                mstore(0, loadimmutable("value"))
+                return(0, 32)
+            }
+        }
+    }
+}
+    "#;
+    let object = parse(input);
+    let runtime = object.inner_object.as_deref().expect("Always exists");
+
+    let environment = Environment {
+        call_value: num::BigUint::from(5u64),
+        ..Default::default()
+    };
+    let mut interpreter = Interpreter::new(environment, EmptyHost);
+    let outcome = interpreter.execute(&object).expect("Always valid");
+    assert_eq!(outcome.status, Status::Return);
+    assert!(outcome.return_data.is_empty());
+
+    interpreter.environment.calldata = words(&["1", "1"]);
+    let outcome = interpreter.execute(runtime).expect("Always valid");
+    assert_eq!(outcome.status, Status::Revert);
+    assert!(outcome.logs.is_empty());
+    assert_eq!(interpreter.storage.len(), 1);
+
+    interpreter.environment.calldata = words(&["1", "0"]);
+    let outcome = interpreter.execute(runtime).expect("Always valid");
+    assert_eq!(outcome.status, Status::Return);
+    assert_eq!(outcome.return_data, words(&["5"]));
+    assert_eq!(outcome.logs.len(), 1);
+    assert_eq!(outcome.logs[0].topics, vec![num::BigUint::from(5u64)]);
+    assert_eq!(
+        interpreter.storage.get(&num::BigUint::from(1u64)),
+        Some(&num::BigUint::from(43u64))
+    );
+}
+
+#[test]
+fn host_call() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            mstore(0, 0x1234)
+            let success := staticcall(gas(), 1, 0, 32, 64, 16)
+            mstore(96, success)
+            mstore(128, returndatasize())
+            mstore(160, call(gas(), 1, 0, 0, 0, 0, 0))
+            return(64, 128)
+        }
+    }
+}
+    "#;
+    let object = parse(input);
+
+    let mut interpreter = Interpreter::new(Environment::default(), EchoHost);
+    let outcome = interpreter.execute(&object).expect("Always valid");
+
+    assert_eq!(outcome.status, Status::Return);
+    assert_eq!(outcome.return_data, words(&["0", "1", "20", "0"]));
+}
+
+#[test]
+fn error_unsupported() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            mstore(0, pc())
+        }
+    }
+}
+    "#;
+    let object = parse(input);
+
+    let mut interpreter = Interpreter::new(Environment::default(), EmptyHost);
+    let result = interpreter.execute(&object);
+
+    assert_eq!(
+        result,
+        Err(Error::Unsupported {
+            location: Location::new(5, 23),
+            identifier: "pc".to_owned(),
+        })
+    );
+}
+
+#[test]
+fn error_memory_limit_overflow() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            return(0xffffffffffffffff, 1)
+        }
+    }
+}
+    "#;
+    let object = parse(input);
+
+    let mut interpreter = Interpreter::new(Environment::default(), EmptyHost);
+    let result = interpreter.execute(&object);
+
+    assert!(matches!(result, Err(Error::MemoryLimit { .. })));
+}
@@ -18,3 +18,14 @@ pub enum Error {
         sequence: String,
     },
 }
+
+impl Error {
+    ///
+    /// Returns the error location.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::InvalidLexeme { location, .. } => *location,
+        }
+    }
+}
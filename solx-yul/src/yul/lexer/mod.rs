@@ -16,6 +16,7 @@ use self::token::lexeme::literal::string::String as StringLiteral;
 use self::token::lexeme::symbol::Symbol;
 use self::token::lexeme::Lexeme;
 use self::token::location::Location;
+use self::token::solidity_location::SolidityLocation;
 use self::token::Token;
 
 ///
@@ -68,6 +69,11 @@ impl<'a> Lexer<'a> {
             }
 
             if let Some(token) = Comment::parse(input) {
+                let comment = input.get(..token.length).unwrap_or(input);
+                if let Some(solidity) = SolidityLocation::parse_comment(comment) {
+                    self.location.solidity = solidity;
+                }
+
                 self.offset += token.length;
                 self.location
                     .shift_down(token.location.line, token.location.column);
@@ -5,6 +5,7 @@
 use crate::yul::lexer::error::Error;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
+use crate::yul::lexer::token::solidity_location::SolidityLocation;
 use crate::yul::lexer::Lexer;
 
 #[test]
@@ -89,3 +90,35 @@ object "Test" {
         }
     }
 }
+
+#[test]
+fn solidity_location() {
+    let input = r#"
+/// @src 0:10:20  "assembly { ... }"
+unsafeasm()
+/** @src 1:30:40 */ mstore(64, 128)
+/// @src -1:-1:-1
+stop()
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    let mut locations = Vec::new();
+    loop {
+        let token = lexer.next().expect("Always valid");
+        if let Lexeme::EndOfFile = token.lexeme {
+            break;
+        }
+        if let Lexeme::Identifier(_) = token.lexeme {
+            locations.push(token.location.solidity);
+        }
+    }
+
+    assert_eq!(
+        locations,
+        vec![
+            Some(SolidityLocation::new(0, 10, 20)),
+            Some(SolidityLocation::new(1, 30, 40)),
+            None,
+        ]
+    );
+}
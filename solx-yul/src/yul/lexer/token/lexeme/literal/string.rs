@@ -29,6 +29,62 @@ impl String {
         }
     }
 
+    ///
+    /// Returns the bytes the literal represents, with escape sequences resolved.
+    ///
+    pub fn to_bytes(&self) -> Vec<u8> {
+        if self.is_hexadecimal {
+            let digits = self
+                .inner
+                .chars()
+                .filter(char::is_ascii_hexdigit)
+                .collect::<Vec<char>>();
+            return digits
+                .chunks(2)
+                .map(|pair| {
+                    pair.iter().fold(0u8, |byte, digit| {
+                        (byte << 4) | digit.to_digit(16).expect("Always valid") as u8
+                    })
+                })
+                .collect();
+        }
+
+        let mut bytes = Vec::with_capacity(self.inner.len());
+        let mut characters = self.inner.chars();
+        while let Some(character) = characters.next() {
+            if character != '\\' {
+                let mut buffer = [0u8; 4];
+                bytes.extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+                continue;
+            }
+
+            match characters.next() {
+                Some('x') => {
+                    let byte = characters.by_ref().take(2).collect::<std::string::String>();
+                    bytes.push(u8::from_str_radix(byte.as_str(), 16).unwrap_or_default());
+                }
+                Some('u') => {
+                    let code_point = characters.by_ref().take(4).collect::<std::string::String>();
+                    let character = u32::from_str_radix(code_point.as_str(), 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .unwrap_or(char::REPLACEMENT_CHARACTER);
+                    let mut buffer = [0u8; 4];
+                    bytes.extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+                }
+                Some('t') => bytes.push(b'\t'),
+                Some('n') => bytes.push(b'\n'),
+                Some('r') => bytes.push(b'\r'),
+                Some('\n' | '\r') | None => {}
+                Some(character) => {
+                    let mut buffer = [0u8; 4];
+                    bytes.extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+                }
+            }
+        }
+        bytes
+    }
+
     ///
     /// Parses the value from the source code slice.
     ///
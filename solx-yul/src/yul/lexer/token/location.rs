@@ -2,6 +2,8 @@
 //! The lexical token location.
 //!
 
+use crate::yul::lexer::token::solidity_location::SolidityLocation;
+
 ///
 /// The token location in the source code file.
 ///
@@ -11,11 +13,18 @@ pub struct Location {
     pub line: usize,
     /// The column number, starting from 1.
     pub column: usize,
+    /// The Solidity source code location, if provided by `solc` debug comments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solidity: Option<SolidityLocation>,
 }
 
 impl Default for Location {
     fn default() -> Self {
-        Self { line: 1, column: 1 }
+        Self {
+            line: 1,
+            column: 1,
+            solidity: None,
+        }
     }
 }
 
@@ -24,7 +33,11 @@ impl Location {
     /// Creates a default location.
     ///
     pub fn new(line: usize, column: usize) -> Self {
-        Self { line, column }
+        Self {
+            line,
+            column,
+            solidity: None,
+        }
     }
 
     ///
@@ -47,6 +60,29 @@ impl Location {
     pub fn shift_right(&mut self, columns: usize) {
         self.column += columns;
     }
+
+    ///
+    /// Returns the byte offset of the location in `source_code`.
+    ///
+    /// Locations beyond the end of their line or the source code are clamped to them.
+    ///
+    pub fn offset(&self, source_code: &str) -> usize {
+        let line_offset: usize = source_code
+            .split_inclusive('\n')
+            .take(self.line.saturating_sub(1))
+            .map(str::len)
+            .sum();
+        let line = source_code[line_offset..]
+            .split_inclusive('\n')
+            .next()
+            .unwrap_or_default();
+        let column_offset = line
+            .char_indices()
+            .nth(self.column.saturating_sub(1))
+            .map(|(offset, _)| offset)
+            .unwrap_or(line.len());
+        line_offset + column_offset
+    }
 }
 
 impl PartialEq for Location {
@@ -60,3 +96,19 @@ impl std::fmt::Display for Location {
         write!(f, "{}:{}", self.line, self.column)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::lexer::token::location::Location;
+
+    #[test]
+    fn offset() {
+        let source_code = "object \"Test\" {\n    code { ä }\n}\n";
+
+        assert_eq!(Location::new(1, 1).offset(source_code), 0);
+        assert_eq!(Location::new(2, 5).offset(source_code), 20);
+        assert_eq!(Location::new(2, 13).offset(source_code), 29);
+        assert_eq!(Location::new(3, 1).offset(source_code), 32);
+        assert_eq!(Location::new(5, 1).offset(source_code), source_code.len());
+    }
+}
@@ -4,6 +4,7 @@
 
 pub mod lexeme;
 pub mod location;
+pub mod solidity_location;
 
 use self::lexeme::Lexeme;
 use self::location::Location;
@@ -0,0 +1,51 @@
+//!
+//! The Solidity source code location.
+//!
+
+///
+/// The Solidity source code location, taken from the `@src` debug comments emitted by `solc`.
+///
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct SolidityLocation {
+    /// The `solc` source file identifier.
+    pub source_id: usize,
+    /// The start offset in the source file.
+    pub start: isize,
+    /// The end offset in the source file.
+    pub end: isize,
+}
+
+impl SolidityLocation {
+    /// The debug comment tag.
+    pub const TAG: &'static str = "@src";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(source_id: usize, start: isize, end: isize) -> Self {
+        Self {
+            source_id,
+            start,
+            end,
+        }
+    }
+
+    ///
+    /// Parses the `@src <source_id>:<start>:<end>` tag from a comment.
+    ///
+    /// Returns `None` if the comment does not contain the tag.
+    /// Returns `Some(None)` if the tag resets the location, e.g. `@src -1:-1:-1`.
+    ///
+    pub fn parse_comment(comment: &str) -> Option<Option<Self>> {
+        let (_, tag) = comment.split_once(Self::TAG)?;
+        let mut parts = tag.split_whitespace().next()?.split(':');
+        let source_id = parts.next()?.parse::<isize>().ok()?;
+        let start = parts.next()?.parse::<isize>().ok()?;
+        let end = parts.next()?.parse::<isize>().ok()?;
+
+        if source_id < 0 || start < 0 || end < 0 {
+            return Some(None);
+        }
+        Some(Some(Self::new(source_id as usize, start, end)))
+    }
+}
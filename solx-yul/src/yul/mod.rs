@@ -2,8 +2,13 @@
 //! The Yul IR compiling tools.
 //!
 
+pub mod analyzer;
 pub mod error;
+pub mod fold;
+pub mod interpreter;
 pub mod lexer;
 pub mod parser;
+pub mod pass;
 pub mod printer;
 pub mod visitor;
+pub mod visitor_mut;
@@ -50,3 +50,17 @@ pub enum Error {
         values: BTreeSet<String>,
     },
 }
+
+impl Error {
+    ///
+    /// Returns the error location.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::InvalidToken { location, .. } => *location,
+            Self::ReservedIdentifier { location, .. } => *location,
+            Self::InvalidNumberOfArguments { location, .. } => *location,
+            Self::InvalidAttributes { location, .. } => *location,
+        }
+    }
+}
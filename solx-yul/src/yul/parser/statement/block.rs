@@ -6,6 +6,7 @@ use std::collections::BTreeSet;
 
 use crate::dependencies::Dependencies;
 use crate::yul::error::Error;
+use crate::yul::lexer::token::lexeme::keyword::Keyword;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
@@ -39,11 +40,13 @@ where
     ///
     /// The element parser.
     ///
+    /// The parser recovers from syntax errors at statement boundaries. If the block has been parsed
+    /// up to its closing bracket despite them, the recovered errors are returned as `Error::Multiple`.
+    /// Otherwise, the fatal error is returned along with the errors recovered before it.
+    ///
     pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
-        let mut statements = Vec::new();
-
         let location = match token {
             Token {
                 lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
@@ -60,25 +63,44 @@ where
             }
         };
 
+        let mut statements = Vec::new();
+        let mut errors = Vec::new();
+        match Self::parse_statements(lexer, &mut statements, &mut errors) {
+            Ok(()) if errors.is_empty() => Ok(Self {
+                location,
+                statements,
+            }),
+            Ok(()) => Err(Error::Multiple(errors)),
+            Err(error) => {
+                errors.push(error);
+                Err(Error::from_errors(errors))
+            }
+        }
+    }
+
+    ///
+    /// Parses the block statements up to the closing bracket, collecting the recovered syntax errors.
+    ///
+    fn parse_statements(
+        lexer: &mut Lexer,
+        statements: &mut Vec<Statement<P>>,
+        errors: &mut Vec<Error>,
+    ) -> Result<(), Error> {
         let mut remaining = None;
 
         loop {
-            match crate::yul::parser::take_or_next(remaining.take(), lexer)? {
+            let result = match crate::yul::parser::take_or_next(remaining.take(), lexer)? {
                 token @ Token {
                     lexeme: Lexeme::Keyword(_),
                     ..
-                } => {
-                    let (statement, next) = Statement::parse(lexer, Some(token))?;
+                } => Statement::parse(lexer, Some(token)).map(|(statement, next)| {
                     remaining = next;
-                    statements.push(statement);
-                }
+                    statement
+                }),
                 token @ Token {
                     lexeme: Lexeme::Literal(_),
                     ..
-                } => {
-                    statements
-                        .push(Expression::parse(lexer, Some(token)).map(Statement::Expression)?);
-                }
+                } => Expression::parse(lexer, Some(token)).map(Statement::Expression),
                 token @ Token {
                     lexeme: Lexeme::Identifier(_),
                     ..
@@ -86,48 +108,129 @@ where
                     Token {
                         lexeme: Lexeme::Symbol(Symbol::Assignment),
                         ..
-                    } => {
-                        statements.push(
-                            Assignment::parse(lexer, Some(token)).map(Statement::Assignment)?,
-                        );
-                    }
+                    } => Assignment::parse(lexer, Some(token)).map(Statement::Assignment),
                     Token {
                         lexeme: Lexeme::Symbol(Symbol::Comma),
                         ..
-                    } => {
-                        statements.push(
-                            Assignment::parse(lexer, Some(token)).map(Statement::Assignment)?,
-                        );
-                    }
-                    _ => {
-                        statements.push(
-                            Expression::parse(lexer, Some(token)).map(Statement::Expression)?,
-                        );
-                    }
+                    } => Assignment::parse(lexer, Some(token)).map(Statement::Assignment),
+                    _ => Expression::parse(lexer, Some(token)).map(Statement::Expression),
                 },
                 token @ Token {
                     lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
                     ..
-                } => statements.push(Block::parse(lexer, Some(token)).map(Statement::Block)?),
+                } => Block::parse(lexer, Some(token)).map(Statement::Block),
                 Token {
                     lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
                     ..
-                } => break,
-                token => {
-                    return Err(ParserError::InvalidToken {
-                        location: token.location,
-                        expected: vec!["{keyword}", "{expression}", "{identifier}", "{", "}"],
-                        found: token.lexeme.to_string(),
+                } => return Ok(()),
+                token => Err(ParserError::InvalidToken {
+                    location: token.location,
+                    expected: vec!["{keyword}", "{expression}", "{identifier}", "{", "}"],
+                    found: token.lexeme.to_string(),
+                }
+                .into()),
+            };
+
+            match result {
+                Ok(statement) => statements.push(statement),
+                Err(error) if error.is_fatal() => return Err(error),
+                Err(Error::Multiple(nested)) => {
+                    // A nested block has been parsed up to its closing bracket, but the rest of
+                    // its statement, such as the other switch cases, must be skipped.
+                    errors.extend(nested);
+                    if !Self::is_statement_start(&lexer.peek()?) {
+                        Self::recover(lexer)?;
+                    }
+                }
+                Err(error) => {
+                    let is_closing_bracket = matches!(
+                        error,
+                        Error::Parser(ParserError::InvalidToken { ref found, .. }) if found == "}"
+                    );
+                    errors.push(error);
+                    // The broken statement has consumed the end of the block, unless it is
+                    // followed by the rest of a function signature.
+                    if is_closing_bracket
+                        && !matches!(
+                            lexer.peek()?,
+                            Token {
+                                lexeme: Lexeme::Symbol(Symbol::Arrow),
+                                ..
+                            }
+                        )
+                    {
+                        return Ok(());
                     }
-                    .into());
+                    Self::recover(lexer)?;
                 }
             }
         }
+    }
 
-        Ok(Self {
-            location,
-            statements,
-        })
+    ///
+    /// Whether the token can start a statement or end the block.
+    ///
+    fn is_statement_start(token: &Token) -> bool {
+        matches!(
+            token.lexeme,
+            Lexeme::Keyword(
+                Keyword::Function
+                    | Keyword::Let
+                    | Keyword::If
+                    | Keyword::Switch
+                    | Keyword::For
+                    | Keyword::Break
+                    | Keyword::Continue
+                    | Keyword::Leave
+            ) | Lexeme::Literal(_)
+                | Lexeme::Identifier(_)
+                | Lexeme::Symbol(Symbol::BracketCurlyLeft | Symbol::BracketCurlyRight)
+        )
+    }
+
+    ///
+    /// Skips the tokens until the start of the next statement or the end of the block.
+    ///
+    /// The nested blocks are skipped as a whole, since they usually belong to the broken statement.
+    ///
+    fn recover(lexer: &mut Lexer) -> Result<(), Error> {
+        let mut depth = 0;
+        loop {
+            match lexer.peek()? {
+                Token {
+                    lexeme: Lexeme::EndOfFile,
+                    ..
+                } => return Ok(()),
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                    ..
+                } => depth += 1,
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                    ..
+                } if depth > 0 => depth -= 1,
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                    ..
+                } => return Ok(()),
+                Token {
+                    lexeme:
+                        Lexeme::Keyword(
+                            Keyword::Function
+                            | Keyword::Let
+                            | Keyword::If
+                            | Keyword::Switch
+                            | Keyword::For
+                            | Keyword::Break
+                            | Keyword::Continue
+                            | Keyword::Leave,
+                        ),
+                    ..
+                } if depth == 0 => return Ok(()),
+                _ => {}
+            }
+            lexer.next()?;
+        }
     }
 
     ///
@@ -153,10 +256,12 @@ where
 
 #[cfg(test)]
 mod tests {
+    use crate::yul::error::Error as YulError;
     use crate::yul::lexer::token::location::Location;
     use crate::yul::lexer::Lexer;
     use crate::yul::parser::dialect::DefaultDialect;
     use crate::yul::parser::error::Error;
+    use crate::yul::parser::statement::block::Block;
     use crate::yul::parser::statement::object::Object;
 
     #[test]
@@ -227,4 +332,150 @@ object "Test" {
             .into())
         );
     }
+
+    #[test]
+    fn error_multiple() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            mstore(0, :=)
+            let x := 1
+            if x { sstore(0, 0) }
+            for { } x { } -> { }
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                :=
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input);
+        let result =
+            Object::<DefaultDialect>::parse(&mut lexer, None, solx_utils::CodeSegment::Deploy);
+        assert_eq!(
+            result,
+            Err(YulError::Multiple(vec![
+                Error::InvalidToken {
+                    location: Location::new(5, 23),
+                    expected: vec!["{literal}", "{identifier}"],
+                    found: ":=".to_owned(),
+                }
+                .into(),
+                Error::InvalidToken {
+                    location: Location::new(8, 27),
+                    expected: vec!["{"],
+                    found: "->".to_owned(),
+                }
+                .into(),
+                Error::InvalidToken {
+                    location: Location::new(15, 17),
+                    expected: vec!["{keyword}", "{expression}", "{identifier}", "{", "}"],
+                    found: ":=".to_owned(),
+                }
+                .into(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn error_multiple_block() {
+        let input = r#"{
+    mstore(0, :=)
+    if x {
+        :=
+    }
+    return(0, 0)
+}"#;
+
+        let mut lexer = Lexer::new(input);
+        let result = Block::<DefaultDialect>::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(YulError::Multiple(vec![
+                Error::InvalidToken {
+                    location: Location::new(2, 15),
+                    expected: vec!["{literal}", "{identifier}"],
+                    found: ":=".to_owned(),
+                }
+                .into(),
+                Error::InvalidToken {
+                    location: Location::new(4, 9),
+                    expected: vec!["{keyword}", "{expression}", "{identifier}", "{", "}"],
+                    found: ":=".to_owned(),
+                }
+                .into(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn error_multiple_switch() {
+        let input = r#"{
+    switch x
+    case 0 {
+        :=
+    }
+    default {
+        return(0, 0)
+    }
+    let y := )
+}"#;
+
+        let mut lexer = Lexer::new(input);
+        let result = Block::<DefaultDialect>::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(YulError::Multiple(vec![
+                Error::InvalidToken {
+                    location: Location::new(4, 9),
+                    expected: vec!["{keyword}", "{expression}", "{identifier}", "{", "}"],
+                    found: ":=".to_owned(),
+                }
+                .into(),
+                Error::InvalidToken {
+                    location: Location::new(9, 14),
+                    expected: vec!["{literal}", "{identifier}"],
+                    found: ")".to_owned(),
+                }
+                .into(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn error_multiple_end_of_file() {
+        let input = r#"{
+    :=
+    if x {
+        return(0, 0)
+"#;
+
+        let mut lexer = Lexer::new(input);
+        let result = Block::<DefaultDialect>::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(YulError::Multiple(vec![
+                Error::InvalidToken {
+                    location: Location::new(2, 5),
+                    expected: vec!["{keyword}", "{expression}", "{identifier}", "{", "}"],
+                    found: ":=".to_owned(),
+                }
+                .into(),
+                Error::InvalidToken {
+                    location: Location::new(5, 1),
+                    expected: vec!["{keyword}", "{expression}", "{identifier}", "{", "}"],
+                    found: "EOF".to_owned(),
+                }
+                .into(),
+            ]))
+        );
+    }
 }
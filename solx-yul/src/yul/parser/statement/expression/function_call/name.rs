@@ -231,6 +231,110 @@ pub enum Name {
     SelfDestruct,
 }
 
+impl Name {
+    ///
+    /// Returns the numbers of input and output values of the builtin function.
+    ///
+    /// Returns `None` for user-defined functions.
+    ///
+    pub fn arity(&self) -> Option<(usize, usize)> {
+        let arity = match self {
+            Self::UserDefined(_) => return None,
+
+            Self::Add | Self::Sub | Self::Mul | Self::Div | Self::Mod | Self::Sdiv | Self::Smod => {
+                (2, 1)
+            }
+
+            Self::Lt | Self::Gt | Self::Eq | Self::Slt | Self::Sgt => (2, 1),
+            Self::IsZero | Self::Clz => (1, 1),
+
+            Self::Or | Self::Xor | Self::And | Self::Shl | Self::Shr | Self::Sar | Self::Byte => {
+                (2, 1)
+            }
+            Self::Not => (1, 1),
+            Self::Pop => (1, 0),
+
+            Self::AddMod | Self::MulMod => (3, 1),
+            Self::Exp | Self::SignExtend => (2, 1),
+
+            Self::Keccak256 => (2, 1),
+
+            Self::MLoad => (1, 1),
+            Self::MStore | Self::MStore8 => (2, 0),
+            Self::MCopy => (3, 0),
+
+            Self::SLoad | Self::TLoad => (1, 1),
+            Self::SStore | Self::TStore => (2, 0),
+            Self::LoadImmutable => (1, 1),
+            Self::SetImmutable => (3, 0),
+
+            Self::CallDataLoad => (1, 1),
+            Self::CallDataSize | Self::CodeSize | Self::ReturnDataSize => (0, 1),
+            Self::CallDataCopy | Self::CodeCopy | Self::ReturnDataCopy => (3, 0),
+            Self::ExtCodeSize | Self::ExtCodeHash => (1, 1),
+            Self::ExtCodeCopy => (4, 0),
+
+            Self::Return | Self::Revert => (2, 0),
+            Self::Stop | Self::Invalid => (0, 0),
+
+            Self::Log0 => (2, 0),
+            Self::Log1 => (3, 0),
+            Self::Log2 => (4, 0),
+            Self::Log3 => (5, 0),
+            Self::Log4 => (6, 0),
+
+            Self::Call | Self::CallCode => (7, 1),
+            Self::DelegateCall | Self::StaticCall => (6, 1),
+
+            Self::Create => (3, 1),
+            Self::Create2 => (4, 1),
+            Self::DataSize | Self::DataOffset => (1, 1),
+            Self::DataCopy => (3, 0),
+
+            Self::LinkerSymbol | Self::MemoryGuard => (1, 1),
+
+            Self::Address | Self::Caller | Self::CallValue | Self::Gas | Self::SelfBalance => {
+                (0, 1)
+            }
+            Self::Balance | Self::BlockHash | Self::BlobHash => (1, 1),
+            Self::GasLimit
+            | Self::GasPrice
+            | Self::Origin
+            | Self::ChainId
+            | Self::Number
+            | Self::Timestamp
+            | Self::Difficulty
+            | Self::Prevrandao
+            | Self::CoinBase
+            | Self::BaseFee
+            | Self::BlobBaseFee
+            | Self::MSize
+            | Self::Pc => (0, 1),
+
+            Self::UnsafeAsm => (0, 0),
+            Self::Verbatim {
+                input_size,
+                output_size,
+            } => (*input_size, *output_size),
+            Self::SelfDestruct => (1, 0),
+        };
+        Some(arity)
+    }
+
+    ///
+    /// Returns the indexes of arguments that must be string literals.
+    ///
+    /// Such arguments are not values, so they are not subject to the literal size limit.
+    ///
+    pub fn literal_arguments(&self) -> &'static [usize] {
+        match self {
+            Self::DataSize | Self::DataOffset | Self::LinkerSymbol | Self::LoadImmutable => &[0],
+            Self::SetImmutable => &[1],
+            _ => &[],
+        }
+    }
+}
+
 impl From<&str> for Name {
     fn from(input: &str) -> Self {
         match input {
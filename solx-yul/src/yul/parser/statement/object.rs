@@ -38,6 +38,9 @@ where
     /// objects are duplicates of the upper-level objects describing the dependencies, so only
     /// their identifiers are preserved. The identifiers are used to address upper-level objects.
     pub factory_dependencies: HashSet<String>,
+    /// The data section identifiers. Their contents are not used by the compiler, so they are
+    /// not preserved.
+    pub data: BTreeSet<String>,
 }
 
 impl<P> Object<P>
@@ -47,11 +50,37 @@ where
     ///
     /// The element parser.
     ///
+    /// The parser recovers from syntax errors at statement and block boundaries, so all of them
+    /// are reported together.
+    ///
     pub fn parse(
         lexer: &mut Lexer,
         initial: Option<Token>,
         code_segment: solx_utils::CodeSegment,
     ) -> Result<Self, Error> {
+        let mut errors = Vec::new();
+        match Self::parse_object(lexer, initial, code_segment, &mut errors) {
+            Ok(Some(object)) if errors.is_empty() => Ok(object),
+            Ok(_) => Err(Error::from_errors(errors)),
+            Err(error) => {
+                errors.push(error);
+                Err(Error::from_errors(errors))
+            }
+        }
+    }
+
+    ///
+    /// Parses the object, collecting the syntax errors recovered in its code and inner objects.
+    ///
+    /// Returns `None` if the object has been parsed up to its closing bracket, but its code has
+    /// syntax errors.
+    ///
+    fn parse_object(
+        lexer: &mut Lexer,
+        initial: Option<Token>,
+        code_segment: solx_utils::CodeSegment,
+        errors: &mut Vec<Error>,
+    ) -> Result<Option<Self>, Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
         let location = match token {
@@ -100,9 +129,17 @@ where
             }
         }
 
-        let code = Code::parse(lexer, None)?;
+        let code = match Code::parse(lexer, None) {
+            Ok(code) => Some(code),
+            Err(error @ Error::Multiple(_)) if !error.is_fatal() => {
+                errors.extend(error.into_errors());
+                None
+            }
+            Err(error) => return Err(error),
+        };
         let mut inner_object = None;
         let mut factory_dependencies = HashSet::new();
+        let mut data = BTreeSet::new();
 
         if let solx_utils::CodeSegment::Deploy = code_segment {
             inner_object = match lexer.peek()? {
@@ -110,10 +147,12 @@ where
                     lexeme: Lexeme::Identifier(identifier),
                     ..
                 } if identifier.inner.as_str() == "object" => {
-                    let mut object = Self::parse(lexer, None, solx_utils::CodeSegment::Runtime)?;
-
-                    factory_dependencies.extend(object.factory_dependencies.drain());
-                    Some(Box::new(object))
+                    Self::parse_object(lexer, None, solx_utils::CodeSegment::Runtime, errors)?.map(
+                        |mut object| {
+                            factory_dependencies.extend(object.factory_dependencies.drain());
+                            Box::new(object)
+                        },
+                    )
                 }
                 _ => None,
             };
@@ -125,7 +164,13 @@ where
             {
                 if identifier.inner.as_str() == "data" {
                     let _data = lexer.next()?;
-                    let _identifier = lexer.next()?;
+                    if let Token {
+                        lexeme: Lexeme::Literal(Literal::String(literal)),
+                        ..
+                    } = lexer.next()?
+                    {
+                        data.insert(literal.inner);
+                    }
                     let _metadata = lexer.next()?;
                 }
             };
@@ -141,18 +186,26 @@ where
                     lexeme: Lexeme::Identifier(ref identifier),
                     ..
                 } if identifier.inner.as_str() == "object" => {
-                    let dependency = Self::parse(
+                    if let Some(dependency) = Self::parse_object(
                         lexer,
                         Some(token.to_owned()),
                         solx_utils::CodeSegment::Deploy,
-                    )?;
-                    factory_dependencies.insert(dependency.identifier);
+                        errors,
+                    )? {
+                        factory_dependencies.insert(dependency.identifier);
+                    }
                 }
                 Token {
                     lexeme: Lexeme::Identifier(identifier),
                     ..
                 } if identifier.inner.as_str() == "data" => {
-                    let _identifier = lexer.next()?;
+                    if let Token {
+                        lexeme: Lexeme::Literal(Literal::String(literal)),
+                        ..
+                    } = lexer.next()?
+                    {
+                        data.insert(literal.inner);
+                    }
                     let _metadata = lexer.next()?;
                 }
                 token => {
@@ -166,13 +219,14 @@ where
             }
         }
 
-        Ok(Self {
+        Ok(code.map(|code| Self {
             location,
             identifier,
             code,
             inner_object,
             factory_dependencies,
-        })
+            data,
+        }))
     }
 
     ///
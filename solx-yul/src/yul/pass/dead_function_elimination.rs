@@ -0,0 +1,152 @@
+//!
+//! The Yul IR dead function elimination pass.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::yul::parser::dialect::Dialect;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::Statement;
+use crate::yul::pass::Pass;
+use crate::yul::visitor_mut::VisitorMut;
+
+///
+/// The Yul IR dead function elimination pass.
+///
+/// Removes the functions that are not reachable from the code outside of function bodies.
+/// Functions are matched by name within an object code, which is conservative if the same name
+/// is defined in several sibling blocks.
+///
+#[derive(Debug, Default)]
+pub struct DeadFunctionElimination;
+
+impl DeadFunctionElimination {
+    ///
+    /// Removes the unreachable functions from the code.
+    ///
+    fn eliminate<P>(code: &mut Code<P>)
+    where
+        P: Dialect,
+    {
+        let mut call_graph = CallGraph::default();
+        call_graph.visit_code_mut(code);
+
+        let mut remover = Remover {
+            live: call_graph.live(),
+        };
+        remover.visit_code_mut(code);
+    }
+}
+
+impl<P> Pass<P> for DeadFunctionElimination
+where
+    P: Dialect,
+{
+    fn name(&self) -> &'static str {
+        "dead-function-elimination"
+    }
+
+    fn run(&mut self, mut object: Object<P>) -> Object<P> {
+        let mut current = Some(&mut object);
+        while let Some(object) = current {
+            Self::eliminate(&mut object.code);
+            current = object.inner_object.as_deref_mut();
+        }
+        object
+    }
+}
+
+///
+/// The call graph of the user-defined functions.
+///
+#[derive(Debug, Default)]
+struct CallGraph {
+    /// The function whose body is being visited.
+    function: Option<String>,
+    /// The functions called outside of function bodies.
+    roots: BTreeSet<String>,
+    /// The functions called from each function.
+    edges: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl CallGraph {
+    ///
+    /// Returns the functions reachable from the roots.
+    ///
+    fn live(mut self) -> BTreeSet<String> {
+        let mut live = BTreeSet::new();
+        let mut queue: Vec<String> = self.roots.into_iter().collect();
+        while let Some(function) = queue.pop() {
+            if !live.insert(function.clone()) {
+                continue;
+            }
+            if let Some(callees) = self.edges.remove(function.as_str()) {
+                queue.extend(callees);
+            }
+        }
+        live
+    }
+}
+
+impl<P> VisitorMut<P> for CallGraph
+where
+    P: Dialect,
+{
+    fn visit_function_definition_mut(&mut self, function_definition: &mut FunctionDefinition<P>) {
+        let outer = self
+            .function
+            .replace(function_definition.identifier.clone());
+        self.edges
+            .entry(function_definition.identifier.clone())
+            .or_default();
+        self.visit_block_mut(&mut function_definition.body);
+        self.function = outer;
+    }
+
+    fn visit_function_call_mut(&mut self, call: &mut FunctionCall) {
+        if let Name::UserDefined(ref identifier) = call.name {
+            match self.function {
+                Some(ref function) => {
+                    self.edges
+                        .entry(function.clone())
+                        .or_default()
+                        .insert(identifier.clone());
+                }
+                None => {
+                    self.roots.insert(identifier.clone());
+                }
+            }
+        }
+        crate::yul::visitor_mut::walk_function_call_mut::<P, Self>(self, call);
+    }
+}
+
+///
+/// Removes the definitions of the functions that are not live.
+///
+#[derive(Debug)]
+struct Remover {
+    /// The live functions.
+    live: BTreeSet<String>,
+}
+
+impl<P> VisitorMut<P> for Remover
+where
+    P: Dialect,
+{
+    fn visit_block_mut(&mut self, block: &mut Block<P>) {
+        block.statements.retain(|statement| match statement {
+            Statement::FunctionDefinition(function_definition) => {
+                self.live.contains(function_definition.identifier.as_str())
+            }
+            _ => true,
+        });
+        crate::yul::visitor_mut::walk_block_mut(self, block);
+    }
+}
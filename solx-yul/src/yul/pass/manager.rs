@@ -0,0 +1,83 @@
+//!
+//! The Yul IR pass manager.
+//!
+
+use crate::yul::analyzer::Analyzer;
+use crate::yul::parser::dialect::Dialect;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::pass::Pass;
+
+///
+/// The Yul IR pass manager.
+///
+/// Runs the passes in the order they were added.
+///
+pub struct Manager<P>
+where
+    P: Dialect,
+{
+    /// The passes to run.
+    passes: Vec<Box<dyn Pass<P>>>,
+    /// Whether to run the semantic analyzer after each pass.
+    verify_each: bool,
+}
+
+impl<P> Manager<P>
+where
+    P: Dialect,
+{
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(verify_each: bool) -> Self {
+        Self {
+            passes: vec![],
+            verify_each,
+        }
+    }
+
+    ///
+    /// Appends a pass to the pipeline.
+    ///
+    pub fn add<T>(&mut self, pass: T) -> &mut Self
+    where
+        T: Pass<P> + 'static,
+    {
+        self.passes.push(Box::new(pass));
+        self
+    }
+
+    ///
+    /// Returns the names of the passes in the pipeline.
+    ///
+    pub fn pass_names(&self) -> Vec<&'static str> {
+        self.passes.iter().map(|pass| pass.name()).collect()
+    }
+
+    ///
+    /// Runs the pipeline on the object.
+    ///
+    /// If `verify_each` is set, the object is analyzed after each pass, and the first pass
+    /// producing invalid Yul is reported.
+    ///
+    pub fn run(&mut self, mut object: Object<P>) -> anyhow::Result<Object<P>> {
+        for pass in self.passes.iter_mut() {
+            object = pass.run(object);
+
+            if self.verify_each {
+                Analyzer::analyze(&object).map_err(|errors| {
+                    anyhow::anyhow!(
+                        "Yul pass `{}` produced invalid code:\n{}",
+                        pass.name(),
+                        errors
+                            .iter()
+                            .map(|error| error.to_string())
+                            .collect::<Vec<String>>()
+                            .join("\n")
+                    )
+                })?;
+            }
+        }
+        Ok(object)
+    }
+}
@@ -0,0 +1,33 @@
+//!
+//! The Yul IR transformation passes.
+//!
+
+pub mod dead_function_elimination;
+pub mod manager;
+
+#[cfg(test)]
+mod tests;
+
+use crate::yul::parser::dialect::Dialect;
+use crate::yul::parser::statement::object::Object;
+
+///
+/// The Yul IR transformation pass.
+///
+/// Passes are usually implemented with [`crate::yul::visitor_mut::VisitorMut`] for in-place
+/// rewrites, or with [`crate::yul::fold::Fold`] for rewrites that rebuild the tree.
+///
+pub trait Pass<P>
+where
+    P: Dialect,
+{
+    ///
+    /// Returns the pass name used in diagnostics.
+    ///
+    fn name(&self) -> &'static str;
+
+    ///
+    /// Transforms the object, including its inner objects.
+    ///
+    fn run(&mut self, object: Object<P>) -> Object<P>;
+}
@@ -0,0 +1,210 @@
+//!
+//! The Yul IR transformation pass tests.
+//!
+
+use std::collections::BTreeSet;
+
+use crate::yul::fold::Fold;
+use crate::yul::lexer::token::lexeme::literal::integer::Integer;
+use crate::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
+use crate::yul::lexer::Lexer;
+use crate::yul::parser::dialect::DefaultDialect;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::Statement;
+use crate::yul::pass::dead_function_elimination::DeadFunctionElimination;
+use crate::yul::pass::manager::Manager;
+use crate::yul::pass::Pass;
+use crate::yul::visitor_mut::VisitorMut;
+
+#[derive(Default)]
+struct FunctionCollector {
+    functions: BTreeSet<String>,
+    calls: Vec<Name>,
+}
+
+impl VisitorMut<DefaultDialect> for FunctionCollector {
+    fn visit_function_definition_mut(
+        &mut self,
+        function_definition: &mut FunctionDefinition<DefaultDialect>,
+    ) {
+        self.functions
+            .insert(function_definition.identifier.clone());
+        crate::yul::visitor_mut::walk_function_definition_mut(self, function_definition);
+    }
+
+    fn visit_name_mut(&mut self, name: &mut Name) {
+        self.calls.push(name.clone());
+    }
+}
+
+struct StrengthReduction;
+
+impl Fold<DefaultDialect> for StrengthReduction {
+    fn fold_function_call(&mut self, call: FunctionCall) -> FunctionCall {
+        let call = crate::yul::fold::walk_function_call::<DefaultDialect, Self>(self, call);
+        match (&call.name, call.arguments.as_slice()) {
+            (
+                Name::Mul,
+                [value, Expression::Literal(
+                    literal @ Literal {
+                        inner: LexicalLiteral::Integer(Integer::Decimal { inner }),
+                        ..
+                    },
+                )],
+            ) if inner == "2" => FunctionCall {
+                location: call.location,
+                name: Name::Shl,
+                arguments: vec![
+                    Expression::Literal(Literal {
+                        inner: LexicalLiteral::Integer(Integer::new_decimal("1".to_owned())),
+                        ..literal.clone()
+                    }),
+                    value.clone(),
+                ],
+            },
+            _ => call,
+        }
+    }
+}
+
+impl Pass<DefaultDialect> for StrengthReduction {
+    fn name(&self) -> &'static str {
+        "strength-reduction"
+    }
+
+    fn run(&mut self, object: Object<DefaultDialect>) -> Object<DefaultDialect> {
+        self.fold_object(object)
+    }
+}
+
+struct FunctionRemoval;
+
+impl Fold<DefaultDialect> for FunctionRemoval {
+    fn fold_statements(
+        &mut self,
+        statements: Vec<Statement<DefaultDialect>>,
+    ) -> Vec<Statement<DefaultDialect>> {
+        let statements = statements
+            .into_iter()
+            .filter(|statement| !matches!(statement, Statement::FunctionDefinition(_)))
+            .collect();
+        crate::yul::fold::walk_statements(self, statements)
+    }
+}
+
+impl Pass<DefaultDialect> for FunctionRemoval {
+    fn name(&self) -> &'static str {
+        "function-removal"
+    }
+
+    fn run(&mut self, object: Object<DefaultDialect>) -> Object<DefaultDialect> {
+        self.fold_object(object)
+    }
+}
+
+fn parse(input: &str) -> Object<DefaultDialect> {
+    let mut lexer = Lexer::new(input);
+    Object::<DefaultDialect>::parse(&mut lexer, None, solx_utils::CodeSegment::Deploy)
+        .expect("Always valid")
+}
+
+#[test]
+fn dead_function_elimination() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            a()
+            function a() { b() }
+            function b() { }
+            function c() { c() d() }
+            function d() { }
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                function e() { }
+                {
+                    function f() { e() }
+                    f()
+                }
+            }
+        }
+    }
+}
+    "#;
+
+    let mut manager = Manager::new(true);
+    manager.add(DeadFunctionElimination);
+    let mut object = manager.run(parse(input)).expect("Always valid");
+
+    let mut collector = FunctionCollector::default();
+    collector.visit_code_mut(&mut object.code);
+    assert_eq!(
+        collector.functions,
+        BTreeSet::from(["a".to_owned(), "b".to_owned()])
+    );
+
+    let mut collector = FunctionCollector::default();
+    collector.visit_object_mut(object.inner_object.as_mut().expect("Always exists"));
+    assert_eq!(
+        collector.functions,
+        BTreeSet::from(["e".to_owned(), "f".to_owned()])
+    );
+}
+
+#[test]
+fn fold() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            sstore(0, mul(calldataload(0), 2))
+        }
+    }
+}
+    "#;
+
+    let mut manager = Manager::new(true);
+    manager.add(StrengthReduction);
+    let mut object = manager.run(parse(input)).expect("Always valid");
+
+    let mut collector = FunctionCollector::default();
+    collector.visit_object_mut(&mut object);
+    assert_eq!(
+        collector.calls,
+        vec![Name::SStore, Name::Shl, Name::CallDataLoad]
+    );
+}
+
+#[test]
+fn error_verify_each() {
+    let input = r#"
+object "Test" {
+    code {
+        {
+            f()
+            function f() { }
+        }
+    }
+}
+    "#;
+
+    let mut manager = Manager::new(true);
+    manager.add(StrengthReduction).add(FunctionRemoval);
+    assert_eq!(
+        manager.pass_names(),
+        vec!["strength-reduction", "function-removal"]
+    );
+
+    let error = manager.run(parse(input)).expect_err("Always invalid");
+    assert!(error
+        .to_string()
+        .contains("Yul pass `function-removal` produced invalid code"));
+}
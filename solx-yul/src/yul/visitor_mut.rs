@@ -0,0 +1,323 @@
+//!
+//! Implementation of a mutable visitor pattern for Yul syntax tree.
+//!
+
+use crate::yul::parser::dialect::Dialect;
+use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::if_conditional::IfConditional;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::switch::Switch;
+use crate::yul::parser::statement::variable_declaration::VariableDeclaration;
+use crate::yul::parser::statement::Statement;
+
+///
+/// Mutable visitor for Yul syntax tree.
+///
+/// Every method recurses into the children by default, so a visitor only overrides the methods
+/// of the nodes it is interested in. An overriding method may call the eponymous `walk_*`
+/// function to continue the recursion.
+///
+pub trait VisitorMut<P>
+where
+    P: Dialect,
+{
+    ///
+    /// Visit Yul object in Yul syntax tree.
+    ///
+    fn visit_object_mut(&mut self, object: &mut Object<P>) {
+        walk_object_mut(self, object)
+    }
+
+    ///
+    /// Visit a `code` block of an object in Yul syntax tree.
+    ///
+    fn visit_code_mut(&mut self, code: &mut Code<P>) {
+        walk_code_mut(self, code)
+    }
+
+    ///
+    /// Visit a block of statements in Yul syntax tree.
+    ///
+    fn visit_block_mut(&mut self, block: &mut Block<P>) {
+        walk_block_mut(self, block)
+    }
+
+    ///
+    /// Visit an arbitrary statement in Yul syntax tree.
+    ///
+    fn visit_statement_mut(&mut self, statement: &mut Statement<P>) {
+        walk_statement_mut(self, statement)
+    }
+
+    ///
+    /// Visit a function definition in Yul syntax tree.
+    ///
+    fn visit_function_definition_mut(&mut self, function_definition: &mut FunctionDefinition<P>) {
+        walk_function_definition_mut(self, function_definition)
+    }
+
+    ///
+    /// Visit a variable declaration in Yul syntax tree.
+    ///
+    fn visit_variable_declaration_mut(&mut self, variable_declaration: &mut VariableDeclaration) {
+        walk_variable_declaration_mut::<P, Self>(self, variable_declaration)
+    }
+
+    ///
+    /// Visit an assignment in Yul syntax tree.
+    ///
+    fn visit_assignment_mut(&mut self, assignment: &mut Assignment) {
+        walk_assignment_mut::<P, Self>(self, assignment)
+    }
+
+    ///
+    /// Visit an `if` statement in Yul syntax tree.
+    ///
+    fn visit_if_conditional_mut(&mut self, if_conditional: &mut IfConditional<P>) {
+        walk_if_conditional_mut(self, if_conditional)
+    }
+
+    ///
+    /// Visit `switch` statement in Yul syntax tree.
+    ///
+    fn visit_switch_mut(&mut self, switch: &mut Switch<P>) {
+        walk_switch_mut(self, switch)
+    }
+
+    ///
+    /// Visit `for` statement in Yul syntax tree.
+    ///
+    fn visit_for_loop_mut(&mut self, for_loop: &mut ForLoop<P>) {
+        walk_for_loop_mut(self, for_loop)
+    }
+
+    ///
+    /// Visit an arbitrary Yul expression in Yul syntax tree.
+    ///
+    fn visit_expression_mut(&mut self, expression: &mut Expression) {
+        walk_expression_mut::<P, Self>(self, expression)
+    }
+
+    ///
+    /// Visit a function call in Yul syntax tree.
+    ///
+    fn visit_function_call_mut(&mut self, call: &mut FunctionCall) {
+        walk_function_call_mut::<P, Self>(self, call)
+    }
+
+    ///
+    /// Visit a function name in Yul syntax tree: a user defined one, or one of the predefined set like `lt`.
+    ///
+    fn visit_name_mut(&mut self, _name: &mut Name) {}
+
+    ///
+    /// Visit a variable identifier in Yul syntax tree.
+    ///
+    fn visit_identifier_mut(&mut self, _identifier: &mut Identifier) {}
+
+    ///
+    /// Visit a literal (e.g. integer) in Yul syntax tree.
+    ///
+    fn visit_literal_mut(&mut self, _literal: &mut Literal) {}
+}
+
+///
+/// Visits the code and the inner object.
+///
+pub fn walk_object_mut<P, V>(visitor: &mut V, object: &mut Object<P>)
+where
+    P: Dialect,
+    V: VisitorMut<P> + ?Sized,
+{
+    visitor.visit_code_mut(&mut object.code);
+    if let Some(inner_object) = object.inner_object.as_mut() {
+        visitor.visit_object_mut(inner_object);
+    }
+}
+
+///
+/// Visits the code block.
+///
+pub fn walk_code_mut<P, V>(visitor: &mut V, code: &mut Code<P>)
+where
+    P: Dialect,
+    V: VisitorMut<P> + ?Sized,
+{
+    visitor.visit_block_mut(&mut code.block);
+}
+
+///
+/// Visits the block statements.
+///
+pub fn walk_block_mut<P, V>(visitor: &mut V, block: &mut Block<P>)
+where
+    P: Dialect,
+    V: VisitorMut<P> + ?Sized,
+{
+    for statement in block.statements.iter_mut() {
+        visitor.visit_statement_mut(statement);
+    }
+}
+
+///
+/// Dispatches the statement to the visitor method of its kind.
+///
+pub fn walk_statement_mut<P, V>(visitor: &mut V, statement: &mut Statement<P>)
+where
+    P: Dialect,
+    V: VisitorMut<P> + ?Sized,
+{
+    match statement {
+        Statement::Object(object) => visitor.visit_object_mut(object),
+        Statement::Code(code) => visitor.visit_code_mut(code),
+        Statement::Block(block) => visitor.visit_block_mut(block),
+        Statement::Expression(expression) => visitor.visit_expression_mut(expression),
+        Statement::FunctionDefinition(function_definition) => {
+            visitor.visit_function_definition_mut(function_definition)
+        }
+        Statement::VariableDeclaration(variable_declaration) => {
+            visitor.visit_variable_declaration_mut(variable_declaration)
+        }
+        Statement::Assignment(assignment) => visitor.visit_assignment_mut(assignment),
+        Statement::IfConditional(if_conditional) => {
+            visitor.visit_if_conditional_mut(if_conditional)
+        }
+        Statement::Switch(switch) => visitor.visit_switch_mut(switch),
+        Statement::ForLoop(for_loop) => visitor.visit_for_loop_mut(for_loop),
+        Statement::Continue(_) | Statement::Break(_) | Statement::Leave(_) => {}
+    }
+}
+
+///
+/// Visits the arguments, results, and body of the function.
+///
+pub fn walk_function_definition_mut<P, V>(
+    visitor: &mut V,
+    function_definition: &mut FunctionDefinition<P>,
+) where
+    P: Dialect,
+    V: VisitorMut<P> + ?Sized,
+{
+    for identifier in function_definition
+        .arguments
+        .iter_mut()
+        .chain(function_definition.result.iter_mut())
+    {
+        visitor.visit_identifier_mut(identifier);
+    }
+    visitor.visit_block_mut(&mut function_definition.body);
+}
+
+///
+/// Visits the bindings and the initializer of the variable declaration.
+///
+pub fn walk_variable_declaration_mut<P, V>(
+    visitor: &mut V,
+    variable_declaration: &mut VariableDeclaration,
+) where
+    P: Dialect,
+    V: VisitorMut<P> + ?Sized,
+{
+    for binding in variable_declaration.bindings.iter_mut() {
+        visitor.visit_identifier_mut(binding);
+    }
+    if let Some(expression) = variable_declaration.expression.as_mut() {
+        visitor.visit_expression_mut(expression);
+    }
+}
+
+///
+/// Visits the bindings and the initializer of the assignment.
+///
+pub fn walk_assignment_mut<P, V>(visitor: &mut V, assignment: &mut Assignment)
+where
+    P: Dialect,
+    V: VisitorMut<P> + ?Sized,
+{
+    for binding in assignment.bindings.iter_mut() {
+        visitor.visit_identifier_mut(binding);
+    }
+    visitor.visit_expression_mut(&mut assignment.initializer);
+}
+
+///
+/// Visits the condition and the body of the `if` statement.
+///
+pub fn walk_if_conditional_mut<P, V>(visitor: &mut V, if_conditional: &mut IfConditional<P>)
+where
+    P: Dialect,
+    V: VisitorMut<P> + ?Sized,
+{
+    visitor.visit_expression_mut(&mut if_conditional.condition);
+    visitor.visit_block_mut(&mut if_conditional.block);
+}
+
+///
+/// Visits the scrutinee, the cases, and the default block of the `switch` statement.
+///
+pub fn walk_switch_mut<P, V>(visitor: &mut V, switch: &mut Switch<P>)
+where
+    P: Dialect,
+    V: VisitorMut<P> + ?Sized,
+{
+    visitor.visit_expression_mut(&mut switch.expression);
+    for case in switch.cases.iter_mut() {
+        visitor.visit_literal_mut(&mut case.literal);
+        visitor.visit_block_mut(&mut case.block);
+    }
+    if let Some(default) = switch.default.as_mut() {
+        visitor.visit_block_mut(default);
+    }
+}
+
+///
+/// Visits the initializer, condition, finalizer, and body of the `for` statement.
+///
+pub fn walk_for_loop_mut<P, V>(visitor: &mut V, for_loop: &mut ForLoop<P>)
+where
+    P: Dialect,
+    V: VisitorMut<P> + ?Sized,
+{
+    visitor.visit_block_mut(&mut for_loop.initializer);
+    visitor.visit_expression_mut(&mut for_loop.condition);
+    visitor.visit_block_mut(&mut for_loop.finalizer);
+    visitor.visit_block_mut(&mut for_loop.body);
+}
+
+///
+/// Dispatches the expression to the visitor method of its kind.
+///
+pub fn walk_expression_mut<P, V>(visitor: &mut V, expression: &mut Expression)
+where
+    P: Dialect,
+    V: VisitorMut<P> + ?Sized,
+{
+    match expression {
+        Expression::FunctionCall(call) => visitor.visit_function_call_mut(call),
+        Expression::Identifier(identifier) => visitor.visit_identifier_mut(identifier),
+        Expression::Literal(literal) => visitor.visit_literal_mut(literal),
+    }
+}
+
+///
+/// Visits the name and the arguments of the function call.
+///
+pub fn walk_function_call_mut<P, V>(visitor: &mut V, call: &mut FunctionCall)
+where
+    P: Dialect,
+    V: VisitorMut<P> + ?Sized,
+{
+    visitor.visit_name_mut(&mut call.name);
+    for argument in call.arguments.iter_mut() {
+        visitor.visit_expression_mut(argument);
+    }
+}
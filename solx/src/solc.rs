@@ -67,18 +67,26 @@ impl solx_core::Solc for Solc {
         let original_output_selection = input_json.settings.output_selection.to_owned();
         input_json.settings.output_selection.normalize();
         input_json.settings.output_selection.retain_solc();
-        input_json
-            .settings
-            .output_selection
-            .set_selector(solx_standard_json::InputSelector::Metadata);
-        input_json
-            .settings
-            .output_selection
-            .set_selector(input_json.settings.via_ir.into());
+        if input_json.settings.stop_after.is_none() {
+            input_json
+                .settings
+                .output_selection
+                .set_selector(solx_standard_json::InputSelector::Metadata);
+            input_json
+                .settings
+                .output_selection
+                .set_selector(input_json.settings.via_ir.into());
+        }
 
         let original_optimizer = input_json.settings.optimizer.to_owned();
         input_json.settings.optimizer.mode = None;
         input_json.settings.optimizer.size_fallback = None;
+        input_json.settings.optimizer.passes = None;
+        input_json.settings.optimizer.objective = None;
+
+        let original_stop_after = input_json.settings.stop_after;
+        input_json.settings.stop_after =
+            original_stop_after.filter(solx_standard_json::InputStopAfter::is_passed_to_solc);
 
         let input_string = serde_json::to_string(input_json).expect("Always valid");
         let input_c_string = CString::new(input_string).expect("Always valid");
@@ -167,6 +175,7 @@ impl solx_core::Solc for Solc {
 
         input_json.settings.output_selection = original_output_selection;
         input_json.settings.optimizer = original_optimizer;
+        input_json.settings.stop_after = original_stop_after;
         solc_output
             .errors
             .retain(|error| match error.error_code.as_deref() {
@@ -94,6 +94,30 @@ fn stack_too_deep_llvm() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn stack_too_deep_llvm_unsafe_asm_locations() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_STACK_TOO_DEEP_LLVM_PATH,
+        "--bin",
+        "-O1",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+
+    result
+        .stderr(predicate::str::contains(
+            "Memory-unsafe assembly blocks (<path>:<start>:<end>):",
+        ))
+        .stderr(predicate::str::contains(format!(
+            "    {}:",
+            crate::common::TEST_SOLIDITY_CONTRACT_STACK_TOO_DEEP_LLVM_PATH
+        )));
+
+    Ok(())
+}
+
 #[test]
 fn stack_too_deep_llvm_suppressed() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -0,0 +1,59 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_debug = TempDir::with_prefix("debug_output")?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--debug-output-dir",
+        tmp_dir_debug.path().to_str().unwrap(),
+        "--debug-output-dot",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success();
+
+    let dot_paths = std::fs::read_dir(tmp_dir_debug.path())?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.to_string_lossy().ends_with(".ethir.dot"))
+        .collect::<Vec<_>>();
+    assert!(!dot_paths.is_empty());
+    for path in dot_paths.into_iter() {
+        let dot = std::fs::read_to_string(path)?;
+        assert!(dot.starts_with("digraph ethir {"));
+        assert!(dot.contains("subgraph \"cluster_"));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn disabled() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_debug = TempDir::with_prefix("debug_output")?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--debug-output-dir",
+        tmp_dir_debug.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success();
+
+    assert!(std::fs::read_dir(tmp_dir_debug.path())?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .all(|path| !path.to_string_lossy().ends_with(".dot")));
+
+    Ok(())
+}
@@ -231,3 +231,38 @@ fn standard_json_missing_file() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn standard_json_keccak256_mismatch() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_LLVM_IR_STANDARD_JSON_KECCAK256_MISMATCH_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Mismatch between content and supplied hash",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json_keccak256_fallback() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_LLVM_IR_STANDARD_JSON_KECCAK256_FALLBACK_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("bytecode"))
+        .stdout(predicate::str::contains("Mismatch between content and supplied hash").not());
+
+    Ok(())
+}
@@ -0,0 +1,172 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_llvm_remarks")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--llvm-remarks",
+        output_directory.path().to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success();
+
+    let file_names = std::fs::read_dir(output_directory.path())?
+        .map(|entry| entry.map(|entry| entry.file_name().to_string_lossy().into_owned()))
+        .collect::<Result<Vec<String>, std::io::Error>>()?;
+    for code_segment in [
+        solx_utils::CodeSegment::Deploy,
+        solx_utils::CodeSegment::Runtime,
+    ] {
+        let suffix = format!(".{code_segment}.remarks.{}", solx_utils::EXTENSION_JSON);
+        assert!(
+            file_names
+                .iter()
+                .any(|file_name| file_name.ends_with(suffix.as_str())),
+            "No {code_segment} code remarks file found"
+        );
+    }
+
+    let remarks = read_remarks(output_directory.path())?;
+    assert!(!remarks.is_empty(), "No remarks found");
+    assert!(remarks
+        .iter()
+        .all(|remark| !remark.pass.is_empty() && !remark.function.is_empty()));
+
+    Ok(())
+}
+
+#[test]
+fn filtered() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_llvm_remarks")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--llvm-remarks",
+        output_directory.path().to_str().expect("Always valid"),
+        "--llvm-remarks-kind",
+        "missed",
+        "--llvm-remarks-filter",
+        "inline",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success();
+
+    let remarks = read_remarks(output_directory.path())?;
+    assert!(remarks.iter().all(|remark| {
+        remark.kind == solx_utils::LLVMRemarkKind::Missed && remark.pass.contains("inline")
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn filtered_llvm_options() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_llvm_remarks")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--llvm-remarks",
+        output_directory.path().to_str().expect("Always valid"),
+        "--llvm-options=-pass-remarks-missed=inline",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success();
+
+    let remarks = read_remarks(output_directory.path())?;
+    assert!(remarks
+        .iter()
+        .all(|remark| remark.kind == solx_utils::LLVMRemarkKind::Missed));
+
+    Ok(())
+}
+
+#[test]
+fn filter_without_remarks() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--llvm-remarks-kind",
+        "missed",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "LLVM remarks filters can only be used with `--llvm-remarks`.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_kind() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_llvm_remarks")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--llvm-remarks",
+        output_directory.path().to_str().expect("Always valid"),
+        "--llvm-remarks-kind",
+        "applied",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "unknown LLVM remark kind: `applied`",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_llvm_remarks")?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_PATH,
+        "--llvm-remarks",
+        output_directory.path().to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Cannot output data outside of JSON in standard JSON mode.",
+    ));
+
+    Ok(())
+}
+
+///
+/// Reads and merges all remarks files from the output directory.
+///
+fn read_remarks(output_directory: &std::path::Path) -> anyhow::Result<Vec<solx_utils::LLVMRemark>> {
+    let mut remarks = Vec::new();
+    for entry in std::fs::read_dir(output_directory)? {
+        let file = std::fs::read_to_string(entry?.path())?;
+        remarks.extend(serde_json::from_str::<Vec<solx_utils::LLVMRemark>>(
+            file.as_str(),
+        )?);
+    }
+    Ok(remarks)
+}
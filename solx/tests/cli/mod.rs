@@ -18,6 +18,7 @@ mod benchmarks;
 mod bin;
 mod bin_runtime;
 mod debug_output_dir;
+mod debug_output_dot;
 mod devdoc;
 mod empty;
 mod evm_version;
@@ -28,25 +29,33 @@ mod ir;
 mod libraries;
 mod llvm_ir;
 mod llvm_options;
+mod llvm_passes;
+mod llvm_remarks;
 mod metadata;
 mod metadata_hash;
 mod metadata_literal;
 mod no_cbor_metadata;
 mod no_import_callback;
 mod optimization;
+mod optimization_objective;
 mod optimization_size_fallback;
 mod output_dir;
 mod overwrite;
+mod profile_use;
 mod recursive_process;
 mod remappings;
+mod stack_diagnostics;
 mod standard_json;
 mod storage_layout;
 mod threads;
+mod time_trace;
 mod transient_storage_layout;
 mod userdoc;
+mod verify;
 mod version;
 mod via_ir;
 mod yul;
+mod yul_ast_json;
 
 ///
 /// Execute `solx` with the given arguments and assert the result.
@@ -70,6 +79,18 @@ pub fn execute_solx_with_env_vars(
     Ok(command.args(args).assert())
 }
 
+///
+/// Execute `solx` with the given arguments in the given working directory, and assert the result.
+///
+pub fn execute_solx_in_directory(
+    args: &[&str],
+    directory: &std::path::Path,
+) -> anyhow::Result<assert_cmd::assert::Assert> {
+    let mut command = Command::new(assert_cmd::cargo::cargo_bin!(env!("CARGO_PKG_NAME")));
+    command.current_dir(directory);
+    Ok(command.args(args).assert())
+}
+
 ///
 /// Execute `solx` with the given arguments and stdin input, and assert the result.
 ///
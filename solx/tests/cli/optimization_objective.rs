@@ -0,0 +1,236 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use revm::context::result::ExecutionResult;
+use tempfile::NamedTempFile;
+use test_case::test_case;
+
+///
+/// Compiles the gas test contract with the given optimizer settings and returns its deploy code.
+///
+fn compile_gas_contract(optimizer: serde_json::Value) -> anyhow::Result<Vec<u8>> {
+    let input = std::fs::read_to_string(
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_OPTIMIZATION_OBJECTIVE_GAS_PATH,
+    )?;
+    let mut input: serde_json::Value = serde_json::from_str(input.as_str())?;
+    input["settings"]["optimizer"] = optimizer;
+    let input_file = NamedTempFile::new()?;
+    std::fs::write(input_file.path(), input.to_string())?;
+
+    let args = &[
+        "--standard-json",
+        input_file.path().to_str().expect("Always valid"),
+    ];
+    let result = crate::cli::execute_solx(args)?;
+    let output: serde_json::Value =
+        serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+    let bytecode = output["contracts"]["A"]["C"]["evm"]["bytecode"]["object"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("Bytecode is missing: {output}"))?;
+    Ok(hex::decode(bytecode)?)
+}
+
+///
+/// Deploys the gas test contract with REVM, calls it, and returns the gas used by the call.
+///
+fn measure_gas(deploy_code: Vec<u8>) -> anyhow::Result<u64> {
+    let caller = revm::primitives::Address::repeat_byte(0x11);
+    let mut vm = solx_tester::REVM::new(false);
+
+    let deploy_tx = revm::context::TxEnv::builder()
+        .caller(caller)
+        .data(revm::primitives::Bytes::from(deploy_code))
+        .create()
+        .gas_limit(solx_tester::REVM::BLOCK_GAS_LIMIT)
+        .chain_id(Some(vm.chain_id()))
+        .build_fill();
+    let address = match vm.execute_transaction(deploy_tx) {
+        Ok(ExecutionResult::Success {
+            output: revm::context::result::Output::Create(_, Some(address)),
+            ..
+        }) => address,
+        result => anyhow::bail!("Deploy transaction failed: {result:?}"),
+    };
+
+    // `sum(100)`
+    let mut calldata = hex::decode("188b85b4")?;
+    calldata.extend(revm::primitives::U256::from(100).to_be_bytes::<32>());
+    let runtime_tx = revm::context::TxEnv::builder()
+        .caller(caller)
+        .data(revm::primitives::Bytes::from(calldata))
+        .to(address)
+        .gas_limit(solx_tester::REVM::BLOCK_GAS_LIMIT)
+        .chain_id(Some(vm.chain_id()))
+        .build_fill();
+    match vm.execute_transaction(runtime_tx) {
+        Ok(ExecutionResult::Success { gas_used, .. }) => Ok(gas_used),
+        result => anyhow::bail!("Runtime transaction failed: {result:?}"),
+    }
+}
+
+#[test_case("runtime-size")]
+#[test_case("deploy-size")]
+#[test_case("gas")]
+fn default(objective: &str) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--optimization-auto",
+        "--optimization-objective",
+        objective,
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Optimization mode: "))
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn default_objective() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--optimization-auto",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Optimization mode: "))
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--optimization-auto",
+        "--optimization-objective",
+        "speed",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("unknown optimization objective"));
+
+    Ok(())
+}
+
+#[test]
+fn without_auto() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "-O",
+        "3",
+        "--optimization-objective",
+        "gas",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Optimization objective can only be used with the `auto` optimization mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn with_size_fallback() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--optimization-auto",
+        "--optimization-size-fallback",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Size optimization fallback cannot be used with the `auto` optimization mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_OPTIMIZATION_OBJECTIVE_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"optimizationMode\""))
+        .stdout(predicate::str::contains("\"severity\":\"error\"").not());
+
+    Ok(())
+}
+
+#[test]
+fn with_optimization() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--optimization-auto",
+        "-O",
+        "3",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Optimization parameter cannot be used with the `auto` optimization mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn gas_matches_measured() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let mut candidate_gas = Vec::new();
+    for mode in solx_codegen_evm::OptimizerSettings::AUTO_CANDIDATE_LEVELS {
+        let deploy_code = compile_gas_contract(serde_json::json!({ "mode": mode.to_string() }))?;
+        candidate_gas.push((mode, measure_gas(deploy_code)?));
+    }
+    let best_gas = candidate_gas
+        .iter()
+        .map(|(_mode, gas)| *gas)
+        .min()
+        .expect("Always exists");
+
+    let deploy_code = compile_gas_contract(serde_json::json!({ "objective": "gas" }))?;
+    let auto_gas = measure_gas(deploy_code)?;
+    assert_eq!(
+        auto_gas, best_gas,
+        "The `gas` objective must select the candidate with the lowest measured gas: {candidate_gas:?}"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--profile-use",
+        crate::common::TEST_PROFILE_PATH,
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains("Binary"));
+
+    Ok(())
+}
+
+#[test]
+fn llvm_ir_unoptimized() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_debug = TempDir::with_prefix("debug_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--profile-use",
+        crate::common::TEST_PROFILE_PATH,
+        "--bin",
+        "--debug-output-dir",
+        tmp_dir_debug.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success();
+
+    let mut llvm_ir = String::new();
+    for entry in std::fs::read_dir(tmp_dir_debug.path())? {
+        let path = entry?.path();
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        if file_name.contains(".unoptimized") && file_name.ends_with(".ll") {
+            llvm_ir.push_str(std::fs::read_to_string(path.as_path())?.as_str());
+        }
+    }
+    assert!(!llvm_ir.is_empty(), "Unoptimized LLVM IR is not dumped");
+    assert!(llvm_ir.contains("!prof"), "Profile annotations are missing");
+    assert!(llvm_ir.contains("!\"function_entry_count\", i64 16}"));
+    assert!(llvm_ir.contains("!\"ProfileSummary\""));
+
+    Ok(())
+}
+
+#[test]
+fn metadata() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--profile-use",
+        crate::common::TEST_PROFILE_PATH,
+        "--metadata",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("profile_hash"));
+
+    Ok(())
+}
+
+#[test]
+fn non_existent() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--profile-use",
+        crate::common::TEST_NON_EXISTENT_PATH,
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Execution profile"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--profile-use",
+        crate::common::TEST_BROKEN_INPUT_PATH,
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains("parsing"));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json_block_map() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--standard-json", crate::common::TEST_JSON_BLOCK_MAP];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"blockMap\""))
+        .stdout(predicate::str::contains("\"severity\":\"error\"").not());
+
+    Ok(())
+}
@@ -110,6 +110,102 @@ fn fuzzed_simple_use_expression() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn keccak256() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_KECCAK256_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("bytecode"))
+        .stdout(predicate::str::contains("Mismatch between content and supplied hash").not());
+
+    Ok(())
+}
+
+#[test]
+fn keccak256_mismatch() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_KECCAK256_MISMATCH_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(
+            "Mismatch between content and supplied hash",
+        ))
+        .stdout(predicate::str::contains("bytecode").not());
+
+    Ok(())
+}
+
+#[test]
+fn stop_after_parsing() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_STOP_AFTER_PARSING_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"ast\""))
+        .stdout(predicate::str::contains("\"bytecode\"").not())
+        .stdout(predicate::str::contains("\"severity\":\"error\"").not());
+
+    Ok(())
+}
+
+#[test]
+fn stop_after_analysis() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_STOP_AFTER_ANALYSIS_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"ast\""))
+        .stdout(predicate::str::contains("\"abi\""))
+        .stdout(predicate::str::contains("\"storageLayout\""))
+        .stdout(predicate::str::contains("set(uint256)"))
+        .stdout(predicate::str::contains("\"bytecode\"").not())
+        .stdout(predicate::str::contains("\"severity\":\"error\"").not());
+
+    Ok(())
+}
+
+#[test]
+fn stop_after_analysis_bytecode() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_STOP_AFTER_ANALYSIS_BYTECODE_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Requested output selection conflicts with `settings.stopAfter`",
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn yul() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -127,6 +223,51 @@ fn yul() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn yul_ast_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_YUL_AST_JSON_STANDARD_JSON_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("bytecode"))
+        .stdout(predicate::str::contains(r#""yulAst":{"#));
+
+    Ok(())
+}
+
+#[test]
+fn yul_ast_json_invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_YUL_AST_JSON_INVALID_STANDARD_JSON_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    let output: serde_json::Value =
+        serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+    let errors = output["errors"]
+        .as_array()
+        .ok_or_else(|| anyhow::anyhow!("Errors are missing"))?;
+    let messages = errors
+        .iter()
+        .filter(|error| error["sourceLocation"]["file"] == "Test")
+        .filter_map(|error| error["message"].as_str())
+        .collect::<Vec<&str>>();
+    assert_eq!(messages.len(), 2, "{messages:?}");
+    assert!(messages[0].contains("11:17 Function `mdelete` is not defined"));
+    assert!(messages[1].contains("12:27 Variable `size` is not declared"));
+
+    Ok(())
+}
+
 #[test]
 fn yul_urls() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -161,6 +302,38 @@ fn yul_urls_invalid() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn yul_urls_invalid_syntax() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_YUL_STANDARD_JSON_URLS_INVALID_SYNTAX_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    let output: serde_json::Value =
+        serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+    let errors = output["errors"]
+        .as_array()
+        .ok_or_else(|| anyhow::anyhow!("Errors are missing"))?;
+    let errors = errors
+        .iter()
+        .filter(|error| error["sourceLocation"]["file"] == "Test")
+        .collect::<Vec<&serde_json::Value>>();
+    assert_eq!(errors.len(), 2, "{errors:?}");
+    assert!(errors[0]["message"]
+        .as_str()
+        .is_some_and(|message| message.contains("Yul parsing") && message.contains("4:23")));
+    assert_eq!(errors[0]["sourceLocation"]["start"], 59);
+    assert!(errors[1]["message"]
+        .as_str()
+        .is_some_and(|message| message.contains("Yul parsing") && message.contains("12:31")));
+    assert_eq!(errors[1]["sourceLocation"]["start"], 193);
+
+    Ok(())
+}
+
 #[test]
 fn invalid_input_yul() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -312,6 +485,62 @@ fn metadata_hash_ipfs_and_metadata() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn metadata_hash_ipfs_matches_metadata() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_JSON_METADATA_HASH_IPFS_AND_METADATA,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    let output: serde_json::Value =
+        serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+    let contract = &output["contracts"]["A"]["C"];
+    let metadata = contract["metadata"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("Metadata is missing"))?;
+    let bytecode = contract["evm"]["deployedBytecode"]["object"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("Bytecode is missing"))?;
+
+    let metadata_json: serde_json::Value = serde_json::from_str(metadata)?;
+    assert!(
+        metadata_json["solx"]["solx_version"].is_string(),
+        "The solx section is missing: {metadata}"
+    );
+    let metadata_hash = solx_utils::IPFSHash::from_slice(metadata.as_bytes())
+        .to_vec()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<String>();
+    assert!(
+        bytecode.contains(metadata_hash.as_str()),
+        "The metadata hash is not appended to the bytecode"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn metadata_hash_bzzr1_and_metadata() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_JSON_METADATA_HASH_BZZR1_AND_METADATA,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("a265627a7a72315820"))
+        .stdout(predicate::str::contains("\"metadata\""));
+
+    Ok(())
+}
+
 #[test]
 fn metadata_hash_ipfs_no_metadata() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -443,6 +672,64 @@ fn select_evm_bytecode_opcodes() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn select_evm_intermediate_representations() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_INTERMEDIATE_REPRESENTATIONS_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"llvmIr\"").count(2))
+        .stdout(predicate::str::contains("\"llvmIrOptimized\"").count(2))
+        .stdout(predicate::str::contains("\"ethir\"").count(2))
+        .stdout(predicate::str::contains("\"object\"").not())
+        .stdout(predicate::str::contains("\"llvmAssembly\"").not());
+
+    Ok(())
+}
+
+#[test]
+fn select_evm_llvm_remarks() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_LLVM_REMARKS_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"llvmRemarks\"").count(2))
+        .stdout(predicate::str::contains("\"object\"").not());
+
+    Ok(())
+}
+
+#[test]
+fn select_evm_stack_diagnostics() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_STACK_DIAGNOSTICS_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"stackDiagnostics\"").count(2))
+        .stdout(predicate::str::contains("\"spillAreaSize\":null"))
+        .stdout(predicate::str::contains("\"object\"").not());
+
+    Ok(())
+}
+
 #[test]
 fn select_evm_deployed_bytecode_link_references() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -525,6 +812,7 @@ fn select_all(path: &str) -> anyhow::Result<()> {
         .stdout(predicate::str::contains("\"deployedBytecode\""))
         .stdout(predicate::str::contains("\"object\"").count(2))
         .stdout(predicate::str::contains("\"llvmAssembly\"").count(2))
+        .stdout(predicate::str::contains("\"llvmIr\"").not())
         .stdout(predicate::str::contains("\"opcodes\"").count(2))
         .stdout(predicate::str::contains("\"linkReferences\"").count(2))
         .stdout(predicate::str::contains("\"sourceMap\"").count(2))
@@ -0,0 +1,81 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_time_trace")?;
+    let output_path = output_directory.path().join("trace.json");
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--time-trace",
+        output_path.to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains("Binary"));
+
+    let trace: serde_json::Value = serde_json::from_slice(std::fs::read(output_path)?.as_slice())?;
+    let events = trace["traceEvents"].as_array().expect("Always exists");
+    for name in [
+        "solc_Solidity_Standard_JSON",
+        "solx_Compilation",
+        "Process(0)",
+        "OptimizeVerify",
+        "EmitBytecode",
+    ] {
+        assert!(
+            events.iter().any(|event| event["name"]
+                .as_str()
+                .expect("Always exists")
+                .contains(name)),
+            "Trace event `{name}` not found"
+        );
+    }
+
+    let llvm_pass_events = events
+        .iter()
+        .filter(|event| event["cat"] == "llvm_pass")
+        .filter_map(|event| event["name"].as_str())
+        .collect::<Vec<&str>>();
+    assert!(
+        llvm_pass_events.iter().any(|name| name.ends_with("Pass")),
+        "Middle-end LLVM pass trace events not found: {llvm_pass_events:?}"
+    );
+    assert!(
+        llvm_pass_events.contains(&"RunPass"),
+        "Back-end LLVM pass trace events not found: {llvm_pass_events:?}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_time_trace")?;
+    let output_path = output_directory.path().join("trace.json");
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_PATH,
+        "--time-trace",
+        output_path.to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("bytecode"));
+    assert!(output_path.exists());
+
+    Ok(())
+}
@@ -0,0 +1,252 @@
+//!
+//! CLI tests for the eponymous subcommand.
+//!
+
+use predicates::prelude::*;
+use tempfile::NamedTempFile;
+use tempfile::TempDir;
+
+///
+/// Compiles the test contract and returns its metadata and deployed bytecode.
+///
+fn compile() -> anyhow::Result<(String, String)> {
+    compile_input(crate::common::TEST_SOLIDITY_STANDARD_JSON_VERIFY_PATH)
+}
+
+///
+/// Compiles the test contract with the given standard JSON input and returns its metadata
+/// and deployed bytecode.
+///
+fn compile_input(path: &str) -> anyhow::Result<(String, String)> {
+    let args = &["--standard-json", path];
+
+    let result = crate::cli::execute_solx(args)?;
+    let output: serde_json::Value =
+        serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+    let contract = &output["contracts"]["A"]["C"];
+    let metadata = contract["metadata"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("Metadata is missing"))?
+        .to_owned();
+    let bytecode = contract["evm"]["deployedBytecode"]["object"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("Deployed bytecode is missing"))?
+        .to_owned();
+    Ok((metadata, bytecode))
+}
+
+///
+/// Flips the hexadecimal digit at `index` counting from the end of `bytecode`.
+///
+fn flip_digit(bytecode: &str, index: usize) -> String {
+    let mut digits = bytecode.chars().collect::<Vec<char>>();
+    let position = digits.len() - 1 - index;
+    digits[position] = if digits[position] == '0' { '1' } else { '0' };
+    digits.into_iter().collect()
+}
+
+#[test]
+fn full_match() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (metadata, bytecode) = compile()?;
+    let metadata_file = NamedTempFile::new()?;
+    std::fs::write(metadata_file.path(), metadata)?;
+
+    let args = &[
+        "verify",
+        "--metadata",
+        metadata_file.path().to_str().expect("Always valid"),
+        "--bytecode",
+        bytecode.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Contract `A:C`: full match."));
+
+    Ok(())
+}
+
+#[test]
+fn full_match_llvm_passes() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let input = std::fs::read_to_string(crate::common::TEST_SOLIDITY_STANDARD_JSON_VERIFY_PATH)?;
+    let mut input: serde_json::Value = serde_json::from_str(input.as_str())?;
+    input["settings"]["optimizer"]["passes"] =
+        serde_json::Value::from("function(instcombine,simplifycfg),globaldce");
+    let input_file = NamedTempFile::new()?;
+    std::fs::write(input_file.path(), input.to_string())?;
+
+    let (metadata, bytecode) = compile_input(input_file.path().to_str().expect("Always valid"))?;
+    let metadata_file = NamedTempFile::new()?;
+    std::fs::write(metadata_file.path(), metadata)?;
+
+    let args = &[
+        "verify",
+        "--metadata",
+        metadata_file.path().to_str().expect("Always valid"),
+        "--bytecode",
+        bytecode.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Contract `A:C`: full match."));
+
+    Ok(())
+}
+
+#[test]
+fn partial_match() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (metadata, bytecode) = compile()?;
+    let metadata_file = NamedTempFile::new()?;
+    std::fs::write(metadata_file.path(), metadata)?;
+
+    let bytecode = flip_digit(bytecode.as_str(), 32);
+
+    let args = &[
+        "verify",
+        "--metadata",
+        metadata_file.path().to_str().expect("Always valid"),
+        "--bytecode",
+        bytecode.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Contract `A:C`: partial match."));
+
+    Ok(())
+}
+
+#[test]
+fn mismatch() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (metadata, bytecode) = compile()?;
+    let metadata_file = NamedTempFile::new()?;
+    std::fs::write(metadata_file.path(), metadata)?;
+
+    let bytecode = flip_digit(bytecode.as_str(), bytecode.len() - 1);
+
+    let args = &[
+        "verify",
+        "--metadata",
+        metadata_file.path().to_str().expect("Always valid"),
+        "--bytecode",
+        bytecode.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Contract `A:C`: the bytecode does not match.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn sources_not_embedded() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (metadata, bytecode) = compile()?;
+    let mut metadata: serde_json::Value = serde_json::from_str(metadata.as_str())?;
+    metadata["sources"]["A"]
+        .as_object_mut()
+        .expect("Always exists")
+        .remove("content");
+    let metadata_file = NamedTempFile::new()?;
+    std::fs::write(metadata_file.path(), metadata.to_string())?;
+
+    let args = &[
+        "verify",
+        "--metadata",
+        metadata_file.path().to_str().expect("Always valid"),
+        "--bytecode",
+        bytecode.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Source `A` is not embedded into the metadata.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_bytecode() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let (metadata, _bytecode) = compile()?;
+    let metadata_file = NamedTempFile::new()?;
+    std::fs::write(metadata_file.path(), metadata)?;
+
+    let args = &[
+        "verify",
+        "--metadata",
+        metadata_file.path().to_str().expect("Always valid"),
+        "--bytecode",
+        "0xinvalid",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Bytecode hexadecimal decoding"));
+
+    Ok(())
+}
+
+#[test]
+fn ambiguous_file_name() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let directory = TempDir::new()?;
+    std::fs::copy(
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        directory.path().join("verify"),
+    )?;
+
+    let args = &[
+        "verify",
+        "--metadata",
+        "metadata.json",
+        "--bytecode",
+        "0x00",
+    ];
+
+    let result = crate::cli::execute_solx_in_directory(args, directory.path())?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("`verify` is ambiguous"));
+
+    Ok(())
+}
+
+#[test]
+fn input_file_named_verify() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let directory = TempDir::new()?;
+    std::fs::copy(
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        directory.path().join("verify"),
+    )?;
+
+    let args = &["./verify", "--bin"];
+
+    let result = crate::cli::execute_solx_in_directory(args, directory.path())?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary").count(1));
+
+    Ok(())
+}
@@ -128,6 +128,43 @@ fn invalid_input() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn invalid_syntax() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_INVALID_SYNTAX_PATH,
+        "--yul",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("4:23"))
+        .stderr(predicate::str::contains("12:31"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_analysis() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_INVALID_PATH,
+        "--yul",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Function `mdelete` is not defined",
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn standard_json() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -0,0 +1,159 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--yul",
+        "--yul-ast-json",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Yul AST JSON:"))
+        .stdout(predicate::str::contains(
+            r#""identifier":"Return_deployed""#,
+        ))
+        .stdout(predicate::str::contains(r#""name":"MStore""#));
+
+    Ok(())
+}
+
+#[test]
+fn via_ir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--via-ir",
+        "--yul-ast-json",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Yul AST JSON:"))
+        .stdout(predicate::str::contains(r#""inner_object":{"#));
+
+    Ok(())
+}
+
+#[test]
+fn yul_input() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let mut binaries = Vec::with_capacity(2);
+    for path in [
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        crate::common::TEST_YUL_AST_JSON_CONTRACT_PATH,
+    ] {
+        let args = &[path, "--yul", "--bin"];
+
+        let result = crate::cli::execute_solx(args)?;
+        let stdout = String::from_utf8(result.success().get_output().stdout.to_owned())?;
+        let binary = stdout
+            .split_once("Binary:\n")
+            .map(|(_, binary)| binary.trim().to_owned())
+            .expect("Always exists");
+        binaries.push(binary);
+    }
+    assert_eq!(binaries[0], binaries[1]);
+
+    Ok(())
+}
+
+#[test]
+fn output_dir_round_trip() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--via-ir",
+        "--yul-ast-json",
+        "--output-dir",
+        output_directory.path().to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let ast_json_path = std::fs::read_dir(output_directory.path())?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .find(|path| path.to_string_lossy().ends_with("_yul_ast.json"))
+        .expect("Always exists");
+
+    let args = &[
+        ast_json_path.to_str().expect("Always valid"),
+        "--yul",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_ast_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_PATH,
+        "--yul",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Yul AST JSON parsing"));
+
+    Ok(())
+}
+
+#[test]
+fn legacy_assembly() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT_PATH, "--yul-ast-json"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Yul AST can be only emitted in Yul mode or with the IR codegen.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_PATH,
+        "--yul-ast-json",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Cannot output data outside of JSON in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -54,6 +54,16 @@ pub const TEST_YUL_CONTRACT_PATH: &str = "tests/data/contracts/yul/Test.yul";
 /// A test input file.
 pub const TEST_YUL_CONTRACT_OBJECT_NAMING_PATH: &str = "tests/data/contracts/yul/ObjectNaming.yul";
 
+/// A test input file.
+pub const TEST_YUL_CONTRACT_INVALID_PATH: &str = "tests/data/contracts/yul/Invalid.yul";
+
+/// A test input file.
+pub const TEST_YUL_CONTRACT_INVALID_SYNTAX_PATH: &str =
+    "tests/data/contracts/yul/InvalidSyntax.yul";
+
+/// A test input file.
+pub const TEST_YUL_AST_JSON_CONTRACT_PATH: &str = "tests/data/contracts/yul/Test.json";
+
 /// A test input file.
 pub const TEST_LLVM_IR_CONTRACT_PATH: &str = "tests/data/contracts/llvm_ir/Test.ll";
 
@@ -111,6 +121,22 @@ pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_BYTECODE_PATH: &str =
 pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_BYTECODE_OPCODES_PATH: &str =
     "tests/data/standard_json_input/select_evm_bytecode_opcodes.json";
 
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_INTERMEDIATE_REPRESENTATIONS_PATH: &str =
+    "tests/data/standard_json_input/select_evm_intermediate_representations.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_LLVM_REMARKS_PATH: &str =
+    "tests/data/standard_json_input/select_evm_llvm_remarks.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_STACK_DIAGNOSTICS_PATH: &str =
+    "tests/data/standard_json_input/select_evm_stack_diagnostics.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_VERIFY_PATH: &str =
+    "tests/data/standard_json_input/verify.json";
+
 /// A test input file.
 pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_DEPLOYED_BYTECODE_PATH: &str =
     "tests/data/standard_json_input/select_evm_deployed_bytecode.json";
@@ -135,9 +161,53 @@ pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_ALL_PATH: &str =
 pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_ALL_WILDCARD_PATH: &str =
     "tests/data/standard_json_input/select_all_wildcard.json";
 
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_KECCAK256_PATH: &str =
+    "tests/data/standard_json_input/solidity_keccak256.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_KECCAK256_MISMATCH_PATH: &str =
+    "tests/data/standard_json_input/solidity_keccak256_mismatch.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_STOP_AFTER_PARSING_PATH: &str =
+    "tests/data/standard_json_input/stop_after_parsing.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_STOP_AFTER_ANALYSIS_PATH: &str =
+    "tests/data/standard_json_input/stop_after_analysis.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_STOP_AFTER_ANALYSIS_BYTECODE_PATH: &str =
+    "tests/data/standard_json_input/stop_after_analysis_bytecode.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_LLVM_PASSES_PATH: &str =
+    "tests/data/standard_json_input/llvm_passes.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_LLVM_PASSES_INVALID_PATH: &str =
+    "tests/data/standard_json_input/llvm_passes_invalid.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_OPTIMIZATION_OBJECTIVE_PATH: &str =
+    "tests/data/standard_json_input/optimization_objective.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_OPTIMIZATION_OBJECTIVE_GAS_PATH: &str =
+    "tests/data/standard_json_input/optimization_objective_gas.json";
+
 /// A test input file.
 pub const TEST_YUL_STANDARD_JSON_PATH: &str = "tests/data/standard_json_input/yul.json";
 
+/// A test input file.
+pub const TEST_YUL_AST_JSON_STANDARD_JSON_PATH: &str =
+    "tests/data/standard_json_input/yul_ast.json";
+
+/// A test input file.
+pub const TEST_YUL_AST_JSON_INVALID_STANDARD_JSON_PATH: &str =
+    "tests/data/standard_json_input/yul_ast_invalid.json";
+
 /// A test input file.
 pub const TEST_YUL_STANDARD_JSON_URLS_PATH: &str = "tests/data/standard_json_input/yul_urls.json";
 
@@ -145,6 +215,10 @@ pub const TEST_YUL_STANDARD_JSON_URLS_PATH: &str = "tests/data/standard_json_inp
 pub const TEST_YUL_STANDARD_JSON_URLS_INVALID_PATH: &str =
     "tests/data/standard_json_input/yul_urls_invalid.json";
 
+/// A test input file.
+pub const TEST_YUL_STANDARD_JSON_URLS_INVALID_SYNTAX_PATH: &str =
+    "tests/data/standard_json_input/yul_urls_invalid_syntax.json";
+
 /// A test input file.
 pub const TEST_LLVM_IR_STANDARD_JSON_PATH: &str =
     "tests/data/standard_json_input/llvm_ir_urls.json";
@@ -157,6 +231,14 @@ pub const TEST_LLVM_IR_STANDARD_JSON_INVALID_PATH: &str =
 pub const TEST_LLVM_IR_STANDARD_JSON_MISSING_FILE_PATH: &str =
     "tests/data/standard_json_input/llvm_ir_urls_missing_file.json";
 
+/// A test input file.
+pub const TEST_LLVM_IR_STANDARD_JSON_KECCAK256_MISMATCH_PATH: &str =
+    "tests/data/standard_json_input/llvm_ir_urls_keccak256_mismatch.json";
+
+/// A test input file.
+pub const TEST_LLVM_IR_STANDARD_JSON_KECCAK256_FALLBACK_PATH: &str =
+    "tests/data/standard_json_input/llvm_ir_urls_keccak256_fallback.json";
+
 /// A test input file.
 pub const TEST_JSON_METADATA_HASH_IPFS_AND_METADATA: &str =
     "tests/data/standard_json_input/metadata_hash_ipfs_and_metadata.json";
@@ -165,6 +247,10 @@ pub const TEST_JSON_METADATA_HASH_IPFS_AND_METADATA: &str =
 pub const TEST_JSON_METADATA_HASH_IPFS_NO_METADATA: &str =
     "tests/data/standard_json_input/metadata_hash_ipfs_no_metadata.json";
 
+/// A test input file.
+pub const TEST_JSON_METADATA_HASH_BZZR1_AND_METADATA: &str =
+    "tests/data/standard_json_input/metadata_hash_bzzr1_and_metadata.json";
+
 /// A test input file.
 pub const TEST_JSON_METADATA_HASH_NONE_AND_METADATA: &str =
     "tests/data/standard_json_input/metadata_hash_none_and_metadata.json";
@@ -180,6 +266,12 @@ pub const TEST_JSON_NO_CBOR_METADATA: &str = "tests/data/standard_json_input/no_
 pub const TEST_JSON_EVM_VERSION_TOO_OLD: &str =
     "tests/data/standard_json_input/evm_version_too_old.json";
 
+/// A test input file.
+pub const TEST_JSON_BLOCK_MAP: &str = "tests/data/standard_json_input/block_map.json";
+
+/// A test execution profile.
+pub const TEST_PROFILE_PATH: &str = "tests/data/profiles/Test.json";
+
 /// The broken input file path.
 pub const TEST_BROKEN_INPUT_PATH: &str = "tests/data/broken.bad";
 
@@ -82,6 +82,7 @@ pub fn build_solidity_standard_json(
     selectors.insert(solx_standard_json::InputSelector::BytecodeSourceMap);
     selectors.insert(solx_standard_json::InputSelector::BytecodeFunctionDebugData);
     selectors.insert(solx_standard_json::InputSelector::BytecodeGeneratedSources);
+    selectors.insert(solx_standard_json::InputSelector::BytecodeStackDiagnostics);
     selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeObject);
     selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeLinkReferences);
     selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeImmutableReferences);
@@ -90,6 +91,7 @@ pub fn build_solidity_standard_json(
     selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeSourceMap);
     selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeFunctionDebugData);
     selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeGeneratedSources);
+    selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeStackDiagnostics);
     selectors.insert(solx_standard_json::InputSelector::GasEstimates);
     selectors.insert(solx_standard_json::InputSelector::AST);
     selectors.insert(solx_standard_json::InputSelector::ABI);
@@ -145,6 +147,8 @@ pub fn build_solidity_standard_json(
         optimizer_settings,
         vec![],
         None,
+        None,
+        false,
     )?;
     build.check_errors()?;
 
@@ -200,6 +204,8 @@ pub fn build_yul_standard_json(
         optimizer_settings,
         vec![],
         None,
+        None,
+        false,
     )?;
     build.check_errors()?;
 
@@ -251,6 +257,8 @@ pub fn build_llvm_ir_standard_json(
         optimizer_settings,
         vec![],
         None,
+        None,
+        false,
     )?;
     build.check_errors()?;
 
@@ -0,0 +1,189 @@
+//!
+//! The in-process execution mode tests entry module.
+//!
+//! The execution mode is process-global, so these tests are kept apart from the unit tests.
+//!
+
+#![cfg(test)]
+
+mod common;
+
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use test_case::test_case;
+
+/// The `solx` executable path that cannot be spawned, so the tests fail if a subprocess is used.
+const UNAVAILABLE_EXECUTABLE_PATH: &str = "/nonexistent/solx";
+
+///
+/// Selects the in-process execution mode, and makes spawning a `solx` subprocess fail.
+///
+/// Must be called before the common test setup, which sets the actual `solx` executable.
+///
+fn setup() {
+    let _ = solx_core::process::EXECUTABLE.set(PathBuf::from(UNAVAILABLE_EXECUTABLE_PATH));
+    assert_eq!(
+        solx_core::process::EXECUTABLE.get(),
+        Some(&PathBuf::from(UNAVAILABLE_EXECUTABLE_PATH)),
+        "The `solx` executable has already been set"
+    );
+    let _ = solx_core::EXECUTION_MODE.set(solx_core::ExecutionMode::InProcess {
+        is_panic_isolated: true,
+    });
+}
+
+#[test_case(false)]
+#[test_case(true)]
+fn default(via_ir: bool) {
+    setup();
+
+    let sources = common::read_sources(&[common::TEST_SOLIDITY_CONTRACT_PATH]);
+    let build = common::build_solidity_standard_json(
+        sources,
+        solx_utils::Libraries::default(),
+        solx_utils::MetadataHashType::IPFS,
+        BTreeSet::new(),
+        via_ir,
+        solx_codegen_evm::OptimizerSettings::cycles(),
+    )
+    .expect("Build failure");
+
+    let bytecode = build
+        .contracts
+        .get(common::TEST_SOLIDITY_CONTRACT_PATH)
+        .expect("Missing file")
+        .get("Test")
+        .expect("Missing contract")
+        .evm
+        .as_ref()
+        .expect("Missing EVM data")
+        .deployed_bytecode
+        .as_ref()
+        .expect("Missing bytecode")
+        .object
+        .as_ref()
+        .expect("Missing bytecode object");
+    assert!(!bytecode.is_empty(), "Expected non-empty bytecode");
+}
+
+#[test]
+fn stack_too_deep() {
+    setup();
+    // The contract can only be compiled with the spill area if the memory-safe assembly check is disabled
+    std::env::set_var(
+        solx_utils::ENV_DISABLE_UNSAFE_MEMORY_ASM_STACK_TOO_DEEP_CHECK,
+        "1",
+    );
+
+    let reference = build_runtime(common::TEST_SOLIDITY_CONTRACT_PATH, '3');
+    assert_eq!(
+        reference
+            .stack_diagnostics
+            .and_then(|diagnostics| diagnostics.spill_area_size),
+        None
+    );
+
+    let stack_too_deep =
+        build_runtime(common::TEST_SOLIDITY_CONTRACT_STACK_TOO_DEEP_LLVM_PATH, '1');
+    let spill_area_size = stack_too_deep
+        .stack_diagnostics
+        .and_then(|diagnostics| diagnostics.spill_area_size)
+        .expect("Missing spill area");
+    assert!(spill_area_size > 0, "Expected a non-empty spill area");
+
+    // The spill area LLVM option of the previous translation unit must not be in effect
+    let after = build_runtime(common::TEST_SOLIDITY_CONTRACT_PATH, '3');
+    assert_eq!(
+        after
+            .stack_diagnostics
+            .and_then(|diagnostics| diagnostics.spill_area_size),
+        None
+    );
+    assert_eq!(after.object, reference.object);
+}
+
+#[test]
+fn different_stack_settings() {
+    setup();
+    // The contract can only be compiled with the spill area if the memory-safe assembly check is disabled
+    std::env::set_var(
+        solx_utils::ENV_DISABLE_UNSAFE_MEMORY_ASM_STACK_TOO_DEEP_CHECK,
+        "1",
+    );
+
+    let sources = common::read_sources(&[
+        common::TEST_SOLIDITY_CONTRACT_PATH,
+        common::TEST_SOLIDITY_CONTRACT_STACK_TOO_DEEP_LLVM_PATH,
+    ]);
+    let mut build = common::build_solidity_standard_json(
+        sources,
+        solx_utils::Libraries::default(),
+        solx_utils::MetadataHashType::IPFS,
+        BTreeSet::new(),
+        false,
+        solx_codegen_evm::OptimizerSettings::try_from_cli('1').expect("Always valid"),
+    )
+    .expect("Build failure");
+
+    let mut runtime = |path: &str| {
+        build
+            .contracts
+            .remove(path)
+            .expect("Missing file")
+            .remove("Test")
+            .expect("Missing contract")
+            .evm
+            .expect("Missing EVM data")
+            .deployed_bytecode
+            .expect("Missing bytecode")
+    };
+    let reference = runtime(common::TEST_SOLIDITY_CONTRACT_PATH);
+    let stack_too_deep = runtime(common::TEST_SOLIDITY_CONTRACT_STACK_TOO_DEEP_LLVM_PATH);
+
+    assert!(reference
+        .object
+        .as_ref()
+        .is_some_and(|object| !object.is_empty()));
+    assert_eq!(
+        reference
+            .stack_diagnostics
+            .and_then(|diagnostics| diagnostics.spill_area_size),
+        None
+    );
+    assert!(stack_too_deep
+        .object
+        .as_ref()
+        .is_some_and(|object| !object.is_empty()));
+    assert!(stack_too_deep
+        .stack_diagnostics
+        .and_then(|diagnostics| diagnostics.spill_area_size)
+        .is_some_and(|spill_area_size| spill_area_size > 0));
+}
+
+///
+/// Builds the contract `Test` at `path` in the legacy pipeline, and returns its runtime bytecode.
+///
+fn build_runtime(path: &str, optimization: char) -> solx_standard_json::OutputContractEVMBytecode {
+    let sources = common::read_sources(&[path]);
+    let mut build = common::build_solidity_standard_json(
+        sources,
+        solx_utils::Libraries::default(),
+        solx_utils::MetadataHashType::IPFS,
+        BTreeSet::new(),
+        false,
+        solx_codegen_evm::OptimizerSettings::try_from_cli(optimization).expect("Always valid"),
+    )
+    .expect("Build failure");
+
+    build
+        .contracts
+        .remove(path)
+        .expect("Missing file")
+        .remove("Test")
+        .expect("Missing contract")
+        .evm
+        .expect("Missing EVM data")
+        .deployed_bytecode
+        .expect("Missing bytecode")
+}
@@ -0,0 +1,46 @@
+//! { "environment": {
+//!     "chain_id": "324",
+//!     "basefee": "7",
+//!     "gas_price": "7"
+//! }, "cases": [ {
+//!     "name": "test_level",
+//!     "inputs": [
+//!         {
+//!             "method": "main",
+//!             "calldata": [
+//!             ]
+//!         }
+//!     ],
+//!     "expected": [
+//!         "324", "7", "7", "2", "30", "0xdeadbeef00000000000000000000000000000001", "0xdeadbeef00000000000000000000000000000001"
+//!     ]
+//! }, {
+//!     "name": "input_level",
+//!     "inputs": [
+//!         {
+//!             "method": "main",
+//!             "calldata": [
+//!             ],
+//!             "environment": {
+//!                 "block_number": "1000",
+//!                 "timestamp": "1700000000",
+//!                 "basefee": "10",
+//!                 "gas_price": "12",
+//!                 "origin": "0x1234567890123456789012345678901234567890"
+//!             },
+//!             "expected": [
+//!                 "324", "10", "12", "1000", "1700000000", "0x1234567890123456789012345678901234567890", "0xdeadbeef00000000000000000000000000000001"
+//!             ]
+//!         }
+//!     ]
+//! } ] }
+
+// SPDX-License-Identifier: MIT
+
+pragma solidity >=0.8.7;
+
+contract Test {
+    function main() public view returns(uint, uint, uint, uint, uint, address, address) {
+        return (block.chainid, block.basefee, tx.gasprice, block.number, block.timestamp, tx.origin, msg.sender);
+    }
+}
@@ -0,0 +1,65 @@
+//! { "cases": [ {
+//!     "name": "revert_string",
+//!     "inputs": [
+//!         {
+//!             "method": "require_short",
+//!             "calldata": [
+//!             ]
+//!         }
+//!     ],
+//!     "expected": {
+//!         "revert_string": "short"
+//!     }
+//! }, {
+//!     "name": "custom_error",
+//!     "inputs": [
+//!         {
+//!             "method": "new_error",
+//!             "calldata": [
+//!             ]
+//!         }
+//!     ],
+//!     "expected": {
+//!         "error": "TestError(uint256,uint256,uint256,string)",
+//!         "arguments": [
+//!             "1", "2", "1", "datadatadata"
+//!         ]
+//!     }
+//! }, {
+//!     "name": "custom_error_address",
+//!     "inputs": [
+//!         {
+//!             "method": "insufficient_balance",
+//!             "calldata": [
+//!                 "42"
+//!             ]
+//!         }
+//!     ],
+//!     "expected": {
+//!         "error": "InsufficientBalance(uint256,address)",
+//!         "arguments": [
+//!             "42", "Test.address"
+//!         ]
+//!     }
+//! } ] }
+
+// SPDX-License-Identifier: MIT
+
+pragma solidity >=0.8.4;
+
+contract Test {
+    error TestError(uint256 one, uint256 two, uint256 three, string data);
+    error InsufficientBalance(uint256 required, address account);
+
+    function require_short() public pure {
+        require(false, "short");
+    }
+
+    function new_error() public pure {
+        revert TestError({one: 1, two: 2, three: 1, data: "datadatadata"});
+    }
+
+    function insufficient_balance(uint256 required) public view {
+        revert InsufficientBalance(required, address(this));
+    }
+}